@@ -1,6 +1,8 @@
 use twilight_cache_inmemory::CacheableChannel;
 use twilight_model::{
-    channel::{permission_overwrite::PermissionOverwrite, Channel, ChannelType},
+    channel::{
+        permission_overwrite::PermissionOverwrite, thread::ThreadMember, Channel, ChannelType,
+    },
     id::{
         marker::{ChannelMarker, GuildMarker},
         Id,
@@ -60,4 +62,12 @@ impl CacheableChannel for MinimalCachedChannel {
     fn set_last_pin_timestamp(&mut self, _timestamp: Option<Timestamp>) {
         // We don't store this information, so this is a no-op
     }
+
+    fn set_member(&mut self, _member: Option<ThreadMember>) {
+        // We don't store this information, so this is a no-op
+    }
+
+    fn set_member_count(&mut self, _member_count: Option<i8>) {
+        // We don't store this information, so this is a no-op
+    }
 }
@@ -0,0 +1,277 @@
+use crate::CacheableModels;
+#[cfg(not(feature = "changes"))]
+use std::marker::PhantomData;
+#[cfg(feature = "changes")]
+use tokio::sync::broadcast::{self, Receiver, Sender};
+use twilight_model::id::{
+    marker::{GuildMarker, UserMarker},
+    Id,
+};
+
+/// Number of [`CacheChange`]s buffered for subscribers that haven't received
+/// them yet.
+#[cfg(feature = "changes")]
+const CAPACITY: usize = 256;
+
+/// Change to a cached resource, with its state from before the change.
+///
+/// Changes are only emitted for resources that were already cached, and may
+/// be received via `InMemoryCache::subscribe` with the `changes` feature.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum CacheChange<CacheModels: CacheableModels> {
+    /// Cached member was updated, such as their roles or nickname.
+    MemberUpdate {
+        /// ID of the guild the member is in.
+        guild_id: Id<GuildMarker>,
+        /// Member before the update.
+        old: CacheModels::Member,
+        /// Member after the update.
+        new: CacheModels::Member,
+        /// ID of the member's user.
+        user_id: Id<UserMarker>,
+    },
+    /// Cached message was deleted.
+    MessageDelete(CacheModels::Message),
+    /// Cached message was updated, such as its content being edited.
+    MessageUpdate {
+        /// Message before the update.
+        old: CacheModels::Message,
+        /// Message after the update.
+        new: CacheModels::Message,
+    },
+}
+
+/// Sender of cache changes to subscribers.
+///
+/// Changes are never sent without the `changes` feature.
+#[derive(Debug)]
+pub(crate) struct ChangeSubscribers<CacheModels: CacheableModels> {
+    #[cfg(not(feature = "changes"))]
+    phantom: PhantomData<CacheModels>,
+    #[cfg(feature = "changes")]
+    tx: Sender<CacheChange<CacheModels>>,
+}
+
+#[cfg(feature = "changes")]
+impl<CacheModels: CacheableModels> ChangeSubscribers<CacheModels> {
+    /// Create a new set of subscribers.
+    pub(crate) fn new() -> Self {
+        Self {
+            tx: broadcast::channel(CAPACITY).0,
+        }
+    }
+
+    /// Whether there are any subscribers.
+    ///
+    /// Used to avoid cloning resources for changes that nobody receives.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.tx.receiver_count() == 0
+    }
+
+    /// Send a change to every subscriber.
+    pub(crate) fn send(&self, change: CacheChange<CacheModels>) {
+        // Only errors if there are no subscribers.
+        let _res = self.tx.send(change);
+    }
+
+    /// Add a subscriber, returning its receiver.
+    pub(crate) fn subscribe(&self) -> Receiver<CacheChange<CacheModels>> {
+        self.tx.subscribe()
+    }
+}
+
+#[cfg(not(feature = "changes"))]
+#[allow(clippy::unused_self)]
+impl<CacheModels: CacheableModels> ChangeSubscribers<CacheModels> {
+    /// Create a new set of subscribers.
+    pub(crate) const fn new() -> Self {
+        Self {
+            phantom: PhantomData,
+        }
+    }
+
+    /// Whether there are any subscribers, which there never are.
+    pub(crate) const fn is_empty(&self) -> bool {
+        true
+    }
+
+    /// Drop a change, as there are no subscribers.
+    pub(crate) fn send(&self, change: CacheChange<CacheModels>) {
+        drop(change);
+    }
+}
+
+#[cfg(all(feature = "changes", test))]
+mod tests {
+    use super::{CacheChange, ChangeSubscribers, CAPACITY};
+    use crate::{test, DefaultCacheModels, DefaultInMemoryCache};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use tokio::sync::broadcast::error::TryRecvError;
+    use twilight_model::{
+        gateway::payload::incoming::{
+            MemberUpdate, MessageCreate, MessageDelete, MessageDeleteBulk, MessageUpdate,
+        },
+        id::Id,
+    };
+
+    assert_impl_all!(CacheChange<DefaultCacheModels>: Clone, Debug, Send, Sync);
+
+    #[test]
+    fn subscribers() {
+        let subscribers = ChangeSubscribers::<DefaultCacheModels>::new();
+        assert!(subscribers.is_empty());
+
+        let mut rx = subscribers.subscribe();
+        assert!(!subscribers.is_empty());
+
+        let message = test::message(Id::new(1), "content");
+        subscribers.send(CacheChange::MessageDelete(message.into()));
+        assert!(matches!(
+            rx.try_recv().unwrap(),
+            CacheChange::MessageDelete(_)
+        ));
+
+        drop(rx);
+        let message = test::message(Id::new(1), "content");
+        subscribers.send(CacheChange::MessageDelete(message.into()));
+        assert!(subscribers.is_empty());
+    }
+
+    /// Test that subscribers that fall behind miss the oldest changes.
+    #[test]
+    fn subscribers_lagged() {
+        let subscribers = ChangeSubscribers::<DefaultCacheModels>::new();
+        let mut rx = subscribers.subscribe();
+
+        for id in 1..=CAPACITY as u64 + 1 {
+            let message = test::message(Id::new(id), "content");
+            subscribers.send(CacheChange::MessageDelete(message.into()));
+        }
+
+        assert!(matches!(rx.try_recv(), Err(TryRecvError::Lagged(1))));
+        assert!(matches!(
+            rx.try_recv().unwrap(),
+            CacheChange::MessageDelete(_)
+        ));
+    }
+
+    #[test]
+    fn member_update() {
+        let cache = DefaultInMemoryCache::new();
+        let guild_id = Id::new(1);
+        let user_id = Id::new(2);
+        let mut rx = cache.subscribe();
+
+        cache.cache_member(guild_id, test::member(user_id));
+
+        let update = MemberUpdate {
+            avatar: None,
+            communication_disabled_until: None,
+            deaf: None,
+            guild_id,
+            flags: None,
+            joined_at: None,
+            mute: None,
+            nick: None,
+            pending: false,
+            premium_since: None,
+            roles: Vec::from([Id::new(3)]),
+            user: test::user(user_id),
+        };
+        cache.update(&update);
+
+        let CacheChange::MemberUpdate {
+            guild_id: changed_guild_id,
+            old,
+            new,
+            user_id: changed_user_id,
+        } = rx.try_recv().unwrap()
+        else {
+            panic!("change is not a member update");
+        };
+        assert_eq!(guild_id, changed_guild_id);
+        assert_eq!(user_id, changed_user_id);
+        assert!(old.roles().is_empty());
+        assert_eq!(&[Id::new(3)], new.roles());
+
+        cache.update(&update);
+        // Nothing changed.
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn message_delete() {
+        let cache = DefaultInMemoryCache::new();
+        let mut rx = cache.subscribe();
+
+        cache.update(&MessageDelete {
+            channel_id: Id::new(2),
+            guild_id: None,
+            id: Id::new(1),
+        });
+        // The message wasn't cached.
+        assert!(rx.try_recv().is_err());
+
+        cache.update(&MessageCreate(test::message(Id::new(1), "first")));
+        cache.update(&MessageCreate(test::message(Id::new(3), "second")));
+
+        cache.update(&MessageDelete {
+            channel_id: Id::new(2),
+            guild_id: None,
+            id: Id::new(1),
+        });
+        let CacheChange::MessageDelete(message) = rx.try_recv().unwrap() else {
+            panic!("change is not a message delete");
+        };
+        assert_eq!("first", message.content());
+
+        cache.update(&MessageDeleteBulk {
+            channel_id: Id::new(2),
+            guild_id: None,
+            ids: Vec::from([Id::new(3), Id::new(4)]),
+        });
+        let CacheChange::MessageDelete(message) = rx.try_recv().unwrap() else {
+            panic!("change is not a message delete");
+        };
+        assert_eq!("second", message.content());
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn message_refetch() {
+        let cache = DefaultInMemoryCache::new();
+        let mut rx = cache.subscribe();
+
+        cache.update(&test::message(Id::new(1), "first"));
+        // The message wasn't cached.
+        assert!(rx.try_recv().is_err());
+
+        // Messages fetched over HTTP are compared with the cached message,
+        // like those of gateway events.
+        cache.update(&test::message(Id::new(1), "second"));
+        let CacheChange::MessageUpdate { old, new } = rx.try_recv().unwrap() else {
+            panic!("change is not a message update");
+        };
+        assert_eq!("first", old.content());
+        assert_eq!("second", new.content());
+    }
+
+    #[test]
+    fn message_update() {
+        let cache = DefaultInMemoryCache::new();
+        let mut rx = cache.subscribe();
+
+        cache.update(&MessageUpdate(test::message(Id::new(1), "first")));
+        // The message wasn't cached.
+        assert!(rx.try_recv().is_err());
+
+        cache.update(&MessageUpdate(test::message(Id::new(1), "second")));
+        let CacheChange::MessageUpdate { old, new } = rx.try_recv().unwrap() else {
+            panic!("change is not a message update");
+        };
+        assert_eq!("first", old.content());
+        assert_eq!("second", new.content());
+    }
+}
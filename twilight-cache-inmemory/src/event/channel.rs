@@ -41,13 +41,19 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
     }
 }
 
-impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ChannelCreate {
+impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for Channel {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
         if !cache.wants(ResourceType::CHANNEL) {
             return;
         }
 
-        cache.cache_channel(self.0.clone());
+        cache.cache_channel(self.clone());
+    }
+}
+
+impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ChannelCreate {
+    fn update(&self, cache: &InMemoryCache<CacheModels>) {
+        cache.update(&self.0);
     }
 }
 
@@ -1,5 +1,5 @@
 use crate::{config::ResourceType, CacheableModels, GuildResource, InMemoryCache, UpdateCache};
-use std::borrow::Cow;
+use std::{borrow::Cow, collections::HashSet};
 use twilight_model::{
     gateway::payload::incoming::GuildEmojisUpdate,
     guild::Emoji,
@@ -9,21 +9,20 @@ use twilight_model::{
 impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
     pub(crate) fn cache_emojis(&self, guild_id: Id<GuildMarker>, emojis: Vec<Emoji>) {
         if let Some(mut guild_emojis) = self.guild_emojis.get_mut(&guild_id) {
-            let incoming: Vec<_> = emojis.iter().map(|e| e.id).collect();
+            let incoming_emoji_ids = emojis.iter().map(|emoji| emoji.id).collect::<HashSet<_>>();
 
-            let removal_filter: Vec<_> = guild_emojis
-                .iter()
-                .copied()
-                .filter(|e| !incoming.contains(e))
-                .collect();
+            // Retain only the existing emojis that are still present in the
+            // updated list of emojis, removing the others from the emoji
+            // cache.
+            guild_emojis.retain(|emoji_id| {
+                let retain = incoming_emoji_ids.contains(emoji_id);
 
-            for to_remove in &removal_filter {
-                guild_emojis.remove(to_remove);
-            }
+                if !retain {
+                    self.emojis.remove(emoji_id);
+                }
 
-            for to_remove in &removal_filter {
-                self.emojis.remove(to_remove);
-            }
+                retain
+            });
         }
 
         for emoji in emojis {
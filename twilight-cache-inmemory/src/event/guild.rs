@@ -100,6 +100,7 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
                 }
             } else {
                 self.guilds.remove(&id);
+                self.unavailable_guilds.remove(&id);
             }
         }
 
@@ -155,7 +156,15 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for GuildCreate {
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for GuildDelete {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
-        cache.delete_guild(self.id, false);
+        // Guilds are only marked as unavailable during outages, as they'll be
+        // available again.
+        if self.is_outage() {
+            if cache.wants(ResourceType::GUILD) {
+                cache.unavailable_guild(self.id);
+            }
+        } else {
+            cache.delete_guild(self.id, false);
+        }
     }
 }
 
@@ -181,7 +190,7 @@ mod tests {
             Channel, ChannelType,
         },
         gateway::payload::incoming::{
-            GuildCreate, GuildUpdate, MemberAdd, MemberRemove, UnavailableGuild,
+            GuildCreate, GuildDelete, GuildUpdate, MemberAdd, MemberRemove, UnavailableGuild,
         },
         guild::{
             AfkTimeout, DefaultMessageNotificationLevel, ExplicitContentFilter, Guild, MfaLevel,
@@ -231,6 +240,7 @@ mod tests {
             rate_limit_per_user: None,
             recipients: None,
             rtc_region: None,
+            status: None,
             thread_metadata: None,
             topic: None,
             user_limit: None,
@@ -276,6 +286,7 @@ mod tests {
             rate_limit_per_user: None,
             recipients: None,
             rtc_region: None,
+            status: None,
             thread_metadata: Some(ThreadMetadata {
                 archived: false,
                 auto_archive_duration: AutoArchiveDuration::Hour,
@@ -467,6 +478,29 @@ mod tests {
         assert_eq!(cache.guild(guild_id).unwrap().member_count, Some(1));
     }
 
+    #[test]
+    fn guild_delete() {
+        let cache = DefaultInMemoryCache::new();
+        let guild = test::guild(Id::new(1), None);
+        cache.update(&GuildCreate::Available(guild.clone()));
+
+        // Outages mark the guild as unavailable.
+        cache.update(&GuildDelete {
+            id: guild.id,
+            unavailable: Some(true),
+        });
+        assert!(cache.guild(guild.id).unwrap().unavailable.unwrap());
+        assert!(cache.unavailable_guilds.contains(&guild.id));
+
+        // Removals evict the guild.
+        cache.update(&GuildDelete {
+            id: guild.id,
+            unavailable: None,
+        });
+        assert!(cache.guild(guild.id).is_none());
+        assert!(!cache.unavailable_guilds.contains(&guild.id));
+    }
+
     #[test]
     fn guild_members_size_after_unavailable() {
         let user_id = Id::new(2);
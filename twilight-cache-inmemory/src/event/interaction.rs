@@ -98,6 +98,7 @@ mod tests {
         cache.update(&InteractionCreate(Interaction {
             app_permissions: Some(Permissions::SEND_MESSAGES),
             application_id: Id::new(1),
+            attachment_size_limit: None,
             authorizing_integration_owners: ApplicationIntegrationMap {
                 guild: None,
                 user: None,
@@ -118,6 +119,7 @@ mod tests {
                 rate_limit_per_user: None,
                 recipients: None,
                 rtc_region: None,
+                status: None,
                 topic: None,
                 user_limit: None,
                 application_id: None,
@@ -4,7 +4,7 @@ use crate::{
     config::ResourceType,
     model::member::ComputedInteractionMember,
     traits::{CacheableGuild, CacheableMember},
-    CacheableModels, InMemoryCache, UpdateCache,
+    CacheChange, CacheableModels, InMemoryCache, UpdateCache,
 };
 use twilight_model::{
     application::interaction::InteractionMember,
@@ -173,7 +173,17 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for MemberUpdate {
         let key = (self.guild_id, self.user.id);
 
         if let Some(mut member) = cache.members.get_mut(&key) {
+            let old = (!cache.changes.is_empty()).then(|| member.clone());
             member.update_with_member_update(self);
+
+            if let Some(old) = old.filter(|old| *old != *member) {
+                cache.changes.send(CacheChange::MemberUpdate {
+                    guild_id: self.guild_id,
+                    old,
+                    new: member.clone(),
+                    user_id: self.user.id,
+                });
+            }
         }
     }
 }
@@ -1,10 +1,11 @@
-use crate::{config::ResourceType, CacheableModels, InMemoryCache, UpdateCache};
-use std::borrow::Cow;
-use twilight_model::gateway::payload::incoming::{
-    MessageCreate, MessageDelete, MessageDeleteBulk, MessageUpdate,
+use crate::{config::ResourceType, CacheChange, CacheableModels, InMemoryCache, UpdateCache};
+use std::{borrow::Cow, mem};
+use twilight_model::{
+    channel::Message,
+    gateway::payload::incoming::{MessageCreate, MessageDelete, MessageDeleteBulk, MessageUpdate},
 };
 
-impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for MessageCreate {
+impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for Message {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
         if cache.wants(ResourceType::USER) {
             cache.cache_user(Cow::Borrowed(&self.author), self.guild_id);
@@ -22,7 +23,21 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for MessageCreate {
             return;
         }
 
-        let mut channel_messages = cache.channel_messages.entry(self.0.channel_id).or_default();
+        // Messages fetched over HTTP may already be cached.
+        if let Some(mut message) = cache.messages.get_mut(&self.id) {
+            let new = CacheModels::Message::from(self.clone());
+            let changed = (!cache.changes.is_empty()).then(|| new.clone());
+            let old = mem::replace(&mut *message, new);
+            drop(message);
+
+            if let Some(new) = changed {
+                cache.changes.send(CacheChange::MessageUpdate { old, new });
+            }
+
+            return;
+        }
+
+        let mut channel_messages = cache.channel_messages.entry(self.channel_id).or_default();
 
         // If the channel has more messages than the cache size the user has
         // requested then we pop a message ID out. Once we have the popped ID we
@@ -34,10 +49,16 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for MessageCreate {
             }
         }
 
-        channel_messages.push_front(self.0.id);
+        channel_messages.push_front(self.id);
         cache
             .messages
-            .insert(self.0.id, CacheModels::Message::from(self.0.clone()));
+            .insert(self.id, CacheModels::Message::from(self.clone()));
+    }
+}
+
+impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for MessageCreate {
+    fn update(&self, cache: &InMemoryCache<CacheModels>) {
+        cache.update(&self.0);
     }
 }
 
@@ -47,7 +68,11 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for MessageDelete {
             return;
         }
 
-        cache.messages.remove(&self.id);
+        if let Some((_, message)) = cache.messages.remove(&self.id) {
+            if !cache.changes.is_empty() {
+                cache.changes.send(CacheChange::MessageDelete(message));
+            }
+        }
 
         let mut channel_messages = cache.channel_messages.entry(self.channel_id).or_default();
 
@@ -66,7 +91,11 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for MessageDeleteBul
         let mut channel_messages = cache.channel_messages.entry(self.channel_id).or_default();
 
         for id in &self.ids {
-            cache.messages.remove(id);
+            if let Some((_, message)) = cache.messages.remove(id) {
+                if !cache.changes.is_empty() {
+                    cache.changes.send(CacheChange::MessageDelete(message));
+                }
+            }
 
             if let Some(idx) = channel_messages
                 .iter()
@@ -100,11 +129,14 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for MessageUpdate {
         // of the message cache capacity, or its Event::MessageCreate was missed.
         // If that is the case, we do not only add it to the message cache but
         // also add its ID to the channel messages cache.
-        if cache
-            .messages
-            .insert(self.id, CacheModels::Message::from(self.0.clone()))
-            .is_some()
-        {
+        let message = CacheModels::Message::from(self.0.clone());
+        let new = (!cache.changes.is_empty()).then(|| message.clone());
+
+        if let Some(old) = cache.messages.insert(self.id, message) {
+            if let Some(new) = new {
+                cache.changes.send(CacheChange::MessageUpdate { old, new });
+            }
+
             return;
         }
 
@@ -124,7 +156,7 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for MessageUpdate {
 
 #[cfg(test)]
 mod tests {
-    use crate::{DefaultInMemoryCache, ResourceType};
+    use crate::{test, DefaultInMemoryCache, ResourceType};
     use twilight_model::{
         channel::message::{Message, MessageFlags, MessageType},
         gateway::payload::incoming::MessageCreate,
@@ -242,4 +274,17 @@ mod tests {
 
         Ok(())
     }
+
+    /// Test that messages fetched over HTTP replace the cached message instead
+    /// of being indexed twice.
+    #[test]
+    fn message_refetch() {
+        let cache = DefaultInMemoryCache::new();
+
+        cache.update(&test::message(Id::new(1), "ping"));
+        cache.update(&test::message(Id::new(1), "pong"));
+
+        assert_eq!(1, cache.channel_messages.get(&Id::new(2)).unwrap().len());
+        assert_eq!("pong", cache.message(Id::new(1)).unwrap().content());
+    }
 }
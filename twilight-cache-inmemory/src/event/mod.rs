@@ -63,6 +63,14 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
     }
 }
 
+impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for CurrentUser {
+    fn update(&self, cache: &InMemoryCache<CacheModels>) {
+        if cache.wants(ResourceType::USER_CURRENT) {
+            cache.cache_current_user(self.clone());
+        }
+    }
+}
+
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for Ready {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
         if cache.wants(ResourceType::USER_CURRENT) {
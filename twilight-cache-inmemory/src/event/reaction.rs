@@ -263,7 +263,7 @@ mod tests {
             emoji: EmojiReactionType::Unicode {
                 name: "😀".to_owned(),
             },
-            guild_id: Id::new(1),
+            guild_id: Some(Id::new(1)),
             message_id: Id::new(4),
         });
         cache.update(&ReactionRemoveEmoji {
@@ -273,7 +273,7 @@ mod tests {
                 id: Id::new(6),
                 name: None,
             },
-            guild_id: Id::new(1),
+            guild_id: Some(Id::new(1)),
             message_id: Id::new(4),
         });
 
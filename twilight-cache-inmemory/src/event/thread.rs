@@ -1,6 +1,9 @@
-use crate::{config::ResourceType, CacheableModels, InMemoryCache, UpdateCache};
+use crate::{
+    config::ResourceType, traits::CacheableChannel, CacheableModels, InMemoryCache, UpdateCache,
+};
 use twilight_model::gateway::payload::incoming::{
-    ThreadCreate, ThreadDelete, ThreadListSync, ThreadUpdate,
+    ThreadCreate, ThreadDelete, ThreadListSync, ThreadMemberUpdate, ThreadMembersUpdate,
+    ThreadUpdate,
 };
 
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ThreadCreate {
@@ -33,6 +36,37 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ThreadListSync {
     }
 }
 
+impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ThreadMemberUpdate {
+    fn update(&self, cache: &InMemoryCache<CacheModels>) {
+        if !cache.wants(ResourceType::CHANNEL) {
+            return;
+        }
+
+        let Some(thread_id) = self.member.id else {
+            return;
+        };
+
+        if let Some(mut thread) = cache.channels.get_mut(&thread_id) {
+            thread.set_member(Some(self.member.clone()));
+        }
+    }
+}
+
+impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ThreadMembersUpdate {
+    fn update(&self, cache: &InMemoryCache<CacheModels>) {
+        if !cache.wants(ResourceType::CHANNEL) {
+            return;
+        }
+
+        if let Some(mut thread) = cache.channels.get_mut(&self.id) {
+            // Discord stops counting members at 50, so this only saturates if
+            // that changes.
+            let member_count = i8::try_from(self.member_count).unwrap_or(i8::MAX);
+            thread.set_member_count(Some(member_count));
+        }
+    }
+}
+
 impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ThreadUpdate {
     fn update(&self, cache: &InMemoryCache<CacheModels>) {
         if !cache.wants(ResourceType::CHANNEL) {
@@ -42,3 +76,58 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for ThreadUpdate {
         cache.cache_channel(self.0.clone());
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{test, DefaultInMemoryCache};
+    use twilight_model::{
+        channel::{thread::ThreadMember, ChannelType},
+        gateway::{
+            event::Event,
+            payload::incoming::{ThreadMemberUpdate, ThreadMembersUpdate},
+        },
+        id::Id,
+        util::Timestamp,
+    };
+
+    #[test]
+    fn thread_member_update() {
+        let cache = DefaultInMemoryCache::new();
+        let (guild_id, thread_id, mut thread) = test::guild_channel_text();
+        thread.kind = ChannelType::PublicThread;
+        cache.cache_channel(thread);
+
+        let member = ThreadMember {
+            flags: 1,
+            id: Some(thread_id),
+            join_timestamp: Timestamp::from_secs(1_632_072_645).expect("non zero"),
+            member: None,
+            presence: None,
+            user_id: Some(Id::new(3)),
+        };
+        cache.update(&Event::ThreadMemberUpdate(Box::new(ThreadMemberUpdate {
+            guild_id,
+            member: member.clone(),
+        })));
+
+        assert_eq!(Some(member), cache.channel(thread_id).unwrap().member);
+    }
+
+    #[test]
+    fn thread_members_update() {
+        let cache = DefaultInMemoryCache::new();
+        let (guild_id, thread_id, mut thread) = test::guild_channel_text();
+        thread.kind = ChannelType::PublicThread;
+        cache.cache_channel(thread);
+
+        cache.update(&ThreadMembersUpdate {
+            added_members: Vec::new(),
+            guild_id,
+            id: thread_id,
+            member_count: 5,
+            removed_member_ids: Vec::new(),
+        });
+
+        assert_eq!(Some(5), cache.channel(thread_id).unwrap().member_count);
+    }
+}
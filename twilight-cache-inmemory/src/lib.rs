@@ -20,6 +20,7 @@ pub mod traits;
 pub mod permission;
 
 mod builder;
+mod change;
 mod config;
 mod event;
 mod stats;
@@ -29,6 +30,7 @@ mod test;
 
 pub use self::{
     builder::InMemoryCacheBuilder,
+    change::CacheChange,
     config::{Config, ResourceType},
     stats::InMemoryCacheStats,
     traits::{
@@ -42,7 +44,7 @@ pub use self::{
 #[cfg(feature = "permission-calculator")]
 pub use self::permission::InMemoryCachePermissions;
 
-use self::iter::InMemoryCacheIter;
+use self::{change::ChangeSubscribers, iter::InMemoryCacheIter};
 use dashmap::{
     mapref::{entry::Entry, one::Ref},
     DashMap, DashSet,
@@ -192,6 +194,7 @@ fn upsert_guild_item<K: Eq + Hash, V: PartialEq>(
 #[derive(Debug)]
 pub struct InMemoryCache<CacheModels: CacheableModels = DefaultCacheModels> {
     config: Config,
+    changes: ChangeSubscribers<CacheModels>,
     channels: DashMap<Id<ChannelMarker>, CacheModels::Channel>,
     channel_messages: DashMap<Id<ChannelMarker>, VecDeque<Id<MessageMarker>>>,
     // So long as the lock isn't held across await or panic points this is fine.
@@ -366,6 +369,42 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
         InMemoryCacheStats::new(self)
     }
 
+    /// Subscribe to changes of cached resources.
+    ///
+    /// Changes to members and messages that were already cached are sent to
+    /// the returned receiver, including their state before the change, which
+    /// is otherwise lost once the cache has been updated. Resources are only
+    /// cloned for changes while there are subscribers.
+    ///
+    /// Up to 256 changes are buffered for each subscriber. A subscriber that
+    /// falls too far behind misses the oldest changes, receiving a
+    /// [`RecvError::Lagged`] error instead.
+    ///
+    /// # Examples
+    ///
+    /// Log the previous content of deleted messages:
+    ///
+    /// ```no_run
+    /// use twilight_cache_inmemory::{CacheChange, DefaultInMemoryCache};
+    ///
+    /// # #[tokio::main] async fn main() {
+    /// let cache = DefaultInMemoryCache::new();
+    /// let mut changes = cache.subscribe();
+    ///
+    /// while let Ok(change) = changes.recv().await {
+    ///     if let CacheChange::MessageDelete(message) = change {
+    ///         println!("deleted message: {}", message.content());
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [`RecvError::Lagged`]: tokio::sync::broadcast::error::RecvError::Lagged
+    #[cfg(feature = "changes")]
+    pub fn subscribe(&self) -> tokio::sync::broadcast::Receiver<CacheChange<CacheModels>> {
+        self.changes.subscribe()
+    }
+
     /// Create an interface for retrieving the permissions of a member in a
     /// guild or channel.
     ///
@@ -402,6 +441,13 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
     }
 
     /// Update the cache with an event from the gateway.
+    ///
+    /// Some models fetched over HTTP, such as a [`Channel`], [`Message`], or
+    /// [`CurrentUser`], may also be cached this way.
+    ///
+    /// [`Channel`]: twilight_model::channel::Channel
+    /// [`CurrentUser`]: twilight_model::user::CurrentUser
+    /// [`Message`]: twilight_model::channel::Message
     pub fn update(&self, value: &impl UpdateCache<CacheModels>) {
         value.update(self);
     }
@@ -830,6 +876,7 @@ impl<CacheModels: CacheableModels> InMemoryCache<CacheModels> {
 impl<CacheModels: CacheableModels> Default for InMemoryCache<CacheModels> {
     fn default() -> Self {
         Self {
+            changes: ChangeSubscribers::new(),
             channel_messages: DashMap::new(),
             channels: DashMap::new(),
             config: Config::default(),
@@ -864,25 +911,33 @@ impl<CacheModels: CacheableModels> Default for InMemoryCache<CacheModels> {
 }
 
 mod private {
-    use twilight_model::gateway::{
-        event::Event,
-        payload::incoming::{
-            ChannelCreate, ChannelDelete, ChannelPinsUpdate, ChannelUpdate, GuildCreate,
-            GuildDelete, GuildEmojisUpdate, GuildScheduledEventCreate, GuildScheduledEventDelete,
-            GuildScheduledEventUpdate, GuildScheduledEventUserAdd, GuildScheduledEventUserRemove,
-            GuildStickersUpdate, GuildUpdate, IntegrationCreate, IntegrationDelete,
-            IntegrationUpdate, InteractionCreate, MemberAdd, MemberChunk, MemberRemove,
-            MemberUpdate, MessageCreate, MessageDelete, MessageDeleteBulk, MessageUpdate,
-            PresenceUpdate, ReactionAdd, ReactionRemove, ReactionRemoveAll, ReactionRemoveEmoji,
-            Ready, RoleCreate, RoleDelete, RoleUpdate, StageInstanceCreate, StageInstanceDelete,
-            StageInstanceUpdate, ThreadCreate, ThreadDelete, ThreadListSync, ThreadUpdate,
-            UnavailableGuild, UserUpdate, VoiceStateUpdate,
+    use twilight_model::{
+        channel::{Channel, Message},
+        gateway::{
+            event::Event,
+            payload::incoming::{
+                ChannelCreate, ChannelDelete, ChannelPinsUpdate, ChannelUpdate, GuildCreate,
+                GuildDelete, GuildEmojisUpdate, GuildScheduledEventCreate,
+                GuildScheduledEventDelete, GuildScheduledEventUpdate, GuildScheduledEventUserAdd,
+                GuildScheduledEventUserRemove, GuildStickersUpdate, GuildUpdate, IntegrationCreate,
+                IntegrationDelete, IntegrationUpdate, InteractionCreate, MemberAdd, MemberChunk,
+                MemberRemove, MemberUpdate, MessageCreate, MessageDelete, MessageDeleteBulk,
+                MessageUpdate, PresenceUpdate, ReactionAdd, ReactionRemove, ReactionRemoveAll,
+                ReactionRemoveEmoji, Ready, RoleCreate, RoleDelete, RoleUpdate,
+                StageInstanceCreate, StageInstanceDelete, StageInstanceUpdate, ThreadCreate,
+                ThreadDelete, ThreadListSync, ThreadMemberUpdate, ThreadMembersUpdate,
+                ThreadUpdate, UnavailableGuild, UserUpdate, VoiceStateUpdate,
+            },
         },
+        user::CurrentUser,
     };
 
     pub trait Sealed {}
 
     impl Sealed for Event {}
+    impl Sealed for Channel {}
+    impl Sealed for CurrentUser {}
+    impl Sealed for Message {}
     impl Sealed for ChannelCreate {}
     impl Sealed for ChannelDelete {}
     impl Sealed for ChannelPinsUpdate {}
@@ -919,6 +974,8 @@ mod private {
     impl Sealed for ThreadCreate {}
     impl Sealed for ThreadDelete {}
     impl Sealed for ThreadListSync {}
+    impl Sealed for ThreadMemberUpdate {}
+    impl Sealed for ThreadMembersUpdate {}
     impl Sealed for ThreadUpdate {}
     impl Sealed for UnavailableGuild {}
     impl Sealed for UserUpdate {}
@@ -930,7 +987,7 @@ mod private {
     impl Sealed for GuildScheduledEventUserRemove {}
 }
 
-/// Implemented for dispatch events.
+/// Implemented for dispatch events and some models fetched over HTTP.
 ///
 /// This trait is sealed and cannot be implemented.
 pub trait UpdateCache<CacheModels: CacheableModels>: private::Sealed {
@@ -1010,6 +1067,8 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for Event {
             Event::ThreadCreate(v) => cache.update(v.deref()),
             Event::ThreadDelete(v) => cache.update(v),
             Event::ThreadListSync(v) => cache.update(v),
+            Event::ThreadMemberUpdate(v) => cache.update(v.deref()),
+            Event::ThreadMembersUpdate(v) => cache.update(v),
             Event::ThreadUpdate(v) => cache.update(v.deref()),
             Event::UnavailableGuild(v) => cache.update(v),
             Event::UserUpdate(v) => cache.update(v),
@@ -1039,8 +1098,6 @@ impl<CacheModels: CacheableModels> UpdateCache<CacheModels> for Event {
             | Event::MessagePollVoteAdd(_)
             | Event::MessagePollVoteRemove(_)
             | Event::Resumed
-            | Event::ThreadMembersUpdate(_)
-            | Event::ThreadMemberUpdate(_)
             | Event::TypingStart(_)
             | Event::VoiceServerUpdate(_)
             | Event::WebhooksUpdate(_) => {}
@@ -821,6 +821,7 @@ mod tests {
             rate_limit_per_user: None,
             recipients: None,
             rtc_region: None,
+            status: None,
             thread_metadata: None,
             topic: None,
             user_limit: None,
@@ -866,6 +867,7 @@ mod tests {
             rate_limit_per_user: None,
             recipients: None,
             rtc_region: None,
+            status: None,
             thread_metadata: None,
             topic: None,
             user_limit: None,
@@ -19,8 +19,8 @@ use twilight_model::{
     },
     id::{
         marker::{
-            ChannelMarker, EmojiMarker, GuildMarker, RoleMarker, ScheduledEventMarker,
-            StickerMarker, UserMarker,
+            ChannelMarker, EmojiMarker, GuildMarker, MessageMarker, RoleMarker,
+            ScheduledEventMarker, StickerMarker, UserMarker,
         },
         Id,
     },
@@ -280,6 +280,7 @@ pub fn guild_channel_text() -> (Id<GuildMarker>, Id<ChannelMarker>, Channel) {
         rate_limit_per_user: None,
         recipients: None,
         rtc_region: None,
+        status: None,
         thread_metadata: None,
         topic: None,
         user_limit: None,
@@ -308,6 +309,46 @@ pub fn member(id: Id<UserMarker>) -> Member {
     }
 }
 
+#[allow(deprecated)]
+pub fn message(id: Id<MessageMarker>, content: &str) -> Message {
+    Message {
+        activity: None,
+        application: None,
+        application_id: None,
+        attachments: Vec::new(),
+        author: user(Id::new(3)),
+        call: None,
+        channel_id: Id::new(2),
+        components: Vec::new(),
+        content: content.to_owned(),
+        edited_timestamp: None,
+        embeds: Vec::new(),
+        flags: Some(MessageFlags::empty()),
+        guild_id: None,
+        id,
+        interaction: None,
+        interaction_metadata: None,
+        kind: MessageType::Regular,
+        member: None,
+        mention_channels: Vec::new(),
+        mention_everyone: false,
+        mention_roles: Vec::new(),
+        mentions: Vec::new(),
+        message_snapshots: Vec::new(),
+        pinned: false,
+        poll: None,
+        reactions: Vec::new(),
+        reference: None,
+        referenced_message: None,
+        role_subscription_data: None,
+        sticker_items: Vec::new(),
+        timestamp: Timestamp::from_secs(1_632_072_645).expect("non zero"),
+        thread: None,
+        tts: false,
+        webhook_id: None,
+    }
+}
+
 pub fn role(id: Id<RoleMarker>) -> Role {
     Role {
         color: 0,
@@ -463,6 +504,7 @@ pub fn guild_scheduled_event(
         image: None,
         name: "test".to_owned(),
         privacy_level: PrivacyLevel::GuildOnly,
+        recurrence_rule: None,
         scheduled_end_time: None,
         scheduled_start_time: Timestamp::from_secs(789).unwrap(),
         status: Status::Completed,
@@ -28,6 +28,7 @@ use twilight_model::{
     application::interaction::InteractionMember,
     channel::{
         message::{Reaction, Sticker},
+        thread::ThreadMember,
         Channel, ChannelType, Message, StageInstance,
     },
     gateway::{
@@ -166,6 +167,16 @@ pub trait CacheableChannel:
 
     /// Set the last pin timestamp to a new timestamp.
     fn set_last_pin_timestamp(&mut self, timestamp: Option<Timestamp>);
+
+    /// Set the current user's thread member if this is a thread.
+    ///
+    /// Does nothing by default.
+    fn set_member(&mut self, _member: Option<ThreadMember>) {}
+
+    /// Set the approximate number of members if this is a thread.
+    ///
+    /// Does nothing by default.
+    fn set_member_count(&mut self, _member_count: Option<i8>) {}
 }
 
 impl CacheableChannel for Channel {
@@ -194,6 +205,14 @@ impl CacheableChannel for Channel {
     fn set_last_pin_timestamp(&mut self, timestamp: Option<Timestamp>) {
         self.last_pin_timestamp = timestamp;
     }
+
+    fn set_member(&mut self, member: Option<ThreadMember>) {
+        self.member = member;
+    }
+
+    fn set_member_count(&mut self, member_count: Option<i8>) {
+        self.member_count = member_count;
+    }
 }
 
 /// Trait for a generic cached representation of a [`Guild`].
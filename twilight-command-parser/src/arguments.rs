@@ -0,0 +1,141 @@
+use std::iter::FusedIterator;
+
+/// Iterator over the arguments of a [`Command`].
+///
+/// Arguments are separated by whitespace. An argument starting with a double
+/// quote extends until the next double quote, allowing it to contain
+/// whitespace; if the closing quote is missing the argument extends to the end
+/// of the input.
+///
+/// [`Command`]: crate::Command
+#[derive(Clone, Debug)]
+pub struct Arguments<'a> {
+    buf: &'a str,
+    idx: usize,
+}
+
+impl<'a> Arguments<'a> {
+    /// Create a new iterator over the arguments in a buffer.
+    pub const fn new(buf: &'a str) -> Self {
+        Self { buf, idx: 0 }
+    }
+
+    /// Full input the arguments are parsed from, regardless of how many
+    /// arguments have been consumed.
+    pub const fn as_str(&self) -> &'a str {
+        self.buf
+    }
+
+    /// Remaining input that has not been consumed as an argument, with
+    /// surrounding whitespace trimmed.
+    ///
+    /// Returns [`None`] if there is no remaining input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_command_parser::Arguments;
+    ///
+    /// let mut arguments = Arguments::new("ban 123 being rude");
+    /// assert_eq!(Some("ban"), arguments.next());
+    /// assert_eq!(Some("123"), arguments.next());
+    /// assert_eq!(Some("being rude"), arguments.remainder());
+    /// ```
+    pub fn remainder(&self) -> Option<&'a str> {
+        self.buf
+            .get(self.idx..)
+            .map(str::trim)
+            .filter(|remainder| !remainder.is_empty())
+    }
+
+    /// Consume the iterator, returning the remaining input.
+    ///
+    /// Refer to [`remainder`] for more information.
+    ///
+    /// [`remainder`]: Self::remainder
+    pub fn into_remainder(self) -> Option<&'a str> {
+        self.remainder()
+    }
+}
+
+impl<'a> Iterator for Arguments<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.buf.get(self.idx..)?;
+        let trimmed = rest.trim_start();
+        self.idx += rest.len() - trimmed.len();
+
+        if trimmed.is_empty() {
+            return None;
+        }
+
+        if let Some(quoted) = trimmed.strip_prefix('"') {
+            return Some(if let Some(end) = quoted.find('"') {
+                // Skip the opening quote, the contents, and the closing quote.
+                self.idx += end + 2;
+
+                &quoted[..end]
+            } else {
+                self.idx = self.buf.len();
+
+                quoted
+            });
+        }
+
+        let end = trimmed.find(char::is_whitespace).unwrap_or(trimmed.len());
+        self.idx += end;
+
+        Some(&trimmed[..end])
+    }
+}
+
+impl FusedIterator for Arguments<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::Arguments;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, iter::FusedIterator};
+
+    assert_impl_all!(Arguments<'_>: Clone, Debug, FusedIterator, Send, Sync);
+
+    #[test]
+    fn whitespace() {
+        let mut arguments = Arguments::new("  one two\n\tthree  ");
+        assert_eq!(Some("one"), arguments.next());
+        assert_eq!(Some("two"), arguments.next());
+        assert_eq!(Some("three"), arguments.next());
+        assert!(arguments.next().is_none());
+        assert!(arguments.remainder().is_none());
+    }
+
+    #[test]
+    fn quoted() {
+        let mut arguments = Arguments::new("\"one two\" three \"\" \"four");
+        assert_eq!(Some("one two"), arguments.next());
+        assert_eq!(Some("three"), arguments.next());
+        assert_eq!(Some(""), arguments.next());
+        assert_eq!(Some("four"), arguments.next());
+        assert!(arguments.next().is_none());
+    }
+
+    #[test]
+    fn remainder() {
+        let mut arguments = Arguments::new("one  two three ");
+        assert_eq!(Some("one  two three"), arguments.remainder());
+        assert_eq!(Some("one"), arguments.next());
+        assert_eq!(Some("two three"), arguments.remainder());
+        assert_eq!("one  two three ", arguments.as_str());
+        assert_eq!(Some("two three"), arguments.into_remainder());
+    }
+
+    #[test]
+    fn unicode() {
+        let mut arguments = Arguments::new("héllo \"wörld ✨\" ✨");
+        assert_eq!(Some("héllo"), arguments.next());
+        assert_eq!(Some("wörld ✨"), arguments.next());
+        assert_eq!(Some("✨"), arguments.next());
+        assert!(arguments.next().is_none());
+    }
+}
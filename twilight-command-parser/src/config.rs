@@ -0,0 +1,206 @@
+use std::borrow::Cow;
+use twilight_model::id::{marker::UserMarker, Id};
+
+/// Name of a command and whether it is matched case sensitively.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum CaseSensitivity<'a> {
+    /// Command is matched regardless of ASCII case.
+    Insensitive(Cow<'a, str>),
+    /// Command is only matched if the case is identical.
+    Sensitive(Cow<'a, str>),
+}
+
+impl CaseSensitivity<'_> {
+    /// Name of the command.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Insensitive(name) | Self::Sensitive(name) => name,
+        }
+    }
+
+    /// Whether the command is matched case sensitively.
+    pub const fn is_sensitive(&self) -> bool {
+        matches!(self, Self::Sensitive(_))
+    }
+
+    /// Whether the provided name matches this command.
+    pub(crate) fn matches(&self, name: &str) -> bool {
+        match self {
+            Self::Insensitive(this) => this.eq_ignore_ascii_case(name),
+            Self::Sensitive(this) => this == name,
+        }
+    }
+}
+
+/// Configuration for a [`Parser`].
+///
+/// [`Parser`]: crate::Parser
+#[derive(Clone, Debug, Default)]
+pub struct CommandParserConfig<'a> {
+    commands: Vec<CaseSensitivity<'a>>,
+    prefixes: Vec<Cow<'a, str>>,
+}
+
+impl<'a> CommandParserConfig<'a> {
+    /// Create a new, empty configuration.
+    ///
+    /// At least one prefix and command must be added for anything to be
+    /// parsed.
+    pub const fn new() -> Self {
+        Self {
+            commands: Vec::new(),
+            prefixes: Vec::new(),
+        }
+    }
+
+    /// Immutable iterator over the configured commands.
+    pub fn commands(&self) -> impl Iterator<Item = &CaseSensitivity<'a>> {
+        self.commands.iter()
+    }
+
+    /// Immutable iterator over the configured prefixes.
+    pub fn prefixes(&self) -> impl Iterator<Item = &str> {
+        self.prefixes.iter().map(AsRef::as_ref)
+    }
+
+    /// Add a command.
+    ///
+    /// Returns `false` if a command with an identical name was already added,
+    /// in which case it is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_command_parser::CommandParserConfig;
+    ///
+    /// let mut config = CommandParserConfig::new();
+    /// assert!(config.add_command("ping", false));
+    /// assert!(!config.add_command("ping", true));
+    /// ```
+    pub fn add_command(&mut self, name: impl Into<Cow<'a, str>>, case_sensitive: bool) -> bool {
+        let name = name.into();
+
+        if self.commands.iter().any(|command| command.name() == name) {
+            return false;
+        }
+
+        self.commands.push(if case_sensitive {
+            CaseSensitivity::Sensitive(name)
+        } else {
+            CaseSensitivity::Insensitive(name)
+        });
+
+        true
+    }
+
+    /// Remove a command by its name, returning it if it was present.
+    pub fn remove_command(&mut self, name: impl AsRef<str>) -> Option<CaseSensitivity<'a>> {
+        let name = name.as_ref();
+        let idx = self
+            .commands
+            .iter()
+            .position(|command| command.name() == name)?;
+
+        Some(self.commands.remove(idx))
+    }
+
+    /// Add a prefix.
+    ///
+    /// Returns `false` if the prefix was already added.
+    pub fn add_prefix(&mut self, prefix: impl Into<Cow<'a, str>>) -> bool {
+        let prefix = prefix.into();
+
+        if self.prefixes.contains(&prefix) {
+            return false;
+        }
+
+        self.prefixes.push(prefix);
+
+        true
+    }
+
+    /// Add both forms of mentioning a user as prefixes, allowing commands to
+    /// be invoked by mentioning the bot.
+    ///
+    /// Returns `false` if both prefixes were already added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_command_parser::CommandParserConfig;
+    /// use twilight_model::id::Id;
+    ///
+    /// let mut config = CommandParserConfig::new();
+    /// config.add_mention_prefix(Id::new(123));
+    ///
+    /// assert_eq!(vec!["<@123>", "<@!123>"], config.prefixes().collect::<Vec<_>>());
+    /// ```
+    pub fn add_mention_prefix(&mut self, user_id: Id<UserMarker>) -> bool {
+        let added = self.add_prefix(format!("<@{user_id}>"));
+
+        self.add_prefix(format!("<@!{user_id}>")) || added
+    }
+
+    /// Remove a prefix, returning it if it was present.
+    pub fn remove_prefix(&mut self, prefix: impl AsRef<str>) -> Option<Cow<'a, str>> {
+        let prefix = prefix.as_ref();
+        let idx = self.prefixes.iter().position(|p| p == prefix)?;
+
+        Some(self.prefixes.remove(idx))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CaseSensitivity, CommandParserConfig};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::id::Id;
+
+    assert_impl_all!(CaseSensitivity<'_>: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(CommandParserConfig<'_>: Clone, Debug, Default, Send, Sync);
+
+    #[test]
+    fn case_sensitivity() {
+        let insensitive = CaseSensitivity::Insensitive("ping".into());
+        assert!(insensitive.matches("PiNg"));
+        assert!(!insensitive.is_sensitive());
+
+        let sensitive = CaseSensitivity::Sensitive("ping".into());
+        assert!(sensitive.matches("ping"));
+        assert!(!sensitive.matches("Ping"));
+        assert!(sensitive.is_sensitive());
+    }
+
+    #[test]
+    fn commands() {
+        let mut config = CommandParserConfig::new();
+        assert!(config.add_command("ping", false));
+        assert!(config.add_command("echo", true));
+        assert!(!config.add_command("echo", false));
+        assert_eq!(2, config.commands().count());
+
+        assert_eq!(
+            Some(CaseSensitivity::Sensitive("echo".into())),
+            config.remove_command("echo")
+        );
+        assert!(config.remove_command("echo").is_none());
+        assert_eq!(1, config.commands().count());
+    }
+
+    #[test]
+    fn prefixes() {
+        let mut config = CommandParserConfig::new();
+        assert!(config.add_prefix("!"));
+        assert!(!config.add_prefix("!"));
+        assert!(config.add_mention_prefix(Id::new(123)));
+        assert!(!config.add_mention_prefix(Id::new(123)));
+        assert_eq!(
+            vec!["!", "<@123>", "<@!123>"],
+            config.prefixes().collect::<Vec<_>>()
+        );
+
+        assert_eq!(Some("!".into()), config.remove_prefix("!"));
+        assert!(config.remove_prefix("!").is_none());
+    }
+}
@@ -0,0 +1,22 @@
+#![doc = include_str!("../README.md")]
+#![warn(
+    clippy::missing_const_for_fn,
+    clippy::pedantic,
+    missing_docs,
+    unsafe_code
+)]
+#![allow(
+    clippy::module_name_repetitions,
+    clippy::must_use_candidate,
+    clippy::unnecessary_wraps
+)]
+
+mod arguments;
+mod config;
+mod parser;
+
+pub use self::{
+    arguments::Arguments,
+    config::{CaseSensitivity, CommandParserConfig},
+    parser::{Command, Parser},
+};
@@ -0,0 +1,171 @@
+use crate::{Arguments, CaseSensitivity, CommandParserConfig};
+
+/// Command parsed from some input.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct Command<'a> {
+    /// Arguments following the command name.
+    pub arguments: Arguments<'a>,
+    /// Name of the command as configured, not as it appeared in the input.
+    pub name: &'a str,
+    /// Prefix the command was invoked with.
+    pub prefix: &'a str,
+}
+
+/// Parser for commands in message content.
+///
+/// Input is matched if it starts with a configured prefix, followed by
+/// optional whitespace and a configured command name, which must be followed
+/// by whitespace or the end of the input. When multiple prefixes or commands
+/// match, the longest one is used.
+///
+/// # Examples
+///
+/// Invoke a command by mentioning the bot:
+///
+/// ```
+/// use twilight_command_parser::{CommandParserConfig, Parser};
+/// use twilight_model::id::Id;
+///
+/// let mut config = CommandParserConfig::new();
+/// config.add_mention_prefix(Id::new(123));
+/// config.add_command("ping", false);
+///
+/// let parser = Parser::new(config);
+/// let command = parser.parse("<@!123> ping").unwrap();
+/// assert_eq!("ping", command.name);
+/// assert_eq!("<@!123>", command.prefix);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Parser<'a> {
+    config: CommandParserConfig<'a>,
+}
+
+impl<'a> Parser<'a> {
+    /// Create a new parser from a configuration.
+    pub fn new(config: impl Into<CommandParserConfig<'a>>) -> Self {
+        Self {
+            config: config.into(),
+        }
+    }
+
+    /// Immutable reference to the configuration.
+    pub const fn config(&self) -> &CommandParserConfig<'a> {
+        &self.config
+    }
+
+    /// Mutable reference to the configuration.
+    pub fn config_mut(&mut self) -> &mut CommandParserConfig<'a> {
+        &mut self.config
+    }
+
+    /// Parse a command from the input.
+    ///
+    /// Returns [`None`] if the input doesn't start with a configured prefix
+    /// followed by a configured command.
+    pub fn parse<'b>(&'b self, buf: &'b str) -> Option<Command<'b>> {
+        let (prefix, rest) = self
+            .config
+            .prefixes()
+            .filter_map(|prefix| Some((prefix, buf.strip_prefix(prefix)?)))
+            .max_by_key(|(prefix, _)| prefix.len())?;
+        let rest = rest.trim_start();
+
+        let name = self
+            .config
+            .commands()
+            .filter(|command| {
+                let len = command.name().len();
+
+                rest.get(..len).is_some_and(|name| command.matches(name))
+                    && rest[len..].chars().next().map_or(true, char::is_whitespace)
+            })
+            .map(CaseSensitivity::name)
+            .max_by_key(|name| name.len())?;
+
+        Some(Command {
+            arguments: Arguments::new(&rest[name.len()..]),
+            name,
+            prefix,
+        })
+    }
+}
+
+impl<'a> From<CommandParserConfig<'a>> for Parser<'a> {
+    fn from(config: CommandParserConfig<'a>) -> Self {
+        Self::new(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Command, Parser};
+    use crate::CommandParserConfig;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::id::Id;
+
+    assert_impl_all!(Command<'_>: Clone, Debug, Send, Sync);
+    assert_impl_all!(Parser<'_>: Clone, Debug, From<CommandParserConfig<'static>>, Send, Sync);
+
+    fn parser() -> Parser<'static> {
+        let mut config = CommandParserConfig::new();
+        config.add_prefix("!");
+        config.add_prefix("!!");
+        config.add_mention_prefix(Id::new(123));
+        config.add_command("echo", false);
+        config.add_command("echoall", false);
+        config.add_command("Ping", true);
+
+        Parser::new(config)
+    }
+
+    #[test]
+    fn prefix() {
+        let parser = parser();
+
+        assert_eq!("!", parser.parse("!echo").unwrap().prefix);
+        assert_eq!("!!", parser.parse("!!echo").unwrap().prefix);
+        assert_eq!("<@123>", parser.parse("<@123> echo").unwrap().prefix);
+        assert_eq!("<@!123>", parser.parse("<@!123>echo").unwrap().prefix);
+        assert!(parser.parse("?echo").is_none());
+        assert!(parser.parse("echo").is_none());
+    }
+
+    #[test]
+    fn name() {
+        let parser = parser();
+
+        assert_eq!("echo", parser.parse("!EcHo").unwrap().name);
+        assert_eq!("echoall", parser.parse("!echoall").unwrap().name);
+        assert_eq!("Ping", parser.parse("!Ping").unwrap().name);
+        assert!(parser.parse("!ping").is_none());
+        assert!(parser.parse("!echoes").is_none());
+        assert!(parser.parse("!").is_none());
+    }
+
+    #[test]
+    fn arguments() {
+        let parser = parser();
+
+        let mut arguments = parser
+            .parse("!echo one \"two three\" four five")
+            .unwrap()
+            .arguments;
+        assert_eq!(Some("one"), arguments.next());
+        assert_eq!(Some("two three"), arguments.next());
+        assert_eq!(Some("four five"), arguments.remainder());
+
+        let arguments = parser.parse("!echo").unwrap().arguments;
+        assert!(arguments.into_remainder().is_none());
+    }
+
+    #[test]
+    fn config_mut() {
+        let mut parser = parser();
+        parser.config_mut().remove_command("echo");
+
+        assert!(parser.parse("!echo").is_none());
+        assert_eq!(2, parser.config().commands().count());
+    }
+}
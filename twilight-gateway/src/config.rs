@@ -1,9 +1,11 @@
 //! User configuration for shards.
 
-use crate::{queue::InMemoryQueue, Session};
+use crate::{queue::InMemoryQueue, Session, SessionStore};
 use std::{
     fmt::{Debug, Formatter, Result as FmtResult},
+    num::NonZeroU8,
     sync::Arc,
+    time::Duration,
 };
 use tokio_websockets::Connector;
 use twilight_model::gateway::{
@@ -42,11 +44,18 @@ impl Debug for Token {
 pub struct Config<Q = InMemoryQueue> {
     /// Identification properties the shard will use.
     identify_properties: Option<IdentifyProperties>,
+    /// Delay before the first reconnection attempt.
+    initial_reconnect_delay: Duration,
     /// Intents that the shard requests when identifying with the gateway.
     intents: Intents,
     /// When the gateway will stop sending a guild's member list in
     /// Guild Create events.
     large_threshold: u64,
+    /// Number of failed reconnection attempts after which the shard fatally
+    /// closes, if limited.
+    max_reconnect_attempts: Option<NonZeroU8>,
+    /// Maximum delay between reconnection attempts.
+    max_reconnect_delay: Duration,
     /// Presence to set when identifying with the gateway.
     presence: Option<UpdatePresencePayload>,
     /// Gateway proxy URL.
@@ -61,6 +70,8 @@ pub struct Config<Q = InMemoryQueue> {
     resume_url: Option<Box<str>>,
     /// Session information to resume a shard on initialization.
     session: Option<Session>,
+    /// Storage the shard persists its session to.
+    session_store: Option<Arc<dyn SessionStore>>,
     /// TLS connector for Websocket connections.
     // We need this to be public so [`stream`] can reuse TLS on multiple shards
     // if unconfigured.
@@ -89,6 +100,11 @@ impl<Q> Config<Q> {
         self.identify_properties.as_ref()
     }
 
+    /// Delay before the first reconnection attempt.
+    pub const fn initial_reconnect_delay(&self) -> Duration {
+        self.initial_reconnect_delay
+    }
+
     /// Intents that the shard requests when identifying with the gateway.
     pub const fn intents(&self) -> Intents {
         self.intents
@@ -100,6 +116,17 @@ impl<Q> Config<Q> {
         self.large_threshold
     }
 
+    /// Number of failed reconnection attempts after which the shard fatally
+    /// closes, if limited.
+    pub const fn max_reconnect_attempts(&self) -> Option<NonZeroU8> {
+        self.max_reconnect_attempts
+    }
+
+    /// Maximum delay between reconnection attempts.
+    pub const fn max_reconnect_delay(&self) -> Duration {
+        self.max_reconnect_delay
+    }
+
     /// Immutable reference to the presence to set when identifying
     /// with the gateway.
     ///
@@ -126,6 +153,11 @@ impl<Q> Config<Q> {
         self.ratelimit_messages
     }
 
+    /// Immutable reference to the storage the shard persists its session to.
+    pub fn session_store(&self) -> Option<&dyn SessionStore> {
+        self.session_store.as_deref()
+    }
+
     /// Immutable reference to the token used to authenticate when identifying
     /// with the gateway.
     pub const fn token(&self) -> &str {
@@ -167,14 +199,18 @@ impl ConfigBuilder {
         Self {
             inner: Config {
                 identify_properties: None,
+                initial_reconnect_delay: Duration::from_secs(1),
                 intents,
                 large_threshold: 50,
+                max_reconnect_attempts: None,
+                max_reconnect_delay: Duration::from_secs(u8::MAX.into()),
                 presence: None,
                 proxy_url: None,
                 queue: InMemoryQueue::default(),
                 ratelimit_messages: true,
                 resume_url: None,
                 session: None,
+                session_store: None,
                 tls: Arc::new(Connector::new().unwrap()),
                 token: Token::new(token.into_boxed_str()),
             },
@@ -219,6 +255,20 @@ impl<Q> ConfigBuilder<Q> {
         self
     }
 
+    /// Set the delay before the first reconnection attempt.
+    ///
+    /// The delay doubles after each failed attempt, up to the
+    /// [maximum reconnect delay].
+    ///
+    /// Defaults to one second.
+    ///
+    /// [maximum reconnect delay]: Self::max_reconnect_delay
+    pub const fn initial_reconnect_delay(mut self, initial_reconnect_delay: Duration) -> Self {
+        self.inner.initial_reconnect_delay = initial_reconnect_delay;
+
+        self
+    }
+
     /// Set the maximum number of members in a guild to load the member list.
     ///
     /// Default value is `50`. The minimum value is `50` and the maximum is
@@ -251,6 +301,39 @@ impl<Q> ConfigBuilder<Q> {
         self
     }
 
+    /// Set the number of consecutive failed reconnection attempts after which
+    /// the shard gives up.
+    ///
+    /// The shard then becomes [fatally closed], ending its stream after
+    /// returning the last attempt's [`ReceiveMessageErrorType::Reconnect`]
+    /// error.
+    ///
+    /// Defaults to no limit.
+    ///
+    /// [fatally closed]: crate::ShardState::FatallyClosed
+    /// [`ReceiveMessageErrorType::Reconnect`]: crate::error::ReceiveMessageErrorType::Reconnect
+    pub const fn max_reconnect_attempts(mut self, max_reconnect_attempts: NonZeroU8) -> Self {
+        self.inner.max_reconnect_attempts = Some(max_reconnect_attempts);
+
+        self
+    }
+
+    /// Set the maximum delay between reconnection attempts.
+    ///
+    /// Shards wait an exponentially increasing amount of time between
+    /// attempts to reconnect to the gateway, starting at the
+    /// [initial reconnect delay] and doubling after each failed attempt, until
+    /// this maximum is reached.
+    ///
+    /// Defaults to 255 seconds.
+    ///
+    /// [initial reconnect delay]: Self::initial_reconnect_delay
+    pub const fn max_reconnect_delay(mut self, max_reconnect_delay: Duration) -> Self {
+        self.inner.max_reconnect_delay = max_reconnect_delay;
+
+        self
+    }
+
     /// Set the presence to use automatically when starting a new session.
     ///
     /// The active presence of a session is maintained across re-connections
@@ -323,14 +406,18 @@ impl<Q> ConfigBuilder<Q> {
     pub fn queue<NewQ>(self, queue: NewQ) -> ConfigBuilder<NewQ> {
         let Config {
             identify_properties,
+            initial_reconnect_delay,
             intents,
             large_threshold,
+            max_reconnect_attempts,
+            max_reconnect_delay,
             presence,
             proxy_url,
             queue: _,
             ratelimit_messages,
             resume_url,
             session,
+            session_store,
             tls,
             token,
         } = self.inner;
@@ -338,14 +425,18 @@ impl<Q> ConfigBuilder<Q> {
         ConfigBuilder {
             inner: Config {
                 identify_properties,
+                initial_reconnect_delay,
                 intents,
                 large_threshold,
+                max_reconnect_attempts,
+                max_reconnect_delay,
                 presence,
                 proxy_url,
                 queue,
                 ratelimit_messages,
                 resume_url,
                 session,
+                session_store,
                 tls,
                 token,
             },
@@ -390,6 +481,20 @@ impl<Q> ConfigBuilder<Q> {
 
         self
     }
+
+    /// Set the storage to persist the shard's session to.
+    ///
+    /// If [`ConfigBuilder::session`] isn't set, the shard loads its session
+    /// from the store when it's created. Refer to [`SessionStore`] for more
+    /// information.
+    ///
+    /// Defaults to no storage.
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn session_store(mut self, session_store: Arc<dyn SessionStore>) -> Self {
+        self.inner.session_store = Some(session_store);
+
+        self
+    }
 }
 
 impl<Q> From<Config<Q>> for ConfigBuilder<Q> {
@@ -402,7 +507,7 @@ impl<Q> From<Config<Q>> for ConfigBuilder<Q> {
 mod tests {
     use super::{Config, ConfigBuilder};
     use static_assertions::assert_impl_all;
-    use std::fmt::Debug;
+    use std::{fmt::Debug, num::NonZeroU8, time::Duration};
     use twilight_model::gateway::Intents;
 
     assert_impl_all!(Config: Clone, Debug, Send, Sync);
@@ -436,6 +541,35 @@ mod tests {
         drop(builder().large_threshold(251));
     }
 
+    #[tokio::test]
+    async fn reconnect_policy() {
+        let config = builder().build();
+        assert_eq!(Duration::from_secs(1), config.initial_reconnect_delay());
+        assert!(config.max_reconnect_attempts().is_none());
+
+        let config = builder()
+            .initial_reconnect_delay(Duration::from_millis(500))
+            .max_reconnect_attempts(NonZeroU8::new(5).unwrap())
+            .build();
+        assert_eq!(Duration::from_millis(500), config.initial_reconnect_delay());
+        assert_eq!(NonZeroU8::new(5), config.max_reconnect_attempts());
+    }
+
+    #[tokio::test]
+    async fn max_reconnect_delay() {
+        assert_eq!(
+            Duration::from_secs(255),
+            builder().build().max_reconnect_delay()
+        );
+        assert_eq!(
+            Duration::from_secs(30),
+            builder()
+                .max_reconnect_delay(Duration::from_secs(30))
+                .build()
+                .max_reconnect_delay()
+        );
+    }
+
     #[tokio::test]
     async fn config_prefixes_bot_to_token() {
         const WITHOUT: &str = "test";
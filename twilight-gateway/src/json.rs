@@ -10,10 +10,17 @@ use crate::{
     error::{ReceiveMessageError, ReceiveMessageErrorType},
     EventTypeFlags,
 };
-use serde::de::DeserializeSeed;
-use twilight_model::gateway::{
-    event::{GatewayEvent, GatewayEventDeserializer},
-    OpCode,
+use serde::{
+    de::{DeserializeOwned, DeserializeSeed},
+    Deserialize,
+};
+use std::collections::HashSet;
+use twilight_model::{
+    gateway::{
+        event::{GatewayEvent, GatewayEventDeserializer},
+        OpCode,
+    },
+    id::{marker::GuildMarker, Id},
 };
 
 /// Parse a JSON encoded gateway event into a `GatewayEvent` if
@@ -26,6 +33,18 @@ use twilight_model::gateway::{
 pub fn parse(
     event: String,
     wanted_event_types: EventTypeFlags,
+) -> Result<Option<GatewayEvent>, ReceiveMessageError> {
+    parse_in_guilds(event, wanted_event_types, None)
+}
+
+/// Parse a JSON encoded gateway event like [`parse`], also skipping dispatch
+/// events about guilds other than `guild_ids`.
+///
+/// The ID of the event's guild is only parsed for wanted event types.
+pub(crate) fn parse_in_guilds(
+    event: String,
+    wanted_event_types: EventTypeFlags,
+    guild_ids: Option<&HashSet<Id<GuildMarker>>>,
 ) -> Result<Option<GatewayEvent>, ReceiveMessageError> {
     let Some(gateway_deserializer) = GatewayEventDeserializer::from_json(&event) else {
         return Err(ReceiveMessageError {
@@ -38,13 +57,21 @@ pub fn parse(
         return Ok(None);
     };
 
-    let event_type = gateway_deserializer.event_type();
+    let event_name = gateway_deserializer.event_type();
 
-    let Ok(event_type) = EventTypeFlags::try_from((opcode, event_type)) else {
+    let Ok(event_type) = EventTypeFlags::try_from((opcode, event_name)) else {
         return Ok(None);
     };
 
-    if wanted_event_types.contains(event_type) {
+    let is_other_guild = || {
+        guild_ids.is_some_and(|guild_ids| {
+            event_name
+                .and_then(|event_name| guild_id(event_name, &event))
+                .is_some_and(|guild_id| !guild_ids.contains(&guild_id))
+        })
+    };
+
+    if wanted_event_types.contains(event_type) && !is_other_guild() {
         #[cfg(feature = "simd-json")]
         let gateway_deserializer = gateway_deserializer.into_owned();
         #[cfg(feature = "simd-json")]
@@ -82,3 +109,110 @@ pub fn parse(
         Ok(None)
     }
 }
+
+/// Parse the ID of the guild a JSON encoded dispatch event is about, without
+/// deserializing the rest of the event.
+///
+/// Returns [`None`] if the event is not about a guild or could not be parsed.
+fn guild_id(event_type: &str, event: &str) -> Option<Id<GuildMarker>> {
+    /// Payload with only its data.
+    #[derive(Deserialize)]
+    struct Payload<T> {
+        d: T,
+    }
+
+    /// Data of guild events, whose ID is of the guild.
+    #[derive(Deserialize)]
+    struct GuildData {
+        id: Id<GuildMarker>,
+    }
+
+    /// Data of other events, which may have the ID of their guild.
+    #[derive(Deserialize)]
+    struct OtherData {
+        guild_id: Option<Id<GuildMarker>>,
+    }
+
+    match event_type {
+        "GUILD_CREATE" | "GUILD_DELETE" | "GUILD_UPDATE" => {
+            from_json::<Payload<GuildData>>(event).map(|payload| payload.d.id)
+        }
+        _ => from_json::<Payload<OtherData>>(event).and_then(|payload| payload.d.guild_id),
+    }
+}
+
+/// Deserialize a JSON encoded value, discarding errors.
+#[cfg(not(feature = "simd-json"))]
+fn from_json<T: DeserializeOwned>(json: &str) -> Option<T> {
+    serde_json::from_str(json).ok()
+}
+
+/// Deserialize a JSON encoded value, discarding errors.
+///
+/// simd-json deserializes in place, so the JSON is copied.
+#[cfg(feature = "simd-json")]
+fn from_json<T: DeserializeOwned>(json: &str) -> Option<T> {
+    simd_json::from_slice(&mut json.as_bytes().to_vec()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{guild_id, parse_in_guilds};
+    use crate::EventTypeFlags;
+    use std::collections::HashSet;
+    use twilight_model::id::Id;
+
+    #[test]
+    fn guild_id_dispatch() {
+        assert_eq!(
+            Some(Id::new(1)),
+            guild_id(
+                "GUILD_DELETE",
+                r#"{"op":0,"s":1,"t":"GUILD_DELETE","d":{"id":"1","unavailable":true}}"#
+            )
+        );
+        assert_eq!(
+            Some(Id::new(1)),
+            guild_id(
+                "TYPING_START",
+                r#"{"op":0,"s":1,"t":"TYPING_START","d":{"channel_id":"2","guild_id":"1","timestamp":0,"user_id":"3"}}"#
+            )
+        );
+        // Nested guild IDs aren't of the event.
+        assert!(guild_id(
+            "MESSAGE_CREATE",
+            r#"{"op":0,"s":1,"t":"MESSAGE_CREATE","d":{"id":"2","message_reference":{"guild_id":"1"}}}"#
+        )
+        .is_none());
+        assert!(guild_id("MESSAGE_CREATE", "not json").is_none());
+    }
+
+    #[test]
+    fn in_guilds() {
+        const EVENT: &str =
+            r#"{"op":0,"s":1,"t":"GUILD_DELETE","d":{"id":"1","unavailable":true}}"#;
+
+        let guild_ids = HashSet::from([Id::new(1)]);
+        assert!(
+            parse_in_guilds(EVENT.to_owned(), EventTypeFlags::all(), Some(&guild_ids))
+                .unwrap()
+                .is_some()
+        );
+
+        let guild_ids = HashSet::from([Id::new(2)]);
+        assert!(
+            parse_in_guilds(EVENT.to_owned(), EventTypeFlags::all(), Some(&guild_ids))
+                .unwrap()
+                .is_none()
+        );
+
+        // Events that aren't about a guild aren't skipped.
+        assert!(parse_in_guilds(
+            r#"{"op":11,"d":null}"#.to_owned(),
+            EventTypeFlags::all(),
+            Some(&guild_ids)
+        )
+        .unwrap()
+        .is_some());
+    }
+}
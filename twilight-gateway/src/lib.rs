@@ -28,6 +28,7 @@ mod ratelimiter;
 mod session;
 mod shard;
 mod stream;
+mod timed;
 
 #[cfg(any(feature = "zlib-stock", feature = "zlib-simd"))]
 pub use self::inflater::Inflater;
@@ -40,9 +41,10 @@ pub use self::{
     latency::Latency,
     message::Message,
     ratelimiter::CommandRatelimiter,
-    session::Session,
+    session::{Session, SessionStore},
     shard::{Shard, ShardState},
     stream::StreamExt,
+    timed::TimedEvent,
 };
 pub use twilight_model::gateway::{CloseFrame, Intents, ShardId};
 
@@ -1,7 +1,8 @@
 //! Active gateway session details.
 
 use serde::{Deserialize, Serialize};
-use std::mem;
+use std::{fmt::Debug, mem};
+use twilight_model::gateway::ShardId;
 
 /// Gateway session information for a shard's active connection.
 ///
@@ -18,6 +19,7 @@ use std::mem;
 /// application's process needs to be restarted, then this session
 /// information—which can be (de)serialized via serde—can be stored, the
 /// application restarted, and then used again via [`ConfigBuilder::session`].
+/// A [`SessionStore`] may be configured to do this automatically.
 ///
 /// If the delay between disconnecting from the gateway and reconnecting isn't
 /// too long and Discord hasn't invalidated the session, then the session will
@@ -91,6 +93,76 @@ impl Session {
     }
 }
 
+/// Storage of shards' sessions, for resuming them after the application
+/// restarts.
+///
+/// Configured via [`ConfigBuilder::session_store`], the shard loads its session
+/// from the store when it's created and saves it whenever it changes. This
+/// allows deployments where processes are replaced, such as rolling restarts,
+/// to resume sessions in the new process, as long as it runs the same shards.
+///
+/// # Examples
+///
+/// Keep shards' sessions in memory:
+///
+/// ```no_run
+/// use std::{collections::HashMap, sync::{Arc, Mutex}};
+/// use twilight_gateway::{ConfigBuilder, Intents, Session, SessionStore, Shard, ShardId};
+///
+/// #[derive(Debug, Default)]
+/// struct MemoryStore(Mutex<HashMap<ShardId, (Session, Option<String>)>>);
+///
+/// impl SessionStore for MemoryStore {
+///     fn load(&self, shard_id: ShardId) -> Option<(Session, Option<String>)> {
+///         self.0.lock().unwrap().get(&shard_id).cloned()
+///     }
+///
+///     fn save(&self, shard_id: ShardId, session: Option<&Session>, resume_url: Option<&str>) {
+///         let mut sessions = self.0.lock().unwrap();
+///
+///         if let Some(session) = session {
+///             sessions.insert(shard_id, (session.clone(), resume_url.map(ToOwned::to_owned)));
+///         } else {
+///             sessions.remove(&shard_id);
+///         }
+///     }
+/// }
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let token = std::env::var("DISCORD_TOKEN")?;
+/// let config = ConfigBuilder::new(token, Intents::GUILDS)
+///     .session_store(Arc::new(MemoryStore::default()))
+///     .build();
+/// let shard = Shard::with_config(ShardId::ONE, config);
+/// # Ok(()) }
+/// ```
+///
+/// [`ConfigBuilder::session_store`]: crate::ConfigBuilder::session_store
+pub trait SessionStore: Debug + Send + Sync {
+    /// Load the session of a shard and the URL to resume it at, if any.
+    ///
+    /// Only called when creating a shard without a configured
+    /// [`ConfigBuilder::session`].
+    ///
+    /// Defaults to not loading a session.
+    ///
+    /// [`ConfigBuilder::session`]: crate::ConfigBuilder::session
+    fn load(&self, shard_id: ShardId) -> Option<(Session, Option<String>)> {
+        let _ = shard_id;
+
+        None
+    }
+
+    /// Save the session of a shard and the URL to resume it at.
+    ///
+    /// Called whenever the session changes: when a new session is created,
+    /// when the sequence is updated by a received event, and with [`None`]
+    /// when the session is invalidated. As this is called for every dispatch
+    /// event it should be cheap, such as by writing to external storage in
+    /// the background.
+    fn save(&self, shard_id: ShardId, session: Option<&Session>, resume_url: Option<&str>);
+}
+
 #[cfg(test)]
 mod tests {
     use super::Session;
@@ -110,10 +110,12 @@ pub enum ShardState {
     ///
     /// Possible reasons may be due to [failed authentication],
     /// [invalid intents], or other reasons. Refer to the documentation for
-    /// [`CloseCode`] for possible reasons.
+    /// [`CloseCode`] for possible reasons. Shards also fatally close once
+    /// they reach the [maximum reconnect attempts].
     ///
     /// [failed authentication]: CloseCode::AuthenticationFailed
     /// [invalid intents]: CloseCode::InvalidIntents
+    /// [maximum reconnect attempts]: crate::ConfigBuilder::max_reconnect_attempts
     FatallyClosed,
     /// Shard is waiting to establish or resume a session.
     Identifying,
@@ -325,11 +327,16 @@ impl Shard {
 impl<Q> Shard<Q> {
     /// Create a new shard with the provided configuration.
     pub fn with_config(shard_id: ShardId, mut config: Config<Q>) -> Self {
-        let session = config.take_session();
+        let mut session = config.take_session();
         let mut resume_url = config.take_resume_url();
         //ensure resume_url is only used if we have a session to resume
         if session.is_none() {
-            resume_url = None;
+            (session, resume_url) = config
+                .session_store()
+                .and_then(|store| store.load(shard_id))
+                .map_or((None, None), |(session, resume_url)| {
+                    (Some(session), resume_url.map(String::into_boxed_str))
+                });
         }
 
         Self {
@@ -520,6 +527,7 @@ impl<Q> Shard<Q> {
             if matches!(frame.code, 1000 | 1001) {
                 self.resume_url = None;
                 self.session = None;
+                self.save_session();
             }
             self.pending = Some(Pending {
                 gateway_event: Some(Message::Close(Some(frame))),
@@ -528,6 +536,15 @@ impl<Q> Shard<Q> {
         }
     }
 
+    /// Save the session to the configured [`SessionStore`], if any.
+    ///
+    /// [`SessionStore`]: crate::SessionStore
+    fn save_session(&self) {
+        if let Some(store) = self.config.session_store() {
+            store.save(self.id, self.session.as_ref(), self.resume_url.as_deref());
+        }
+    }
+
     /// Parse a JSON message into an event with minimal data for [processing].
     ///
     /// # Errors
@@ -646,6 +663,7 @@ impl<Q: Queue> Shard<Q> {
 
                 if let Some(session) = self.session.as_mut() {
                     session.set_sequence(sequence);
+                    self.save_session();
                 }
             }
             Some(OpCode::Heartbeat) => {
@@ -729,13 +747,12 @@ impl<Q: Queue + Unpin> Stream for Shard<Q> {
         let message = loop {
             match self.state {
                 ShardState::FatallyClosed => {
-                    _ = ready!(Pin::new(
-                        self.connection
-                            .as_mut()
-                            .expect("poll_next called after Poll::Ready(None)")
-                    )
-                    .poll_close(cx));
-                    self.connection = None;
+                    // The connection is already closed if reconnecting failed.
+                    if let Some(connection) = self.connection.as_mut() {
+                        _ = ready!(Pin::new(connection).poll_close(cx));
+                        self.connection = None;
+                    }
+
                     return Poll::Ready(None);
                 }
                 ShardState::Disconnected { reconnect_attempts } if self.connection.is_none() => {
@@ -752,9 +769,13 @@ impl<Q: Queue + Unpin> Stream for Shard<Q> {
                         tracing::debug!(url = base_url, "connecting to gateway");
 
                         let tls = self.config.tls.clone();
+                        let delay = self
+                            .config
+                            .initial_reconnect_delay()
+                            .saturating_mul(2u32.saturating_pow(reconnect_attempts.into()))
+                            .min(self.config.max_reconnect_delay());
                         self.connection_future = Some(ConnectionFuture(Box::pin(async move {
-                            let secs = 2u8.saturating_pow(reconnect_attempts.into());
-                            time::sleep(Duration::from_secs(secs.into())).await;
+                            time::sleep(delay).await;
 
                             Ok(ClientBuilder::new()
                                 .uri(&uri)
@@ -779,8 +800,19 @@ impl<Q: Queue + Unpin> Stream for Shard<Q> {
                         }
                         Err(source) => {
                             self.resume_url = None;
-                            self.state = ShardState::Disconnected {
-                                reconnect_attempts: reconnect_attempts + 1,
+                            self.save_session();
+
+                            let reconnect_attempts = reconnect_attempts.saturating_add(1);
+                            self.state = if self
+                                .config
+                                .max_reconnect_attempts()
+                                .is_some_and(|max| reconnect_attempts >= max.get())
+                            {
+                                tracing::warn!(reconnect_attempts, "giving up reconnecting");
+
+                                ShardState::FatallyClosed
+                            } else {
+                                ShardState::Disconnected { reconnect_attempts }
                             };
 
                             return Poll::Ready(Some(Err(ReceiveMessageError {
@@ -972,9 +1004,94 @@ fn default_identify_properties() -> IdentifyProperties {
 #[cfg(test)]
 mod tests {
     use super::Shard;
+    use crate::{ConfigBuilder, Session, SessionStore};
     use static_assertions::{assert_impl_all, assert_not_impl_any};
-    use std::fmt::Debug;
+    use std::{
+        fmt::Debug,
+        sync::{Arc, Mutex},
+    };
+    use twilight_model::gateway::{Intents, ShardId};
 
     assert_impl_all!(Shard: Debug, Send);
     assert_not_impl_any!(Shard: Sync);
+
+    /// Store of a single session, recording saves.
+    #[derive(Debug, Default)]
+    struct Store {
+        /// Session to load.
+        loaded: Option<Session>,
+        /// Saved sessions and resume URLs.
+        saved: Mutex<Vec<(Option<Session>, Option<String>)>>,
+    }
+
+    impl SessionStore for Store {
+        fn load(&self, _: ShardId) -> Option<(Session, Option<String>)> {
+            self.loaded
+                .clone()
+                .map(|session| (session, Some("wss://resume.discord.gg".to_owned())))
+        }
+
+        fn save(&self, _: ShardId, session: Option<&Session>, resume_url: Option<&str>) {
+            self.saved
+                .lock()
+                .unwrap()
+                .push((session.cloned(), resume_url.map(ToOwned::to_owned)));
+        }
+    }
+
+    #[tokio::test]
+    async fn session_store_load() {
+        let session = Session::new(2, "id".to_owned());
+        let store = Arc::new(Store {
+            loaded: Some(session.clone()),
+            ..Store::default()
+        });
+
+        let config = ConfigBuilder::new(String::new(), Intents::empty())
+            .session_store(store.clone())
+            .build();
+        let shard = Shard::with_config(ShardId::ONE, config);
+        assert_eq!(Some(&session), shard.session());
+        assert_eq!(Some("wss://resume.discord.gg"), shard.resume_url());
+
+        // Configured sessions take precedence.
+        let other = Session::new(3, "other".to_owned());
+        let config = ConfigBuilder::new(String::new(), Intents::empty())
+            .session(other.clone())
+            .session_store(store)
+            .build();
+        let shard = Shard::with_config(ShardId::ONE, config);
+        assert_eq!(Some(&other), shard.session());
+        assert!(shard.resume_url().is_none());
+    }
+
+    #[tokio::test]
+    async fn session_store_save() {
+        let store = Arc::new(Store::default());
+        let config = ConfigBuilder::new(String::new(), Intents::empty())
+            .session_store(store.clone())
+            .build();
+        let mut shard = Shard::with_config(ShardId::ONE, config);
+
+        shard
+            .process(
+                r#"{"op":0,"s":1,"t":"READY","d":{"resume_gateway_url":"wss://resume.discord.gg","session_id":"id"}}"#,
+            )
+            .unwrap();
+        shard
+            .process(r#"{"op":0,"s":2,"t":"TYPING_START","d":{}}"#)
+            .unwrap();
+        shard.process(r#"{"op":9,"d":false}"#).unwrap();
+
+        let resume_url = Some("wss://resume.discord.gg".to_owned());
+        assert_eq!(
+            [
+                (Some(Session::new(1, "id".to_owned())), resume_url.clone()),
+                (Some(Session::new(2, "id".to_owned())), resume_url),
+                (None, None),
+            ]
+            .as_slice(),
+            store.saved.lock().unwrap().as_slice()
+        );
+    }
 }
@@ -2,6 +2,8 @@
 
 use crate::{error::ReceiveMessageError, EventTypeFlags, Message};
 use futures_core::Stream;
+use std::collections::HashSet;
+use twilight_model::id::{marker::GuildMarker, Id};
 
 /// An extension trait for the [`Stream`] trait.
 ///
@@ -70,29 +72,107 @@ pub trait StreamExt: Stream {
     {
         private::NextEvent::new(self, wanted_event_types)
     }
+
+    /// Consumes and returns the next wanted [`Event`] about one of a set of
+    /// guilds in the stream or `None` if the stream is finished.
+    ///
+    /// Events about other guilds are skipped after only parsing their guild
+    /// ID, which is useful when only a few of the bot's guilds are relevant.
+    /// Events that aren't about a guild, such as [`Event::Ready`] or direct
+    /// messages, are not skipped.
+    ///
+    /// Otherwise equivalent to [`next_event`], including its cancel safety.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use twilight_gateway::{Intents, Shard, ShardId};
+    /// # #[tokio::main] async fn main() {
+    /// # let mut shard = Shard::new(ShardId::ONE, String::new(), Intents::empty());
+    /// use std::collections::HashSet;
+    /// use twilight_gateway::{EventTypeFlags, StreamExt as _};
+    /// use twilight_model::id::Id;
+    ///
+    /// let guild_ids = HashSet::from([Id::new(1), Id::new(2)]);
+    ///
+    /// while let Some(item) = shard
+    ///     .next_guild_event(EventTypeFlags::all(), &guild_ids)
+    ///     .await
+    /// {
+    ///     let Ok(event) = item else {
+    ///         continue;
+    ///     };
+    ///
+    ///     tracing::debug!(guild_id = ?event.guild_id(), kind = ?event.kind(), "received event");
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [`Event`]: crate::Event
+    /// [`Event::Ready`]: crate::Event::Ready
+    /// [`next_event`]: Self::next_event
+    fn next_guild_event<'a>(
+        &'a mut self,
+        wanted_event_types: EventTypeFlags,
+        guild_ids: &'a HashSet<Id<GuildMarker>>,
+    ) -> private::NextEvent<'a, Self>
+    where
+        Self: Unpin,
+    {
+        private::NextEvent::with_guild_ids(self, wanted_event_types, guild_ids)
+    }
+
+    /// Consumes and returns the next wanted [`Event`] in the stream, paired
+    /// with when it was received, or `None` if the stream is finished.
+    ///
+    /// Otherwise equivalent to [`next_event`], including its cancel safety.
+    /// Refer to [`TimedEvent`] for measuring latency.
+    ///
+    /// [`Event`]: crate::Event
+    /// [`TimedEvent`]: crate::TimedEvent
+    /// [`next_event`]: Self::next_event
+    fn next_timed_event(
+        &mut self,
+        wanted_event_types: EventTypeFlags,
+    ) -> private::NextTimedEvent<'_, Self>
+    where
+        Self: Unpin,
+    {
+        private::NextTimedEvent::new(self, wanted_event_types)
+    }
 }
 
 impl<St: ?Sized> StreamExt for St where St: Stream<Item = Result<Message, ReceiveMessageError>> {}
 
 mod private {
-    //! Private module to hide the returned type from the [`next_event`](super::StreamExt::next_event)
-    //! method.
+    //! Private module to hide the returned types from the [`next_event`](super::StreamExt::next_event),
+    //! [`next_guild_event`](super::StreamExt::next_guild_event), and
+    //! [`next_timed_event`](super::StreamExt::next_timed_event) methods.
     //!
     //! Effectively disallows consumers from implementing the trait.
 
-    use crate::{error::ReceiveMessageError, json::parse, EventTypeFlags, Message};
+    use crate::{
+        error::ReceiveMessageError, json::parse_in_guilds, EventTypeFlags, Message, TimedEvent,
+    };
     use futures_core::Stream;
     use std::{
+        collections::HashSet,
         future::Future,
         pin::Pin,
         task::{ready, Context, Poll},
     };
-    use twilight_model::gateway::event::Event;
+    use twilight_model::{
+        gateway::event::Event,
+        id::{marker::GuildMarker, Id},
+    };
 
-    /// Future for the [`next_event`](super::StreamExt::next_event) method.
+    /// Future for the [`next_event`](super::StreamExt::next_event) and
+    /// [`next_guild_event`](super::StreamExt::next_guild_event) methods.
     pub struct NextEvent<'a, St: ?Sized> {
         /// Gateway event types to deserialize.
         events: EventTypeFlags,
+        /// Guilds whose events to deserialize, if limited.
+        guild_ids: Option<&'a HashSet<Id<GuildMarker>>>,
         /// Inner wrapped stream.
         stream: &'a mut St,
     }
@@ -100,7 +180,24 @@ mod private {
     impl<'a, St: ?Sized> NextEvent<'a, St> {
         /// Create a new future.
         pub fn new(stream: &'a mut St, events: EventTypeFlags) -> Self {
-            Self { events, stream }
+            Self {
+                events,
+                guild_ids: None,
+                stream,
+            }
+        }
+
+        /// Create a new future skipping events about other guilds.
+        pub fn with_guild_ids(
+            stream: &'a mut St,
+            events: EventTypeFlags,
+            guild_ids: &'a HashSet<Id<GuildMarker>>,
+        ) -> Self {
+            Self {
+                events,
+                guild_ids: Some(guild_ids),
+                stream,
+            }
         }
     }
 
@@ -111,8 +208,11 @@ mod private {
 
         fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
             let events = self.events;
+            let guild_ids = self.guild_ids;
             let try_from_message = |message| match message {
-                Message::Text(json) => parse(json, events).map(|opt| opt.map(Into::into)),
+                Message::Text(json) => {
+                    parse_in_guilds(json, events, guild_ids).map(|opt| opt.map(Into::into))
+                }
                 Message::Close(frame) => Ok(Some(Event::GatewayClose(frame))),
             };
 
@@ -128,4 +228,68 @@ mod private {
             }
         }
     }
+
+    /// Future for the [`next_timed_event`](super::StreamExt::next_timed_event)
+    /// method.
+    pub struct NextTimedEvent<'a, St: ?Sized> {
+        /// Future of the next event.
+        inner: NextEvent<'a, St>,
+    }
+
+    impl<'a, St: ?Sized> NextTimedEvent<'a, St> {
+        /// Create a new future.
+        pub fn new(stream: &'a mut St, events: EventTypeFlags) -> Self {
+            Self {
+                inner: NextEvent::new(stream, events),
+            }
+        }
+    }
+
+    impl<St: ?Sized + Stream<Item = Result<Message, ReceiveMessageError>> + Unpin> Future
+        for NextTimedEvent<'_, St>
+    {
+        type Output = Option<Result<TimedEvent, ReceiveMessageError>>;
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            let event = ready!(Pin::new(&mut self.inner).poll(cx));
+
+            Poll::Ready(event.map(|result| result.map(TimedEvent::new)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StreamExt;
+    use crate::{Event, EventTypeFlags, Message};
+    use std::collections::HashSet;
+    use twilight_model::id::Id;
+
+    #[tokio::test]
+    async fn next_guild_event() {
+        let messages = [
+            r#"{"op":0,"s":1,"t":"GUILD_DELETE","d":{"id":"2","unavailable":true}}"#,
+            r#"{"op":0,"s":2,"t":"GUILD_DELETE","d":{"id":"1","unavailable":true}}"#,
+            r#"{"op":0,"s":3,"t":"RESUMED","d":{}}"#,
+        ];
+        let mut stream =
+            tokio_stream::iter(messages.map(|message| Ok(Message::Text(message.to_owned()))));
+        let guild_ids = HashSet::from([Id::new(1)]);
+
+        let event = stream
+            .next_guild_event(EventTypeFlags::all(), &guild_ids)
+            .await;
+        assert!(matches!(event, Some(Ok(Event::GuildDelete(e))) if e.id == Id::new(1)));
+
+        // Events not about a guild aren't skipped.
+        let event = stream
+            .next_guild_event(EventTypeFlags::all(), &guild_ids)
+            .await;
+        assert!(matches!(event, Some(Ok(Event::Resumed))));
+
+        assert!(stream
+            .next_guild_event(EventTypeFlags::all(), &guild_ids)
+            .await
+            .is_none());
+    }
 }
@@ -0,0 +1,181 @@
+//! Events paired with when they were received, for measuring latency.
+
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use twilight_model::gateway::event::Event;
+
+/// Milliseconds since the Unix epoch of the first second of 2015, which
+/// snowflakes are relative to.
+const DISCORD_EPOCH: u64 = 1_420_070_400_000;
+
+/// [`Event`] paired with when it was received.
+///
+/// May be obtained via [`StreamExt::next_timed_event`].
+///
+/// # Examples
+///
+/// Warn when message events arrive more than a second after they were sent:
+///
+/// ```no_run
+/// # use twilight_gateway::{Intents, Shard, ShardId};
+/// # #[tokio::main] async fn main() {
+/// # let mut shard = Shard::new(ShardId::ONE, String::new(), Intents::empty());
+/// use std::time::Duration;
+/// use twilight_gateway::{EventTypeFlags, StreamExt as _};
+///
+/// while let Some(item) = shard.next_timed_event(EventTypeFlags::all()).await {
+///     let Ok(timed) = item else {
+///         continue;
+///     };
+///
+///     if timed.delay().is_some_and(|delay| delay > Duration::from_secs(1)) {
+///         tracing::warn!(delay = ?timed.delay(), "shard is falling behind");
+///     }
+///
+///     // Handle `timed.event()`, then measure how long it took.
+///     tracing::debug!(elapsed = ?timed.received_at().elapsed(), "handled event");
+/// }
+/// # }
+/// ```
+///
+/// [`StreamExt::next_timed_event`]: crate::StreamExt::next_timed_event
+#[derive(Clone, Debug)]
+pub struct TimedEvent {
+    /// Received event.
+    event: Event,
+    /// When the event was received, for measuring processing latency.
+    received_at: Instant,
+    /// When the event was received, for comparing with Discord timestamps.
+    received_at_system: SystemTime,
+}
+
+impl TimedEvent {
+    /// Pair an event with the current time.
+    pub(crate) fn new(event: Event) -> Self {
+        Self {
+            event,
+            received_at: Instant::now(),
+            received_at_system: SystemTime::now(),
+        }
+    }
+
+    /// Immutable reference to the event.
+    pub const fn event(&self) -> &Event {
+        &self.event
+    }
+
+    /// Consume the timed event, returning the event.
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn into_event(self) -> Event {
+        self.event
+    }
+
+    /// When the shard yielded the event.
+    pub const fn received_at(&self) -> Instant {
+        self.received_at
+    }
+
+    /// When Discord created the resource the event is about, derived from its
+    /// ID.
+    ///
+    /// Only events about newly created resources have a meaningful creation
+    /// time: [`ChannelCreate`], [`InteractionCreate`], [`MessageCreate`], and
+    /// [`ThreadCreate`]. Returns [`None`] for other events.
+    ///
+    /// [`ChannelCreate`]: Event::ChannelCreate
+    /// [`InteractionCreate`]: Event::InteractionCreate
+    /// [`MessageCreate`]: Event::MessageCreate
+    /// [`ThreadCreate`]: Event::ThreadCreate
+    pub fn created_at(&self) -> Option<SystemTime> {
+        let id = match &self.event {
+            Event::ChannelCreate(channel) => channel.id.get(),
+            Event::InteractionCreate(interaction) => interaction.id.get(),
+            Event::MessageCreate(message) => message.id.get(),
+            Event::ThreadCreate(thread) => thread.id.get(),
+            _ => return None,
+        };
+
+        UNIX_EPOCH.checked_add(Duration::from_millis((id >> 22) + DISCORD_EPOCH))
+    }
+
+    /// Time between Discord creating the resource the event is about and the
+    /// shard receiving the event.
+    ///
+    /// Large delays indicate that the shard is falling behind. The delay
+    /// includes the skew between Discord's and the local clock, and is
+    /// [`Duration::ZERO`] if the local clock is behind.
+    ///
+    /// Returns [`None`] if the event has no [creation time].
+    ///
+    /// [creation time]: Self::created_at
+    pub fn delay(&self) -> Option<Duration> {
+        let created_at = self.created_at()?;
+
+        Some(
+            self.received_at_system
+                .duration_since(created_at)
+                .unwrap_or_default(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TimedEvent;
+    use static_assertions::assert_impl_all;
+    use std::{
+        fmt::Debug,
+        time::{Duration, UNIX_EPOCH},
+    };
+    use twilight_model::{
+        gateway::{event::Event, payload::incoming::GuildDelete},
+        id::Id,
+    };
+
+    assert_impl_all!(TimedEvent: Clone, Debug, Send, Sync);
+
+    #[test]
+    fn created_at() {
+        let event = TimedEvent::new(Event::GuildDelete(GuildDelete {
+            id: Id::new(1),
+            unavailable: None,
+        }));
+        assert!(event.created_at().is_none());
+        assert!(event.delay().is_none());
+        assert!(matches!(event.into_event(), Event::GuildDelete(_)));
+    }
+
+    #[test]
+    fn created_at_message() {
+        let message = serde_json::from_str(
+            r#"{
+                "attachments": [],
+                "author": {
+                    "avatar": null,
+                    "discriminator": "0001",
+                    "id": "1",
+                    "username": "user"
+                },
+                "channel_id": "2",
+                "content": "",
+                "edited_timestamp": null,
+                "embeds": [],
+                "id": "175928847299117063",
+                "mention_everyone": false,
+                "mention_roles": [],
+                "mentions": [],
+                "pinned": false,
+                "timestamp": "2016-04-30T11:18:25.796000+00:00",
+                "tts": false,
+                "type": 0
+            }"#,
+        )
+        .unwrap();
+        let event = TimedEvent::new(Event::MessageCreate(Box::new(message)));
+
+        assert_eq!(
+            Some(UNIX_EPOCH + Duration::from_millis(1_462_015_105_796)),
+            event.created_at()
+        );
+        assert!(event.delay().unwrap() > Duration::ZERO);
+    }
+}
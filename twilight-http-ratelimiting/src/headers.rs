@@ -265,6 +265,9 @@ pub struct Present {
     reset_after: u64,
     /// When the bucket resets, as a Unix timestamp in milliseconds.
     reset: u64,
+    /// Number of milliseconds to wait before retrying, if the request was
+    /// ratelimited.
+    retry_after: Option<u64>,
     /// Scope of the ratelimit.
     scope: Option<RatelimitScope>,
 }
@@ -307,6 +310,13 @@ impl Present {
         self.reset
     }
 
+    /// Number of milliseconds to wait before retrying, if the request was
+    /// ratelimited.
+    #[must_use]
+    pub const fn retry_after(&self) -> Option<u64> {
+        self.retry_after
+    }
+
     /// Scope of the ratelimit.
     #[must_use]
     pub const fn scope(&self) -> Option<RatelimitScope> {
@@ -401,6 +411,20 @@ impl RatelimitHeaders {
         matches!(self, Self::Present(_))
     }
 
+    /// Set the number of milliseconds to wait before retrying a ratelimited
+    /// request.
+    ///
+    /// This is used to apply the more precise `retry_after` field of a 429
+    /// response's body in place of the `retry-after` header. Global
+    /// ratelimits are rounded up to the nearest second.
+    pub fn set_retry_after(&mut self, retry_after: u64) {
+        match self {
+            Self::Global(global) => global.retry_after = retry_after.div_ceil(1000),
+            Self::None => {}
+            Self::Present(present) => present.retry_after = Some(retry_after),
+        }
+    }
+
     /// Parse headers from an iterator of tuples containing the header name and
     /// value.
     ///
@@ -536,6 +560,7 @@ impl RatelimitHeaders {
             reset: reset.ok_or_else(|| HeaderParsingError::missing(HeaderName::Reset))?,
             reset_after: reset_after
                 .ok_or_else(|| HeaderParsingError::missing(HeaderName::ResetAfter))?,
+            retry_after: retry_after.map(|retry_after| retry_after.saturating_mul(1000)),
             scope,
         }))
     }
@@ -758,6 +783,11 @@ mod tests {
             RatelimitHeaders::Present(present)
             if present.reset == 1_470_173_023_123
         ));
+        assert!(matches!(
+            &headers,
+            RatelimitHeaders::Present(present)
+            if present.retry_after().is_none()
+        ));
         assert!(matches!(
             headers,
             RatelimitHeaders::Present(present)
@@ -767,6 +797,48 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn present_retry_after() -> Result<(), Box<dyn Error>> {
+        let headers = [
+            ("x-ratelimit-limit", "10".as_bytes()),
+            ("x-ratelimit-remaining", "0".as_bytes()),
+            ("x-ratelimit-reset", "1470173023.123".as_bytes()),
+            ("x-ratelimit-reset-after", "2.5".as_bytes()),
+            ("retry-after", "3".as_bytes()),
+            ("x-ratelimit-scope", "user".as_bytes()),
+        ];
+
+        let mut headers = RatelimitHeaders::from_pairs(headers.into_iter())?;
+        assert!(matches!(
+            &headers,
+            RatelimitHeaders::Present(present)
+            if present.retry_after() == Some(3_000)
+        ));
+
+        headers.set_retry_after(2_345);
+        assert!(matches!(
+            headers,
+            RatelimitHeaders::Present(present)
+            if present.retry_after() == Some(2_345)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn global_set_retry_after() -> Result<(), Box<dyn Error>> {
+        let headers = [
+            ("retry-after", "65".as_bytes()),
+            ("x-ratelimit-global", "true".as_bytes()),
+        ];
+
+        let mut headers = RatelimitHeaders::from_pairs(headers.into_iter())?;
+        headers.set_retry_after(64_001);
+        assert!(matches!(headers, RatelimitHeaders::Global(g) if g.retry_after() == 65));
+
+        Ok(())
+    }
+
     #[test]
     fn name() {
         assert_eq!("x-ratelimit-bucket", HeaderName::BUCKET);
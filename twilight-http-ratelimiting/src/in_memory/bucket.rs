@@ -3,22 +3,30 @@
 //! consumed by the [`BucketQueueTask`] that manages the ratelimit for the bucket
 //! and respects the global ratelimit.
 
-use super::GlobalLockPair;
-use crate::{headers::RatelimitHeaders, request::Path, ticket::TicketNotifier};
+use super::{GlobalLockPair, GlobalThrottle, RatelimitDelay};
+use crate::{
+    headers::{RatelimitHeaders, RatelimitScope},
+    request::Path,
+    ticket::TicketNotifier,
+};
 use std::{
     collections::HashMap,
+    future::{self, Future},
+    pin::pin,
     sync::{
-        atomic::{AtomicU64, Ordering},
+        atomic::{AtomicU64, AtomicUsize, Ordering},
         Arc, Mutex,
     },
-    time::{Duration, Instant},
+    task::Poll,
+    time::Duration,
 };
 use tokio::{
     sync::{
+        broadcast,
         mpsc::{self, UnboundedReceiver, UnboundedSender},
-        Mutex as AsyncMutex,
+        watch, Mutex as AsyncMutex,
     },
-    time::{sleep, timeout},
+    time::{sleep, timeout, Instant},
 };
 
 /// Time remaining until a bucket will reset.
@@ -40,8 +48,6 @@ pub struct Bucket {
     /// Total number of tickets allotted in a cycle.
     pub limit: AtomicU64,
     /// Path this ratelimit applies to.
-    // This is dead code, but it is useful for debugging.
-    #[allow(dead_code)]
     pub path: Path,
     /// Queue associated with this bucket.
     pub queue: BucketQueue,
@@ -94,7 +100,7 @@ impl Bucket {
 
         let elapsed = started_at.elapsed();
 
-        if elapsed > Duration::from_millis(reset_after) {
+        if elapsed >= Duration::from_millis(reset_after) {
             return TimeRemaining::Finished;
         }
 
@@ -121,26 +127,39 @@ impl Bucket {
         }
     }
 
+    /// Restore this bucket's ratelimit data from a previous snapshot, starting
+    /// the countdown now.
+    pub fn restore(&self, limit: u64, remaining: u64, reset_after: Duration) {
+        let reset_after = u64::try_from(reset_after.as_millis()).unwrap_or(u64::MAX);
+
+        self.limit.store(limit, Ordering::SeqCst);
+        self.remaining.store(remaining, Ordering::SeqCst);
+        self.reset_after.store(reset_after, Ordering::SeqCst);
+        self.started_at
+            .lock()
+            .expect("bucket poisoned")
+            .replace(Instant::now());
+    }
+
     /// Update this bucket's ratelimit data after a request has been made.
+    ///
+    /// When ratelimit headers are present the countdown is restarted from
+    /// their reset duration, as it may differ from the bucket's usual duration,
+    /// such as after encountering a 429 response.
     pub fn update(&self, ratelimits: Option<(u64, u64, u64)>) {
-        let bucket_limit = self.limit();
+        let mut started_at = self.started_at.lock().expect("bucket poisoned");
 
-        {
-            let mut started_at = self.started_at.lock().expect("bucket poisoned");
+        if let Some((limit, remaining, reset_after)) = ratelimits {
+            self.reset_after.store(reset_after, Ordering::SeqCst);
+            self.limit.store(limit, Ordering::SeqCst);
+            started_at.replace(Instant::now());
 
+            self.remaining.store(remaining, Ordering::Relaxed);
+        } else {
             if started_at.is_none() {
                 started_at.replace(Instant::now());
             }
-        }
 
-        if let Some((limit, remaining, reset_after)) = ratelimits {
-            if bucket_limit != limit && bucket_limit == u64::MAX {
-                self.reset_after.store(reset_after, Ordering::SeqCst);
-                self.limit.store(limit, Ordering::SeqCst);
-            }
-
-            self.remaining.store(remaining, Ordering::Relaxed);
-        } else {
             self.remaining.fetch_sub(1, Ordering::Relaxed);
         }
     }
@@ -149,32 +168,81 @@ impl Bucket {
 /// Queue of ratelimit requests for a bucket.
 #[derive(Debug)]
 pub struct BucketQueue {
-    /// Receiver for the ratelimit requests.
-    rx: AsyncMutex<UnboundedReceiver<TicketNotifier>>,
+    /// Number of ratelimit requests in the queue.
+    len: AtomicUsize,
+    /// Sender for the priority ratelimit requests.
+    priority_tx: UnboundedSender<TicketNotifier>,
+    /// Receivers for the priority and regular ratelimit requests.
+    rx: AsyncMutex<(
+        UnboundedReceiver<TicketNotifier>,
+        UnboundedReceiver<TicketNotifier>,
+    )>,
     /// Sender for the ratelimit requests.
     tx: UnboundedSender<TicketNotifier>,
 }
 
 impl BucketQueue {
     /// Add a new ratelimit request to the queue.
-    pub fn push(&self, tx: TicketNotifier) {
-        let _sent = self.tx.send(tx);
+    ///
+    /// Priority requests are received before all regular requests.
+    pub fn push(&self, tx: TicketNotifier, priority: bool) {
+        let queue = if priority {
+            &self.priority_tx
+        } else {
+            &self.tx
+        };
+
+        if queue.send(tx).is_ok() {
+            self.len.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Number of ratelimit requests in the queue.
+    pub fn len(&self) -> usize {
+        self.len.load(Ordering::Relaxed)
     }
 
     /// Receive the first incoming ratelimit request.
     pub async fn pop(&self, timeout_duration: Duration) -> Option<TicketNotifier> {
         let mut rx = self.rx.lock().await;
+        let (priority, regular) = &mut *rx;
+
+        let recv = future::poll_fn(|cx| {
+            if let Poll::Ready(Some(tx)) = priority.poll_recv(cx) {
+                return Poll::Ready(Some(tx));
+            }
+
+            regular.poll_recv(cx)
+        });
+        let tx = timeout(timeout_duration, recv).await.ok().flatten()?;
+        self.len.fetch_sub(1, Ordering::Relaxed);
+
+        Some(tx)
+    }
+
+    /// Close the queue, dropping queued ratelimit requests so that their
+    /// tickets are canceled.
+    pub async fn close(&self) {
+        let mut rx = self.rx.lock().await;
+        let (priority, regular) = &mut *rx;
+
+        priority.close();
+        regular.close();
+        while priority.try_recv().is_ok() || regular.try_recv().is_ok() {}
 
-        timeout(timeout_duration, rx.recv()).await.ok().flatten()
+        self.len.store(0, Ordering::Relaxed);
     }
 }
 
 impl Default for BucketQueue {
     fn default() -> Self {
+        let (priority_tx, priority_rx) = mpsc::unbounded_channel();
         let (tx, rx) = mpsc::unbounded_channel();
 
         Self {
-            rx: AsyncMutex::new(rx),
+            len: AtomicUsize::new(0),
+            priority_tx,
+            rx: AsyncMutex::new((priority_rx, rx)),
             tx,
         }
     }
@@ -188,10 +256,18 @@ pub(super) struct BucketQueueTask {
     bucket: Arc<Bucket>,
     /// All buckets managed by the associated [`super::InMemoryRatelimiter`].
     buckets: Arc<Mutex<HashMap<Path, Arc<Bucket>>>>,
+    /// Sender of notifications when requests are delayed.
+    delays: broadcast::Sender<RatelimitDelay>,
     /// Global ratelimit data.
     global: Arc<GlobalLockPair>,
+    /// Whether requests to the [`Path`] aren't bound to the global ratelimit.
+    global_exempt: bool,
     /// The [`Path`] this [`Bucket`] belongs to.
     path: Path,
+    /// Receiver of whether the ratelimiter was shut down.
+    shutdown: watch::Receiver<bool>,
+    /// Proactive throttle of global throughput, if it applies.
+    throttle: Option<Arc<GlobalThrottle>>,
 }
 
 impl BucketQueueTask {
@@ -199,29 +275,84 @@ impl BucketQueueTask {
     const WAIT: Duration = Duration::from_secs(10);
 
     /// Create a new task to manage the ratelimit for a [`Bucket`].
-    pub const fn new(
+    pub fn new(
         bucket: Arc<Bucket>,
         buckets: Arc<Mutex<HashMap<Path, Arc<Bucket>>>>,
+        delays: broadcast::Sender<RatelimitDelay>,
         global: Arc<GlobalLockPair>,
-        path: Path,
+        global_exempt: bool,
+        shutdown: watch::Receiver<bool>,
+        throttle: Option<Arc<GlobalThrottle>>,
     ) -> Self {
+        let path = bucket.path.clone();
+
         Self {
             bucket,
             buckets,
+            delays,
             global,
+            global_exempt,
             path,
+            shutdown,
+            throttle,
         }
     }
 
     /// Process incoming ratelimit requests to this bucket and update the state
-    /// based on received [`RatelimitHeaders`].
+    /// based on received [`RatelimitHeaders`], until the queue is idle or the
+    /// ratelimiter is shut down.
     #[tracing::instrument(name = "background queue task", skip(self), fields(path = ?self.path))]
     pub async fn run(self) {
+        let mut shutdown_rx = self.shutdown.clone();
+
+        // Processing must be dropped before closing the queue, as it may hold
+        // the queue's lock.
+        let is_shutdown = {
+            let mut process = pin!(self.process());
+            // Errors if the ratelimiter was dropped, which is also a shutdown.
+            let mut shutdown = pin!(shutdown_rx.wait_for(|shutdown| *shutdown));
+
+            future::poll_fn(|cx| {
+                if process.as_mut().poll(cx).is_ready() {
+                    return Poll::Ready(false);
+                }
+
+                shutdown.as_mut().poll(cx).map(|_| true)
+            })
+            .await
+        };
+
+        if is_shutdown {
+            tracing::debug!("ratelimiter shut down, canceling queued tickets");
+            self.bucket.queue.close().await;
+        } else {
+            tracing::debug!("bucket appears finished, removing");
+        }
+
+        self.buckets
+            .lock()
+            .expect("ratelimit buckets poisoned")
+            .remove(&self.path);
+    }
+
+    /// Process incoming ratelimit requests until the queue is idle.
+    async fn process(&self) {
         while let Some(queue_tx) = self.next().await {
-            if self.global.is_locked() {
+            if !self.global_exempt && self.global.is_locked() {
                 drop(self.global.0.lock().await);
             }
 
+            // Don't use up the throttle's window on canceled tickets.
+            if queue_tx.is_canceled() {
+                tracing::debug!("ticket canceled");
+
+                continue;
+            }
+
+            if let Some(throttle) = &self.throttle {
+                throttle.acquire().await;
+            }
+
             let Some(ticket_headers) = queue_tx.available() else {
                 continue;
             };
@@ -241,37 +372,55 @@ impl BucketQueueTask {
                 }
             }
         }
-
-        tracing::debug!("bucket appears finished, removing");
-
-        self.buckets
-            .lock()
-            .expect("ratelimit buckets poisoned")
-            .remove(&self.path);
     }
 
     /// Update the bucket's ratelimit state.
+    ///
+    /// If the request was ratelimited the bucket's queue is paused until it
+    /// may be retried. Ratelimits on shared resources never lock the global
+    /// ratelimit, as they don't count towards it.
     async fn handle_headers(&self, headers: &RatelimitHeaders) {
-        let ratelimits = match headers {
+        let (ratelimits, retry_after) = match headers {
             RatelimitHeaders::Global(global) => {
-                self.lock_global(Duration::from_secs(global.retry_after()))
-                    .await;
+                let wait = Duration::from_secs(global.retry_after());
+
+                if global.scope() == Some(RatelimitScope::Shared) {
+                    (None, Some(wait))
+                } else {
+                    self.lock_global(wait).await;
 
-                None
+                    (None, None)
+                }
             }
             RatelimitHeaders::None => return,
-            RatelimitHeaders::Present(present) => {
-                Some((present.limit(), present.remaining(), present.reset_after()))
-            }
+            RatelimitHeaders::Present(present) => (
+                Some((present.limit(), present.remaining(), present.reset_after())),
+                present.retry_after().map(Duration::from_millis),
+            ),
         };
 
         tracing::debug!(path=?self.path, "updating bucket");
         self.bucket.update(ratelimits);
+
+        if let Some(wait) = retry_after {
+            tracing::debug!(
+                path=?self.path,
+                milliseconds=%wait.as_millis(),
+                "request got ratelimited, waiting to retry",
+            );
+            let _sent = self.delays.send(RatelimitDelay::Bucket {
+                path: self.path.clone(),
+                queued: self.bucket.queue.len(),
+                wait,
+            });
+            sleep(wait).await;
+        }
     }
 
     /// Lock the global ratelimit for a specified duration.
     async fn lock_global(&self, wait: Duration) {
         tracing::debug!(path=?self.path, "request got global ratelimited");
+        let _sent = self.delays.send(RatelimitDelay::Global { wait });
         self.global.lock();
         let lock = self.global.0.lock().await;
         sleep(wait).await;
@@ -315,6 +464,12 @@ impl BucketQueueTask {
             "waiting for ratelimit to pass",
         );
 
+        let _sent = self.delays.send(RatelimitDelay::Bucket {
+            path: self.path.clone(),
+            queued: self.bucket.queue.len(),
+            wait,
+        });
+
         sleep(wait).await;
 
         tracing::debug!("done waiting for ratelimit to pass");
@@ -322,3 +477,75 @@ impl BucketQueueTask {
         self.bucket.try_reset();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Bucket, TimeRemaining};
+    use crate::request::Path;
+    use std::time::Duration;
+    use tokio::time;
+
+    #[tokio::test(start_paused = true)]
+    async fn try_reset() {
+        let bucket = Bucket::new(Path::ChannelsIdMessages(1));
+        assert!(!bucket.try_reset());
+
+        bucket.update(Some((5, 0, 1_000)));
+        assert!(matches!(
+            bucket.time_remaining(),
+            TimeRemaining::Some(remaining) if remaining == Duration::from_secs(1),
+        ));
+
+        time::advance(Duration::from_millis(500)).await;
+        assert!(!bucket.try_reset());
+        assert!(matches!(
+            bucket.time_remaining(),
+            TimeRemaining::Some(remaining) if remaining == Duration::from_millis(500),
+        ));
+
+        time::advance(Duration::from_millis(500)).await;
+        assert!(matches!(bucket.time_remaining(), TimeRemaining::Finished));
+        assert!(bucket.try_reset());
+        assert_eq!(5, bucket.remaining());
+        assert!(matches!(bucket.time_remaining(), TimeRemaining::NotStarted));
+    }
+
+    #[test]
+    fn update_reset_after() {
+        let bucket = Bucket::new(Path::ChannelsIdMessages(1));
+        assert!(matches!(bucket.time_remaining(), TimeRemaining::NotStarted));
+
+        bucket.update(Some((5, 4, 1_000)));
+        assert_eq!(5, bucket.limit());
+        assert_eq!(4, bucket.remaining());
+        assert_eq!(1_000, bucket.reset_after());
+
+        // A 429 response may carry a longer reset than the bucket's usual one.
+        bucket.update(Some((5, 0, 60_000)));
+        assert_eq!(0, bucket.remaining());
+        assert_eq!(60_000, bucket.reset_after());
+        assert!(matches!(
+            bucket.time_remaining(),
+            TimeRemaining::Some(remaining) if remaining > Duration::from_secs(59),
+        ));
+    }
+
+    #[test]
+    fn update_limit() {
+        let bucket = Bucket::new(Path::ChannelsIdMessages(1));
+
+        bucket.update(Some((5, 4, 1_000)));
+        assert_eq!(5, bucket.limit());
+
+        // Discord may tighten a bucket, which must take effect immediately.
+        bucket.update(Some((2, 1, 500)));
+        assert_eq!(2, bucket.limit());
+        assert_eq!(1, bucket.remaining());
+        assert_eq!(500, bucket.reset_after());
+
+        bucket.update(Some((10, 9, 2_000)));
+        assert_eq!(10, bucket.limit());
+        assert_eq!(9, bucket.remaining());
+        assert_eq!(2_000, bucket.reset_after());
+    }
+}
@@ -2,24 +2,61 @@
 
 mod bucket;
 
-use self::bucket::{Bucket, BucketQueueTask};
+use self::bucket::{Bucket, BucketQueueTask, TimeRemaining};
 use super::{
     ticket::{self, TicketNotifier},
     Bucket as InfoBucket, Ratelimiter,
 };
 use crate::{
-    request::Path, GetBucketFuture, GetTicketFuture, HasBucketFuture, IsGloballyLockedFuture,
+    request::Path, GenericError, GetBucketFuture, GetTicketFuture, HasBucketFuture,
+    IsGloballyLockedFuture, WouldExceedDeadline,
 };
 use std::{
     collections::hash_map::{Entry, HashMap},
     future,
+    num::NonZeroU32,
     sync::{
         atomic::{AtomicBool, Ordering},
         Arc, Mutex,
     },
-    time::Duration,
+    time::{Duration, SystemTime},
 };
-use tokio::sync::Mutex as AsyncMutex;
+use tokio::{
+    sync::{broadcast, watch, Mutex as AsyncMutex},
+    time::{sleep, Instant},
+};
+
+/// Number of [`RatelimitDelay`]s buffered for subscribers that haven't received
+/// them yet.
+const DELAYS_CAPACITY: usize = 64;
+
+/// Default number of requests that may be sent per second across all buckets.
+///
+/// This is Discord's default global ratelimit; some large bots have a raised
+/// limit.
+pub const GLOBAL_LIMIT_DEFAULT: u32 = 50;
+
+/// Notification that requests are being held back by a ratelimit.
+///
+/// Received by subscribing via [`InMemoryRatelimiter::delays`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum RatelimitDelay {
+    /// Requests to a path are waiting for its bucket to refresh.
+    Bucket {
+        /// Path the bucket applies to.
+        path: Path,
+        /// Number of requests waiting in the bucket's queue.
+        queued: usize,
+        /// Duration until the bucket refreshes.
+        wait: Duration,
+    },
+    /// Global ratelimit was exhausted, holding back all requests bound to it.
+    Global {
+        /// Duration until the global ratelimit refreshes.
+        wait: Duration,
+    },
+}
 
 /// Global lock. We use a pair to avoid actually locking the mutex every check.
 /// This allows futures to only wait on the global lock when a global ratelimit
@@ -45,21 +82,102 @@ impl GlobalLockPair {
     }
 }
 
+/// Proactive throttle of the number of requests sent per second across all
+/// buckets.
+#[derive(Debug)]
+struct GlobalThrottle {
+    /// Maximum number of requests per second.
+    limit: u32,
+    /// When the current one second window started and the number of requests
+    /// sent in it.
+    window: AsyncMutex<(Instant, u32)>,
+}
+
+impl GlobalThrottle {
+    /// Duration of a window.
+    const WINDOW: Duration = Duration::from_secs(1);
+
+    /// Create a new throttle with a limit of requests per second.
+    fn new(limit: NonZeroU32) -> Self {
+        Self {
+            limit: limit.get(),
+            window: AsyncMutex::new((Instant::now(), 0)),
+        }
+    }
+
+    /// Wait until a request may be sent without exceeding the limit.
+    async fn acquire(&self) {
+        let mut window = self.window.lock().await;
+        let (started_at, count) = &mut *window;
+
+        if started_at.elapsed() >= Self::WINDOW {
+            *started_at = Instant::now();
+            *count = 0;
+        }
+
+        if *count >= self.limit {
+            tracing::debug!("global throughput exhausted, waiting for next window");
+            sleep(Self::WINDOW.saturating_sub(started_at.elapsed())).await;
+
+            *started_at = Instant::now();
+            *count = 0;
+        }
+
+        *count += 1;
+    }
+}
+
+/// Snapshot of a bucket's ratelimit state.
+///
+/// Created via [`InMemoryRatelimiter::snapshot`] and restored via
+/// [`InMemoryRatelimiter::restore`], such as to persist ratelimits across a
+/// restart. Serialization is available via the `serde` feature.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct BucketSnapshot {
+    /// Total number of tickets allotted in a cycle.
+    pub limit: u64,
+    /// Path the bucket applies to.
+    pub path: Path,
+    /// Number of tickets remaining.
+    pub remaining: u64,
+    /// When the bucket resets.
+    pub reset_at: SystemTime,
+}
+
 /// Default ratelimiter implementation used in twilight that
 /// stores ratelimit information in an in-memory mapping.
 ///
+/// Requests are proactively throttled to [`GLOBAL_LIMIT_DEFAULT`] requests
+/// per second across all buckets, which can be configured via
+/// [`with_global_limit`]. Interaction and webhook requests aren't bound to the
+/// global ratelimit, so they aren't throttled or held back while the global
+/// ratelimit is exhausted.
+///
 /// This will meet most users' needs for simple ratelimiting,
 /// but for multi-processed bots, consider either implementing
 /// your own [`Ratelimiter`] that uses a shared storage backend
 /// or use the [HTTP proxy].
 ///
+/// [`with_global_limit`]: Self::with_global_limit
 /// [HTTP proxy]: https://twilight.rs/chapter_2_multi-serviced_approach.html#http-proxy-ratelimiting
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug)]
 pub struct InMemoryRatelimiter {
     /// Mapping of [`Path`]s to their associated [`Bucket`]s.
     buckets: Arc<Mutex<HashMap<Path, Arc<Bucket>>>>,
+    /// Sender of notifications when requests are delayed.
+    delays: broadcast::Sender<RatelimitDelay>,
     /// Global ratelimit data.
     global: Arc<GlobalLockPair>,
+    /// Restored snapshots of buckets that have not been created yet.
+    restored: Arc<Mutex<HashMap<Path, BucketSnapshot>>>,
+    /// Sender of whether the ratelimiter was shut down.
+    ///
+    /// Dropped along with the last clone of the ratelimiter, which also shuts
+    /// down its bucket queue tasks.
+    shutdown: Arc<watch::Sender<bool>>,
+    /// Proactive throttle of global throughput, if enabled.
+    throttle: Option<Arc<GlobalThrottle>>,
 }
 
 impl InMemoryRatelimiter {
@@ -72,17 +190,163 @@ impl InMemoryRatelimiter {
         Self::default()
     }
 
+    /// Create a new in-memory ratelimiter with a custom number of requests
+    /// that may be sent per second across all buckets.
+    ///
+    /// Passing `None` disables proactive throttling, only respecting global
+    /// ratelimits once Discord reports them.
+    ///
+    /// # Examples
+    ///
+    /// Create a ratelimiter for a bot with a raised global limit:
+    ///
+    /// ```
+    /// use std::num::NonZeroU32;
+    /// use twilight_http_ratelimiting::InMemoryRatelimiter;
+    ///
+    /// let ratelimiter = InMemoryRatelimiter::with_global_limit(NonZeroU32::new(500));
+    /// ```
+    #[must_use]
+    pub fn with_global_limit(limit: Option<NonZeroU32>) -> Self {
+        Self {
+            buckets: Arc::default(),
+            delays: broadcast::channel(DELAYS_CAPACITY).0,
+            global: Arc::default(),
+            restored: Arc::default(),
+            shutdown: Arc::new(watch::channel(false).0),
+            throttle: limit.map(|limit| Arc::new(GlobalThrottle::new(limit))),
+        }
+    }
+
+    /// Subscribe to notifications of requests being delayed by ratelimits,
+    /// such as to alert on commands that are being abused.
+    ///
+    /// Notifications are only sent while there is a subscriber. A subscriber
+    /// that falls too far behind misses the oldest notifications, receiving a
+    /// [`RecvError::Lagged`] error instead.
+    ///
+    /// # Examples
+    ///
+    /// Log requests that are delayed by more than a second:
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use twilight_http_ratelimiting::{in_memory::RatelimitDelay, InMemoryRatelimiter};
+    ///
+    /// # #[tokio::main] async fn main() {
+    /// let ratelimiter = InMemoryRatelimiter::new();
+    /// let mut delays = ratelimiter.delays();
+    ///
+    /// while let Ok(delay) = delays.recv().await {
+    ///     if let RatelimitDelay::Bucket { path, queued, wait, .. } = delay {
+    ///         if wait > Duration::from_secs(1) {
+    ///             println!("{queued} requests to {path:?} delayed by {wait:?}");
+    ///         }
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [`RecvError::Lagged`]: tokio::sync::broadcast::error::RecvError::Lagged
+    pub fn delays(&self) -> broadcast::Receiver<RatelimitDelay> {
+        self.delays.subscribe()
+    }
+
+    /// Snapshot the state of buckets that have not yet reset.
+    ///
+    /// # Examples
+    ///
+    /// Snapshot the ratelimiter's state on shutdown and restore it on startup:
+    ///
+    /// ```
+    /// use twilight_http_ratelimiting::InMemoryRatelimiter;
+    ///
+    /// let ratelimiter = InMemoryRatelimiter::new();
+    /// // Persist the snapshots, such as by serializing them to a file.
+    /// let snapshots = ratelimiter.snapshot();
+    ///
+    /// let ratelimiter = InMemoryRatelimiter::new();
+    /// ratelimiter.restore(snapshots);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buckets' lock is poisoned.
+    pub fn snapshot(&self) -> Vec<BucketSnapshot> {
+        let now = SystemTime::now();
+        let buckets = self.buckets.lock().expect("buckets poisoned");
+
+        buckets
+            .iter()
+            .filter_map(|(path, bucket)| {
+                let TimeRemaining::Some(remaining) = bucket.time_remaining() else {
+                    return None;
+                };
+
+                Some(BucketSnapshot {
+                    limit: bucket.limit(),
+                    path: path.clone(),
+                    remaining: bucket.remaining(),
+                    reset_at: now + remaining,
+                })
+            })
+            .collect()
+    }
+
+    /// Restore bucket snapshots, such as those persisted by a previous
+    /// process.
+    ///
+    /// Snapshots that have already reset are ignored. Restored state is applied
+    /// once a request is made to the snapshot's path and replaces the state of
+    /// an existing bucket, if there is one. Snapshots of paths that reset
+    /// before being requested are dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buckets' lock is poisoned.
+    pub fn restore(&self, snapshots: impl IntoIterator<Item = BucketSnapshot>) {
+        let now = SystemTime::now();
+        let buckets = self.buckets.lock().expect("buckets poisoned");
+        let mut restored = self.restored.lock().expect("restored buckets poisoned");
+        restored.retain(|_, snapshot| snapshot.reset_at > now);
+
+        for snapshot in snapshots {
+            let Ok(reset_after) = snapshot.reset_at.duration_since(now) else {
+                continue;
+            };
+
+            if let Some(bucket) = buckets.get(&snapshot.path) {
+                bucket.restore(snapshot.limit, snapshot.remaining, reset_after);
+            } else {
+                restored.insert(snapshot.path.clone(), snapshot);
+            }
+        }
+    }
+
+    /// Shut down the ratelimiter, canceling queued and future tickets of all
+    /// its clones.
+    ///
+    /// Canceled tickets' [`TicketReceiver`]s resolve to an error. This also
+    /// happens when the last clone of the ratelimiter is dropped, such as
+    /// along with the HTTP client using it, instead of queued tickets waiting
+    /// for their bucket.
+    ///
+    /// [`TicketReceiver`]: crate::ticket::TicketReceiver
+    pub fn shutdown(&self) {
+        self.shutdown.send_replace(true);
+    }
+
     /// Enqueue the [`TicketNotifier`] to the [`Path`]'s [`Bucket`].
     ///
     /// Returns the new [`Bucket`] if none existed.
-    fn entry(&self, path: Path, tx: TicketNotifier) -> Option<Arc<Bucket>> {
+    fn entry(&self, path: Path, tx: TicketNotifier, priority: bool) -> Option<Arc<Bucket>> {
         let mut buckets = self.buckets.lock().expect("buckets poisoned");
 
         match buckets.entry(path.clone()) {
             Entry::Occupied(bucket) => {
                 tracing::debug!("got existing bucket: {path:?}");
 
-                bucket.get().queue.push(tx);
+                bucket.get().queue.push(tx, priority);
 
                 tracing::debug!("added request into bucket queue: {path:?}");
 
@@ -92,7 +356,26 @@ impl InMemoryRatelimiter {
                 tracing::debug!("making new bucket for path: {path:?}");
 
                 let bucket = Bucket::new(path);
-                bucket.queue.push(tx);
+
+                let snapshot = {
+                    let now = SystemTime::now();
+                    let mut restored = self.restored.lock().expect("restored buckets poisoned");
+
+                    // Forget the snapshots of paths that reset without being
+                    // requested so that they don't accumulate.
+                    restored.retain(|_, snapshot| snapshot.reset_at > now);
+
+                    restored.remove(&bucket.path)
+                };
+
+                if let Some(snapshot) = snapshot {
+                    if let Ok(reset_after) = snapshot.reset_at.duration_since(SystemTime::now()) {
+                        tracing::debug!("restoring bucket state for path: {:?}", bucket.path);
+                        bucket.restore(snapshot.limit, snapshot.remaining, reset_after);
+                    }
+                }
+
+                bucket.queue.push(tx, priority);
 
                 let bucket = Arc::new(bucket);
                 entry.insert(Arc::clone(&bucket));
@@ -101,6 +384,57 @@ impl InMemoryRatelimiter {
             }
         }
     }
+
+    /// Queue a ticket for the [`Path`], spawning its [`BucketQueueTask`] if
+    /// its [`Bucket`] is new.
+    fn queue(&self, path: Path, priority: bool) -> GetTicketFuture {
+        tracing::debug!("getting bucket for path: {path:?}");
+
+        let (tx, rx) = ticket::channel();
+
+        if *self.shutdown.borrow() {
+            tracing::debug!("ratelimiter shut down, canceling ticket");
+
+            return Box::pin(future::ready(Ok(rx)));
+        }
+
+        // Interactions and webhooks aren't bound to the global ratelimit.
+        let global_exempt = matches!(
+            path,
+            Path::InteractionCallback(_)
+                | Path::WebhooksIdToken(..)
+                | Path::WebhooksIdTokenMessagesId(..)
+        );
+
+        if let Some(bucket) = self.entry(path, tx, priority) {
+            let throttle = if global_exempt {
+                None
+            } else {
+                self.throttle.clone()
+            };
+
+            tokio::spawn(
+                BucketQueueTask::new(
+                    bucket,
+                    Arc::clone(&self.buckets),
+                    self.delays.clone(),
+                    Arc::clone(&self.global),
+                    global_exempt,
+                    self.shutdown.subscribe(),
+                    throttle,
+                )
+                .run(),
+            );
+        }
+
+        Box::pin(future::ready(Ok(rx)))
+    }
+}
+
+impl Default for InMemoryRatelimiter {
+    fn default() -> Self {
+        Self::with_global_limit(NonZeroU32::new(GLOBAL_LIMIT_DEFAULT))
+    }
 }
 
 impl Ratelimiter for InMemoryRatelimiter {
@@ -119,7 +453,7 @@ impl Ratelimiter for InMemoryRatelimiter {
                         bucket.limit(),
                         bucket.remaining(),
                         reset_after,
-                        *started_at,
+                        started_at.map(Instant::into_std),
                     )))))
                 },
             )
@@ -140,22 +474,415 @@ impl Ratelimiter for InMemoryRatelimiter {
     }
 
     fn ticket(&self, path: Path) -> GetTicketFuture {
-        tracing::debug!("getting bucket for path: {path:?}");
+        self.queue(path, false)
+    }
 
-        let (tx, rx) = ticket::channel();
+    fn priority_ticket(&self, path: Path) -> GetTicketFuture {
+        self.queue(path, true)
+    }
 
-        if let Some(bucket) = self.entry(path.clone(), tx) {
-            tokio::spawn(
-                BucketQueueTask::new(
-                    bucket,
-                    Arc::clone(&self.buckets),
-                    Arc::clone(&self.global),
-                    path,
-                )
-                .run(),
-            );
+    fn ticket_with_deadline(&self, path: Path, deadline: std::time::Instant) -> GetTicketFuture {
+        let ratelimiter = self.clone();
+
+        Box::pin(async move {
+            let wait = ratelimiter
+                .bucket(&path)
+                .await?
+                .filter(|bucket| bucket.remaining() == 0)
+                .and_then(|bucket| bucket.time_remaining());
+
+            if let Some(wait) = wait {
+                if crate::now() + wait > deadline {
+                    return Err(Box::new(WouldExceedDeadline::new(wait)) as GenericError);
+                }
+            }
+
+            ratelimiter.ticket(path).await
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BucketSnapshot, GlobalThrottle, InMemoryRatelimiter, RatelimitDelay};
+    use crate::{request::Path, RatelimitHeaders, Ratelimiter, WouldExceedDeadline};
+    use static_assertions::assert_impl_all;
+    use std::{
+        error::Error,
+        fmt::Debug,
+        hash::Hash,
+        num::NonZeroU32,
+        time::{Duration, SystemTime},
+    };
+    use tokio::time::{self, timeout, Instant};
+
+    assert_impl_all!(BucketSnapshot: Clone, Debug, Eq, Hash, PartialEq, Send, Sync);
+    assert_impl_all!(InMemoryRatelimiter: Clone, Debug, Default, Send, Sync);
+    assert_impl_all!(RatelimitDelay: Clone, Debug, Eq, PartialEq, Send, Sync);
+
+    #[tokio::test(start_paused = true)]
+    async fn global_throttle() {
+        let throttle = GlobalThrottle::new(NonZeroU32::new(2).unwrap());
+        let start = Instant::now();
+
+        throttle.acquire().await;
+        throttle.acquire().await;
+        assert!(start.elapsed() < Duration::from_millis(100));
+
+        throttle.acquire().await;
+        assert!(start.elapsed() >= Duration::from_millis(900));
+    }
+
+    /// Test that canceled tickets don't use up the throttle's window.
+    #[tokio::test(start_paused = true)]
+    async fn global_throttle_canceled() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let path = Path::ChannelsIdMessages(1);
+        let ratelimiter = InMemoryRatelimiter::with_global_limit(NonZeroU32::new(1));
+
+        drop(ratelimiter.ticket(path.clone()).await?);
+
+        let start = Instant::now();
+        let sender = ratelimiter.ticket(path).await?.await?;
+        assert!(start.elapsed() < Duration::from_millis(100));
+        drop(sender);
+
+        Ok(())
+    }
+
+    /// Test that a ratelimited request on a shared resource pauses its bucket
+    /// until it may be retried, without locking the global ratelimit.
+    #[tokio::test]
+    async fn shared_retry_after() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let path = Path::ChannelsIdMessages(1);
+        let ratelimiter = InMemoryRatelimiter::new();
+
+        let sender = ratelimiter.ticket(path.clone()).await?.await?;
+        let mut headers = RatelimitHeaders::from_pairs(
+            [
+                ("retry-after", "1".as_bytes()),
+                ("x-ratelimit-limit", "5".as_bytes()),
+                ("x-ratelimit-remaining", "4".as_bytes()),
+                ("x-ratelimit-reset", "0".as_bytes()),
+                ("x-ratelimit-reset-after", "1".as_bytes()),
+                ("x-ratelimit-scope", "shared".as_bytes()),
+            ]
+            .into_iter(),
+        )?;
+        // As applied from the body of the 429 response.
+        headers.set_retry_after(200);
+        let start = Instant::now();
+        sender.headers(Some(headers)).unwrap();
+
+        drop(ratelimiter.ticket(path).await?.await?);
+        assert!(start.elapsed() >= Duration::from_millis(200));
+        assert!(!ratelimiter.is_globally_locked().await?);
+
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn bucket_reset() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let path = Path::ChannelsIdMessages(1);
+        let ratelimiter = InMemoryRatelimiter::new();
+
+        let sender = ratelimiter.ticket(path.clone()).await?.await?;
+        let headers = RatelimitHeaders::from_pairs(
+            [
+                ("x-ratelimit-limit", "5".as_bytes()),
+                ("x-ratelimit-remaining", "0".as_bytes()),
+                ("x-ratelimit-reset", "0".as_bytes()),
+                ("x-ratelimit-reset-after", "2.5".as_bytes()),
+            ]
+            .into_iter(),
+        )?;
+        sender.headers(Some(headers)).unwrap();
+
+        let start = Instant::now();
+        drop(ratelimiter.ticket(path.clone()).await?.await?);
+        assert_eq!(Duration::from_millis(2_500), start.elapsed());
+
+        let bucket = ratelimiter.bucket(&path).await?.expect("bucket exists");
+        assert_eq!(5, bucket.remaining());
+
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn global_lock() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let ratelimiter = InMemoryRatelimiter::new();
+
+        let sender = ratelimiter
+            .ticket(Path::ChannelsIdMessages(1))
+            .await?
+            .await?;
+        let headers = RatelimitHeaders::from_pairs(
+            [
+                ("retry-after", "3".as_bytes()),
+                ("x-ratelimit-global", "true".as_bytes()),
+            ]
+            .into_iter(),
+        )?;
+        let start = Instant::now();
+        sender.headers(Some(headers)).unwrap();
+
+        time::sleep(Duration::from_secs(1)).await;
+        assert!(ratelimiter.is_globally_locked().await?);
+
+        drop(
+            ratelimiter
+                .ticket(Path::ChannelsIdMessages(2))
+                .await?
+                .await?,
+        );
+        assert_eq!(Duration::from_secs(3), start.elapsed());
+        assert!(!ratelimiter.is_globally_locked().await?);
+
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn queue_timeout() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let path = Path::ChannelsIdMessages(1);
+        let ratelimiter = InMemoryRatelimiter::new();
+
+        drop(ratelimiter.ticket(path.clone()).await?.await?);
+        assert!(ratelimiter.has(&path).await?);
+
+        // Buckets are removed once their queue has been idle for a while.
+        time::sleep(Duration::from_secs(11)).await;
+        assert!(!ratelimiter.has(&path).await?);
+
+        Ok(())
+    }
+
+    /// Create a ratelimiter whose bucket for a path is exhausted for a minute.
+    fn exhausted(path: &Path) -> InMemoryRatelimiter {
+        let ratelimiter = InMemoryRatelimiter::new();
+        ratelimiter.restore([BucketSnapshot {
+            limit: 5,
+            path: path.clone(),
+            remaining: 0,
+            reset_at: SystemTime::now() + Duration::from_secs(60),
+        }]);
+
+        ratelimiter
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn shutdown() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let path = Path::ChannelsIdMessages(1);
+        let ratelimiter = exhausted(&path);
+
+        let ticket = ratelimiter.ticket(path.clone()).await?;
+        ratelimiter.shutdown();
+
+        let start = Instant::now();
+        assert!(ticket.await.is_err());
+        assert_eq!(Duration::ZERO, start.elapsed());
+
+        // Tickets are canceled after shutting down as well.
+        assert!(ratelimiter.ticket(path).await?.await.is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn shutdown_on_drop() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let path = Path::ChannelsIdMessages(1);
+        let ratelimiter = exhausted(&path);
+
+        let mut ticket = ratelimiter.ticket(path).await?;
+        // The ratelimiter is only shut down once its last clone is dropped.
+        drop(ratelimiter.clone());
+        assert!(timeout(Duration::from_secs(1), &mut ticket).await.is_err());
+
+        drop(ratelimiter);
+
+        let start = Instant::now();
+        assert!(ticket.await.is_err());
+        assert_eq!(Duration::ZERO, start.elapsed());
+
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn global_lock_exempt() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let ratelimiter = InMemoryRatelimiter::new();
+        ratelimiter.global.lock();
+        let guard = ratelimiter.global.0.lock().await;
+
+        let interaction = ratelimiter.ticket(Path::InteractionCallback(1)).await?;
+        let message = ratelimiter.ticket(Path::ChannelsIdMessages(1)).await?;
+
+        assert!(timeout(Duration::from_secs(1), interaction).await.is_ok());
+        assert!(timeout(Duration::from_secs(1), message).await.is_err());
+
+        ratelimiter.global.unlock();
+        drop(guard);
+
+        let message = ratelimiter.ticket(Path::ChannelsIdMessages(2)).await?;
+        assert!(timeout(Duration::from_secs(1), message).await.is_ok());
+
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn delays() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let path = Path::ChannelsIdMessages(1);
+        let ratelimiter = InMemoryRatelimiter::new();
+        let mut delays = ratelimiter.delays();
+        ratelimiter.restore([BucketSnapshot {
+            limit: 5,
+            path: path.clone(),
+            remaining: 0,
+            reset_at: SystemTime::now() + Duration::from_secs(60),
+        }]);
+
+        let first = ratelimiter.ticket(path.clone()).await?;
+        let second = ratelimiter.ticket(path.clone()).await?;
+
+        let delay = delays.recv().await?;
+        assert!(matches!(
+            delay,
+            RatelimitDelay::Bucket { path: delayed, queued: 2, wait }
+                if delayed == path && wait > Duration::from_secs(59)
+        ));
+
+        drop((first, second));
+
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn priority_ticket() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let path = Path::ChannelsIdMessages(1);
+        let ratelimiter = InMemoryRatelimiter::new();
+        ratelimiter.restore([BucketSnapshot {
+            limit: 5,
+            path: path.clone(),
+            remaining: 0,
+            reset_at: SystemTime::now() + Duration::from_secs(5),
+        }]);
+
+        let regular = ratelimiter.ticket(path.clone()).await?;
+        let priority = ratelimiter.priority_ticket(path).await?;
+
+        tokio::select! {
+            _ = regular => panic!("regular ticket was ready first"),
+            sender = priority => drop(sender?),
         }
 
-        Box::pin(future::ready(Ok(rx)))
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn ticket_with_deadline() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let path = Path::ChannelsIdMessages(1);
+        let ratelimiter = InMemoryRatelimiter::new();
+        ratelimiter.restore([BucketSnapshot {
+            limit: 5,
+            path: path.clone(),
+            remaining: 0,
+            reset_at: SystemTime::now() + Duration::from_secs(60),
+        }]);
+        drop(ratelimiter.ticket(path.clone()).await?);
+
+        let error = ratelimiter
+            .ticket_with_deadline(
+                path.clone(),
+                Instant::now().into_std() + Duration::from_secs(3),
+            )
+            .await
+            .unwrap_err();
+        assert!(error
+            .downcast_ref::<WouldExceedDeadline>()
+            .is_some_and(|error| error.wait() > Duration::from_secs(59)));
+
+        let other = Path::ChannelsIdMessages(2);
+        drop(
+            ratelimiter
+                .ticket_with_deadline(other, Instant::now().into_std() + Duration::from_secs(3))
+                .await?,
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn snapshot_restore() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let path = Path::ChannelsIdMessages(1);
+        let expired = Path::ChannelsIdMessages(2);
+        let now = SystemTime::now();
+
+        let ratelimiter = InMemoryRatelimiter::new();
+        ratelimiter.restore([
+            BucketSnapshot {
+                limit: 5,
+                path: path.clone(),
+                remaining: 0,
+                reset_at: now + Duration::from_secs(60),
+            },
+            BucketSnapshot {
+                limit: 5,
+                path: expired.clone(),
+                remaining: 0,
+                reset_at: now - Duration::from_secs(1),
+            },
+        ]);
+
+        // Restored state is applied once the bucket is created.
+        assert!(ratelimiter.bucket(&path).await?.is_none());
+        drop(ratelimiter.ticket(path.clone()).await?);
+        drop(ratelimiter.ticket(expired.clone()).await?);
+
+        let bucket = ratelimiter.bucket(&path).await?.expect("bucket restored");
+        assert_eq!(5, bucket.limit());
+        assert_eq!(0, bucket.remaining());
+        assert!(bucket
+            .time_remaining()
+            .is_some_and(|remaining| remaining > Duration::from_secs(59)));
+
+        let snapshots = ratelimiter.snapshot();
+        assert_eq!(1, snapshots.len());
+        assert_eq!(path, snapshots[0].path);
+        assert_eq!(5, snapshots[0].limit);
+        assert_eq!(0, snapshots[0].remaining);
+
+        Ok(())
+    }
+
+    /// Test that restored snapshots of paths that reset without being
+    /// requested are dropped.
+    #[tokio::test]
+    async fn snapshot_restore_expired() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let expiring = Path::ChannelsIdMessages(1);
+        let pending = Path::ChannelsIdMessages(2);
+        let now = SystemTime::now();
+
+        let ratelimiter = InMemoryRatelimiter::new();
+        ratelimiter.restore([
+            BucketSnapshot {
+                limit: 5,
+                path: expiring.clone(),
+                remaining: 0,
+                reset_at: now + Duration::from_millis(100),
+            },
+            BucketSnapshot {
+                limit: 5,
+                path: pending.clone(),
+                remaining: 0,
+                reset_at: now + Duration::from_secs(60),
+            },
+        ]);
+        assert_eq!(2, ratelimiter.restored.lock().unwrap().len());
+
+        std::thread::sleep(Duration::from_millis(150));
+        drop(ratelimiter.ticket(Path::ChannelsIdMessages(3)).await?);
+
+        let restored = ratelimiter.restored.lock().unwrap();
+        assert!(!restored.contains_key(&expiring));
+        assert!(restored.contains_key(&pending));
+
+        Ok(())
     }
 }
@@ -26,7 +26,7 @@ pub use self::{
 use self::ticket::{TicketReceiver, TicketSender};
 use std::{
     error::Error,
-    fmt::Debug,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
     future::Future,
     pin::Pin,
     time::{Duration, Instant},
@@ -100,13 +100,52 @@ impl Bucket {
     pub fn time_remaining(&self) -> Option<Duration> {
         let reset_at = self.started_at? + self.reset_after;
 
-        reset_at.checked_duration_since(Instant::now())
+        reset_at.checked_duration_since(now())
     }
 }
 
+/// Current time according to Tokio's clock.
+///
+/// Tokio's clock may be paused and advanced in tests, so ratelimiters should
+/// use it instead of [`Instant::now`] to behave deterministically.
+fn now() -> Instant {
+    tokio::time::Instant::now().into_std()
+}
+
 /// A generic error type that implements [`Error`].
 pub type GenericError = Box<dyn Error + Send + Sync>;
 
+/// Error returned by [`Ratelimiter::ticket_with_deadline`] when the estimated
+/// wait for a ticket is longer than the deadline allows.
+#[derive(Debug)]
+pub struct WouldExceedDeadline {
+    /// Estimated duration until a ticket is available.
+    wait: Duration,
+}
+
+impl WouldExceedDeadline {
+    /// Create an error with the estimated wait for a ticket.
+    #[must_use]
+    pub const fn new(wait: Duration) -> Self {
+        Self { wait }
+    }
+
+    /// Estimated duration until a ticket is available.
+    #[must_use]
+    pub const fn wait(&self) -> Duration {
+        self.wait
+    }
+}
+
+impl Display for WouldExceedDeadline {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("ticket would not be available before the deadline, estimated wait is ")?;
+        Debug::fmt(&self.wait, f)
+    }
+}
+
+impl Error for WouldExceedDeadline {}
+
 /// Future returned by [`Ratelimiter::bucket`].
 pub type GetBucketFuture =
     Pin<Box<dyn Future<Output = Result<Option<Bucket>, GenericError>> + Send + 'static>>;
@@ -158,6 +197,37 @@ pub trait Ratelimiter: Debug + Send + Sync {
     /// available. Tickets are ready in order of retrieval.
     fn ticket(&self, path: Path) -> GetTicketFuture;
 
+    /// Retrieve a ticket to know when to send a request ahead of requests
+    /// queued via [`Self::ticket`], such as for interaction responses or
+    /// moderation actions that shouldn't wait behind bulk work.
+    ///
+    /// Priority tickets are ready in order of retrieval among themselves and
+    /// are still bound to the path's ratelimit.
+    ///
+    /// Defaults to [`Self::ticket`] for ratelimiters that don't support
+    /// priorities.
+    fn priority_ticket(&self, path: Path) -> GetTicketFuture {
+        self.ticket(path)
+    }
+
+    /// Retrieve a ticket to know when to send a request, failing fast if it
+    /// wouldn't be available before a deadline.
+    ///
+    /// The wait should be estimated from the path's bucket: if it has no
+    /// remaining tickets, a ticket is estimated to be available once the
+    /// bucket refreshes. If that is after the deadline then the returned
+    /// future resolves to a [`WouldExceedDeadline`] error without queueing a
+    /// ticket, such as for interaction responses that are useless after a few
+    /// seconds.
+    ///
+    /// Defaults to [`Self::ticket`] for ratelimiters that can't estimate
+    /// waits.
+    fn ticket_with_deadline(&self, path: Path, deadline: Instant) -> GetTicketFuture {
+        let _ = deadline;
+
+        self.ticket(path)
+    }
+
     /// Retrieve a ticket to send a request.
     /// Other than [`Self::ticket`], this method will return
     /// a [`TicketSender`].
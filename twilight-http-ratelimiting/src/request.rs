@@ -18,6 +18,7 @@ use std::{
 ///
 /// [method]: https://developer.mozilla.org/en-US/docs/Web/HTTP/Methods
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[non_exhaustive]
 pub enum Method {
     /// Delete a resource.
@@ -115,6 +116,7 @@ pub enum PathParseErrorType {
 /// An enum representing a path, most useful for ratelimiting implementations.
 // If adding to this enum, be sure to add to the `TryFrom` impl.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[non_exhaustive]
 pub enum Path {
     /// Operating on global commands.
@@ -170,12 +172,16 @@ pub enum Path {
     ChannelsIdThreads(u64),
     /// Operating on a channel's typing indicator.
     ChannelsIdTyping(u64),
+    /// Operating on a voice channel's status.
+    ChannelsIdVoiceStatus(u64),
     /// Operating on a channel's webhooks.
     ChannelsIdWebhooks(u64),
     /// Operating on an application's entitlements.
     ApplicationIdEntitlements(u64),
     /// Operating on an application's SKUs.
     ApplicationIdSKUs(u64),
+    /// Operating on an application's role connection metadata records.
+    ApplicationIdRoleConnectionsMetadata(u64),
     /// Operating with the gateway information.
     Gateway,
     /// Operating with the gateway information tailored to the current user.
@@ -282,6 +288,8 @@ pub enum Path {
     UsersId,
     /// Operating on the user's private channels.
     UsersIdChannels,
+    /// Operating on the user's role connection for an application.
+    UsersIdApplicationsIdRoleConnection,
     /// Operating on the user's connections.
     UsersIdConnections,
     /// Operating on the state of a guild that the user is in.
@@ -311,7 +319,10 @@ impl FromStr for Path {
 
     /// Parses a string into a path.
     ///
-    /// The string *may* start with a slash (`/`), which will be ignored.
+    /// The string *may* start with a slash (`/`), which will be ignored. An
+    /// `api` or versioned `api/v{version}` prefix and a query string are
+    /// ignored as well, so the path and query of a URL to Discord's API may be
+    /// used as-is.
     ///
     /// # Examples
     ///
@@ -325,6 +336,10 @@ impl FromStr for Path {
     ///     Path::ChannelsIdMessages(123),
     ///     Path::from_str("channels/123/messages")?,
     /// );
+    /// assert_eq!(
+    ///     Path::ChannelsIdMessages(123),
+    ///     Path::from_str("/api/v10/channels/123/messages?limit=5")?,
+    /// );
     /// # Ok(()) }
     /// ```
     #[allow(clippy::enum_glob_use, clippy::too_many_lines)]
@@ -339,9 +354,25 @@ impl FromStr for Path {
             })
         }
 
-        let skip = usize::from(s.starts_with('/'));
+        let s = s.split_once('?').map_or(s, |(path, _)| path);
+        let s = s.strip_prefix('/').unwrap_or(s);
+
+        // Strip the API prefix and its version, if present.
+        let s = match s.strip_prefix("api/") {
+            Some(rest) => match rest.split_once('/') {
+                Some((version, rest))
+                    if version.len() > 1
+                        && version.starts_with('v')
+                        && version[1..].bytes().all(|byte| byte.is_ascii_digit()) =>
+                {
+                    rest
+                }
+                _ => rest,
+            },
+            None => s,
+        };
 
-        let parts = s.split('/').skip(skip).collect::<Vec<&str>>();
+        let parts = s.split('/').collect::<Vec<&str>>();
 
         Ok(match parts[..] {
             ["applications", "@me"] => ApplicationsMe,
@@ -357,6 +388,9 @@ impl FromStr for Path {
             | ["applications", id, "guilds", _, "commands", _, "permissions"] => {
                 ApplicationGuildCommandId(parse_id(id)?)
             }
+            ["applications", id, "role-connections", "metadata"] => {
+                ApplicationIdRoleConnectionsMetadata(parse_id(id)?)
+            }
             ["applications", id, "skus"] => ApplicationIdSKUs(parse_id(id)?),
             ["channels", id] => ChannelsId(parse_id(id)?),
             ["channels", id, "followers"] => ChannelsIdFollowers(parse_id(id)?),
@@ -397,6 +431,7 @@ impl FromStr for Path {
             ["channels", id, "thread-members", _] => ChannelsIdThreadMembersId(parse_id(id)?),
             ["channels", id, "threads"] => ChannelsIdThreads(parse_id(id)?),
             ["channels", id, "typing"] => ChannelsIdTyping(parse_id(id)?),
+            ["channels", id, "voice-status"] => ChannelsIdVoiceStatus(parse_id(id)?),
             ["channels", id, "webhooks"] | ["channels", id, "webhooks", _] => {
                 ChannelsIdWebhooks(parse_id(id)?)
             }
@@ -454,11 +489,14 @@ impl FromStr for Path {
             ["invites", _] => InvitesCode,
             ["interactions", id, _, "callback"] => InteractionCallback(parse_id(id)?),
             ["stage-instances", _] => StageInstances,
-            ["sticker-packs"] => StickerPacks,
+            ["sticker-packs"] | ["sticker-packs", _] => StickerPacks,
             ["stickers", _] => Stickers,
             ["oauth2", "applications", "@me"] => OauthApplicationsMe,
             ["oauth2", "@me"] => OauthMe,
             ["users", _] => UsersId,
+            ["users", _, "applications", _, "role-connection"] => {
+                UsersIdApplicationsIdRoleConnection
+            }
             ["users", _, "connections"] => UsersIdConnections,
             ["users", _, "channels"] => UsersIdChannels,
             ["users", _, "guilds"] => UsersIdGuilds,
@@ -522,6 +560,30 @@ mod tests {
         assert_eq!(Path::ChannelsId(123), Path::from_str("/channels/123")?);
         assert_eq!(Path::WebhooksId(123), Path::from_str("/webhooks/123")?);
         assert_eq!(Path::InvitesCode, Path::from_str("/invites/abc")?);
+        assert_eq!(
+            Path::ApplicationIdRoleConnectionsMetadata(123),
+            Path::from_str("/applications/123/role-connections/metadata")?,
+        );
+        assert_eq!(
+            Path::UsersIdApplicationsIdRoleConnection,
+            Path::from_str("/users/@me/applications/123/role-connection")?,
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_str_url_path_and_query() -> Result<(), Box<dyn Error>> {
+        assert_eq!(Path::ChannelsId(123), Path::from_str("/api/channels/123")?);
+        assert_eq!(
+            Path::ChannelsIdMessages(123),
+            Path::from_str("/api/v10/channels/123/messages?limit=5")?,
+        );
+        assert_eq!(Path::Guilds, Path::from_str("guilds?with_counts=true")?);
+        assert_eq!(
+            Path::ChannelsIdMessagesId(Method::Delete, 123),
+            Path::try_from((Method::Delete, "/api/v9/channels/123/messages/456"))?,
+        );
 
         Ok(())
     }
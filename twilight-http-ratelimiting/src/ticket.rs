@@ -105,6 +105,18 @@ impl TicketNotifier {
 
         Some(TicketHeaders(rx))
     }
+
+    /// Whether the consumer has dropped their [`TicketReceiver`] half,
+    /// canceling the ticket.
+    ///
+    /// Unlike [`available`], this doesn't signal the consumer, so ratelimiters
+    /// may skip canceled tickets before waiting to grant them.
+    ///
+    /// [`available`]: Self::available
+    #[must_use]
+    pub fn is_canceled(&self) -> bool {
+        self.0.is_closed()
+    }
 }
 
 /// Channel receiver to wait for availability of a ratelimit ticket.
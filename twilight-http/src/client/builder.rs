@@ -1,8 +1,10 @@
-use super::Token;
+use super::{concurrency::ConcurrencyLimiter, Interceptor, Token, TWILIGHT_USER_AGENT};
 use crate::{client::connector, Client};
-use http::header::HeaderMap;
-use hyper_util::rt::TokioExecutor;
+use http::header::{HeaderMap, HeaderValue};
+use hyper_util::rt::{TokioExecutor, TokioTimer};
 use std::{
+    net::IpAddr,
+    num::NonZeroUsize,
     sync::{atomic::AtomicBool, Arc},
     time::Duration,
 };
@@ -13,14 +15,23 @@ use twilight_model::channel::message::AllowedMentions;
 #[derive(Debug)]
 #[must_use = "has no effect if not built into a Client"]
 pub struct ClientBuilder {
+    concurrency_limit: Option<NonZeroUsize>,
     pub(crate) default_allowed_mentions: Option<AllowedMentions>,
+    interceptor: Option<Box<dyn Interceptor>>,
+    local_address: Option<IpAddr>,
+    pool_idle_timeout: Option<Duration>,
+    path_concurrency_limit: Option<NonZeroUsize>,
+    pool_max_idle_per_host: usize,
     pub(crate) proxy: Option<Box<str>>,
     pub(crate) ratelimiter: Option<Box<dyn Ratelimiter>>,
     remember_invalid_token: bool,
     pub(crate) default_headers: Option<HeaderMap>,
+    tcp_keepalive: Option<Duration>,
     pub(crate) timeout: Duration,
     pub(super) token: Option<Token>,
     pub(crate) use_http: bool,
+    /// User-Agent including the configured suffix, if any.
+    user_agent: Option<HeaderValue>,
 }
 
 impl ClientBuilder {
@@ -31,10 +42,13 @@ impl ClientBuilder {
 
     /// Build the [`Client`].
     pub fn build(self) -> Client {
-        let connector = connector::create();
+        let connector = connector::create(self.tcp_keepalive, self.local_address);
 
-        let http =
-            hyper_util::client::legacy::Client::builder(TokioExecutor::new()).build(connector);
+        let http = hyper_util::client::legacy::Client::builder(TokioExecutor::new())
+            .pool_idle_timeout(self.pool_idle_timeout)
+            .pool_max_idle_per_host(self.pool_max_idle_per_host)
+            .pool_timer(TokioTimer::new())
+            .build(connector);
 
         let token_invalidated = if self.remember_invalid_token {
             Some(Arc::new(AtomicBool::new(false)))
@@ -43,18 +57,42 @@ impl ClientBuilder {
         };
 
         Client {
+            concurrency: ConcurrencyLimiter::new(
+                self.concurrency_limit,
+                self.path_concurrency_limit,
+            ),
             http,
+            interceptor: self.interceptor.map(Arc::from),
             default_headers: self.default_headers,
             proxy: self.proxy,
-            ratelimiter: self.ratelimiter,
+            ratelimiter: self.ratelimiter.map(Arc::from),
             timeout: self.timeout,
             token_invalidated,
             token: self.token,
             default_allowed_mentions: self.default_allowed_mentions,
             use_http: self.use_http,
+            user_agent: self
+                .user_agent
+                .unwrap_or_else(|| HeaderValue::from_static(TWILIGHT_USER_AGENT)),
         }
     }
 
+    /// Set the maximum number of requests that may be in flight at once.
+    ///
+    /// Requests over the limit wait for earlier requests to complete before
+    /// they are sent. Requests waiting in the [ratelimiter]'s queue don't
+    /// count towards the limit. This bounds the number of open connections
+    /// when many requests are made at once.
+    ///
+    /// The default is no limit.
+    ///
+    /// [ratelimiter]: Self::ratelimiter
+    pub const fn concurrency_limit(mut self, limit: NonZeroUsize) -> Self {
+        self.concurrency_limit = Some(limit);
+
+        self
+    }
+
     /// Set the default allowed mentions setting to use on all messages sent through the HTTP
     /// client.
     pub fn default_allowed_mentions(mut self, allowed_mentions: AllowedMentions) -> Self {
@@ -63,11 +101,94 @@ impl ClientBuilder {
         self
     }
 
+    /// Set the [`Interceptor`] to call before sending each request and after
+    /// receiving each response.
+    ///
+    /// This may be used to log requests, inject headers, or record latencies.
+    ///
+    /// The default is no interceptor.
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn interceptor(mut self, interceptor: Option<Box<dyn Interceptor>>) -> Self {
+        self.interceptor = interceptor;
+
+        self
+    }
+
+    /// Set the local address to bind outbound connections to.
+    ///
+    /// This is useful on hosts with multiple addresses to choose which one
+    /// requests are made from. Requests to hosts that don't resolve to an
+    /// address of the same IP version will fail.
+    ///
+    /// # Examples
+    ///
+    /// Make requests from the local IPv6 address `2001:db8::1`:
+    ///
+    /// ```
+    /// use std::net::{IpAddr, Ipv6Addr};
+    /// use twilight_http::Client;
+    ///
+    /// let address = IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1));
+    /// let client = Client::builder().local_address(address).build();
+    /// ```
+    pub const fn local_address(mut self, address: IpAddr) -> Self {
+        self.local_address = Some(address);
+
+        self
+    }
+
+    /// Set how long idle connections are kept in the pool for reuse.
+    ///
+    /// If the argument is `None` then idle connections are never closed by the
+    /// client, although Discord may still close them.
+    ///
+    /// The default is 90 seconds.
+    pub const fn pool_idle_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.pool_idle_timeout = timeout;
+
+        self
+    }
+
+    /// Set the maximum number of requests to the same [`Path`] that may be
+    /// queued in the [ratelimiter] or in flight at once.
+    ///
+    /// Requests over the limit wait for earlier requests to the path to
+    /// complete before they enter the ratelimiter's queue. This stops a
+    /// single route from piling up an unbounded number of waiting requests,
+    /// such as a command being spammed.
+    ///
+    /// The default is no limit.
+    ///
+    /// [`Path`]: twilight_http_ratelimiting::Path
+    /// [ratelimiter]: Self::ratelimiter
+    pub const fn path_concurrency_limit(mut self, limit: NonZeroUsize) -> Self {
+        self.path_concurrency_limit = Some(limit);
+
+        self
+    }
+
+    /// Set the maximum number of idle connections kept in the pool per host.
+    ///
+    /// Connections exceeding this limit are closed once their requests
+    /// complete instead of being reused, and a value of 0 disables connection
+    /// reuse entirely.
+    ///
+    /// The default is no limit.
+    pub const fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = max;
+
+        self
+    }
+
     /// Set the proxy to use for all HTTP(S) requests.
     ///
     /// **Note** that this isn't currently a traditional proxy, but is for
     /// working with something like [twilight's HTTP proxy server].
     ///
+    /// This can also be used to point the client at a local mock server in
+    /// tests, returning canned responses without network access. Consider
+    /// disabling the [ratelimiter] in that case.
+    ///
     /// # Examples
     ///
     /// Set the proxy to `twilight_http_proxy.internal`:
@@ -82,6 +203,7 @@ impl ClientBuilder {
     /// # Ok(()) }
     /// ```
     ///
+    /// [ratelimiter]: Self::ratelimiter
     /// [twilight's HTTP proxy server]: https://github.com/twilight-rs/http-proxy
     pub fn proxy(mut self, proxy_url: String, use_http: bool) -> Self {
         self.proxy.replace(proxy_url.into_boxed_str());
@@ -93,10 +215,14 @@ impl ClientBuilder {
     /// Set a ratelimiter to use.
     ///
     /// If the argument is `None` then the client's ratelimiter will be skipped
-    /// before making a request.
+    /// before making a request. This is useful when ratelimits are handled
+    /// elsewhere, such as by a proxy shared between processes, in which case
+    /// the headers of responses may be read via [`Response::ratelimit_headers`].
     ///
     /// If this method is not called at all then a default [`InMemoryRatelimiter`] will be
     /// created by [`ClientBuilder::build`].
+    ///
+    /// [`Response::ratelimit_headers`]: crate::Response::ratelimit_headers
     #[allow(clippy::missing_const_for_fn)]
     pub fn ratelimiter(mut self, ratelimiter: Option<Box<dyn Ratelimiter>>) -> Self {
         self.ratelimiter = ratelimiter;
@@ -113,7 +239,50 @@ impl ClientBuilder {
         self
     }
 
+    /// Set the interval of TCP keepalive probes sent on idle connections.
+    ///
+    /// If the argument is `None` then TCP keepalive is disabled.
+    ///
+    /// The default is `None`.
+    pub const fn tcp_keepalive(mut self, interval: Option<Duration>) -> Self {
+        self.tcp_keepalive = interval;
+
+        self
+    }
+
+    /// Set text to append to the library's User-Agent, such as the name,
+    /// version, and URL of the bot.
+    ///
+    /// The User-Agent sent with requests is then in the format
+    /// `DiscordBot (URL, VERSION) Twilight-rs SUFFIX`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the suffix contains characters that aren't valid in a header
+    /// value, such as newlines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_http::Client;
+    ///
+    /// let client = Client::builder()
+    ///     .user_agent_suffix("MyBot/1.0 (https://example.com)")
+    ///     .build();
+    /// ```
+    #[track_caller]
+    pub fn user_agent_suffix(mut self, suffix: &str) -> Self {
+        let user_agent = HeaderValue::try_from(format!("{TWILIGHT_USER_AGENT} {suffix}"))
+            .expect("user agent suffix contains invalid header characters");
+        self.user_agent.replace(user_agent);
+
+        self
+    }
+
     /// Set a group headers which are sent in every request.
+    ///
+    /// Default headers take precedence over headers set by the client, such
+    /// as the User-Agent.
     pub fn default_headers(mut self, headers: HeaderMap) -> Self {
         self.default_headers.replace(headers);
 
@@ -134,15 +303,36 @@ impl ClientBuilder {
     }
 
     /// Set the token to use for HTTP requests.
+    ///
+    /// If the token is not prefixed with `Bot ` or `Bearer ` then it is
+    /// assumed to be a bot token and is prefixed with `Bot `. Empty tokens are
+    /// not prefixed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the token, excluding its prefix, contains whitespace or other
+    /// characters that aren't visible ASCII, such as a trailing newline left
+    /// over from reading the token from a file.
+    #[track_caller]
     pub fn token(mut self, mut token: String) -> Self {
-        // let is_bot = token.starts_with("Bot ");
-        // let is_bearer = token.starts_with("Bearer ");
-        //
-        // // Make sure it is either a bot or bearer token, and assume it's a bot
-        // // token if no prefix is given
-        // if !is_bot && !is_bearer {
-        //     token.insert_str(0, "Bot ");
-        // }
+        let is_bot = token.starts_with("Bot ");
+        let is_bearer = token.starts_with("Bearer ");
+
+        let unprefixed = token
+            .strip_prefix("Bot ")
+            .or_else(|| token.strip_prefix("Bearer "))
+            .unwrap_or(&token);
+
+        assert!(
+            unprefixed.bytes().all(|byte| byte.is_ascii_graphic()),
+            "token contains whitespace or invalid characters"
+        );
+
+        // Make sure it is either a bot or bearer token, and assume it's a bot
+        // token if no prefix is given
+        if !token.is_empty() && !is_bot && !is_bearer {
+            token.insert_str(0, "Bot ");
+        }
 
         self.token.replace(Token::new(token.into_boxed_str()));
 
@@ -154,21 +344,29 @@ impl Default for ClientBuilder {
     fn default() -> Self {
         #[allow(clippy::box_default)]
         Self {
+            concurrency_limit: None,
             default_allowed_mentions: None,
             default_headers: None,
+            interceptor: None,
+            local_address: None,
+            path_concurrency_limit: None,
+            pool_idle_timeout: Some(Duration::from_secs(90)),
+            pool_max_idle_per_host: usize::MAX,
             proxy: None,
             ratelimiter: Some(Box::new(InMemoryRatelimiter::default())),
             remember_invalid_token: true,
+            tcp_keepalive: None,
             timeout: Duration::from_secs(10),
             token: None,
             use_http: false,
+            user_agent: None,
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::ClientBuilder;
+    use super::{ClientBuilder, TWILIGHT_USER_AGENT};
     use static_assertions::assert_impl_all;
     use std::fmt::Debug;
 
@@ -182,4 +380,49 @@ mod tests {
         );
         assert!(format!("{:?}", ClientBuilder::new()).contains("token: None"));
     }
+
+    #[test]
+    fn user_agent_suffix() {
+        let client = ClientBuilder::new().user_agent_suffix("MyBot/1.0").build();
+        assert_eq!(
+            format!("{TWILIGHT_USER_AGENT} MyBot/1.0"),
+            client.user_agent.to_str().unwrap()
+        );
+    }
+
+    #[should_panic(expected = "user agent suffix contains invalid header characters")]
+    #[test]
+    fn user_agent_suffix_invalid() {
+        drop(ClientBuilder::new().user_agent_suffix("MyBot\n1.0"));
+    }
+
+    #[test]
+    fn token_prefix() {
+        let client = ClientBuilder::new().token("foo".to_owned()).build();
+        assert_eq!(Some("Bot foo"), client.token());
+
+        let client = ClientBuilder::new().token("Bot foo".to_owned()).build();
+        assert_eq!(Some("Bot foo"), client.token());
+
+        let client = ClientBuilder::new().token("Bearer foo".to_owned()).build();
+        assert_eq!(Some("Bearer foo"), client.token());
+    }
+
+    #[test]
+    fn token_empty() {
+        let client = ClientBuilder::new().token(String::new()).build();
+        assert_eq!(Some(""), client.token());
+    }
+
+    #[should_panic(expected = "token contains whitespace or invalid characters")]
+    #[test]
+    fn token_inner_newline() {
+        drop(ClientBuilder::new().token("Bot foo\nbar".to_owned()));
+    }
+
+    #[should_panic(expected = "token contains whitespace or invalid characters")]
+    #[test]
+    fn token_trailing_newline() {
+        drop(ClientBuilder::new().token("foo\n".to_owned()));
+    }
 }
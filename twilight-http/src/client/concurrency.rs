@@ -0,0 +1,106 @@
+use std::{
+    collections::HashMap,
+    num::NonZeroUsize,
+    sync::{Arc, Mutex},
+};
+use tokio::sync::Semaphore;
+use twilight_http_ratelimiting::Path;
+
+/// Limits on the number of requests the client runs at once.
+///
+/// Configured via [`ClientBuilder::concurrency_limit`] and
+/// [`ClientBuilder::path_concurrency_limit`].
+///
+/// [`ClientBuilder::concurrency_limit`]: super::ClientBuilder::concurrency_limit
+/// [`ClientBuilder::path_concurrency_limit`]: super::ClientBuilder::path_concurrency_limit
+#[derive(Debug)]
+pub(crate) struct ConcurrencyLimiter {
+    /// Semaphore shared by all requests, if they are limited.
+    global: Option<Arc<Semaphore>>,
+    /// Maximum number of requests per path, if they are limited.
+    path_limit: Option<NonZeroUsize>,
+    /// Semaphores of paths that have had requests.
+    paths: Mutex<HashMap<Path, Arc<Semaphore>>>,
+}
+
+impl ConcurrencyLimiter {
+    /// Create a new limiter, or [`None`] if neither limit is set.
+    pub(crate) fn new(
+        global_limit: Option<NonZeroUsize>,
+        path_limit: Option<NonZeroUsize>,
+    ) -> Option<Self> {
+        if global_limit.is_none() && path_limit.is_none() {
+            return None;
+        }
+
+        Some(Self {
+            global: global_limit.map(|limit| Arc::new(Semaphore::new(limit.get()))),
+            path_limit,
+            paths: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Semaphore limiting the total number of in-flight requests.
+    pub(crate) fn global(&self) -> Option<Arc<Semaphore>> {
+        self.global.clone()
+    }
+
+    /// Semaphore limiting the number of queued or in-flight requests to a
+    /// path.
+    pub(crate) fn path(&self, path: &Path) -> Option<Arc<Semaphore>> {
+        let limit = self.path_limit?;
+        let mut paths = self.paths.lock().expect("paths poisoned");
+
+        if let Some(semaphore) = paths.get(path) {
+            return Some(Arc::clone(semaphore));
+        }
+
+        // Forget the semaphores of paths without any outstanding permits so
+        // that paths with IDs in them don't accumulate.
+        paths.retain(|_, semaphore| Arc::strong_count(semaphore) > 1);
+
+        let semaphore = Arc::new(Semaphore::new(limit.get()));
+        paths.insert(path.clone(), Arc::clone(&semaphore));
+
+        Some(semaphore)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ConcurrencyLimiter;
+    use std::{num::NonZeroUsize, sync::Arc};
+    use twilight_http_ratelimiting::Path;
+
+    #[test]
+    fn new() {
+        assert!(ConcurrencyLimiter::new(None, None).is_none());
+
+        let limiter = ConcurrencyLimiter::new(NonZeroUsize::new(2), None).unwrap();
+        assert_eq!(2, limiter.global().unwrap().available_permits());
+        assert!(limiter.path(&Path::ChannelsId(1)).is_none());
+    }
+
+    #[test]
+    fn path() {
+        let limiter = ConcurrencyLimiter::new(None, Some(NonZeroUsize::MIN)).unwrap();
+        assert!(limiter.global().is_none());
+
+        let first = limiter.path(&Path::ChannelsId(1)).unwrap();
+        assert!(Arc::ptr_eq(
+            &first,
+            &limiter.path(&Path::ChannelsId(1)).unwrap()
+        ));
+        assert!(!Arc::ptr_eq(
+            &first,
+            &limiter.path(&Path::ChannelsId(2)).unwrap()
+        ));
+
+        // The idle semaphore of the second path is removed when a third path
+        // is added, while the first is still referenced.
+        limiter.path(&Path::ChannelsId(3));
+        let paths = limiter.paths.lock().unwrap();
+        assert!(paths.contains_key(&Path::ChannelsId(1)));
+        assert!(!paths.contains_key(&Path::ChannelsId(2)));
+    }
+}
@@ -1,5 +1,7 @@
 //! HTTP connectors with different features.
 
+use std::{net::IpAddr, time::Duration};
+
 /// HTTPS connector using `rustls` as a TLS backend.
 #[cfg(any(
     feature = "rustls-native-roots",
@@ -43,13 +45,18 @@ pub type Connector = HttpsConnector<HttpConnector>;
 pub type Connector = HttpConnector;
 
 /// Create a connector with the specified features.
-pub fn create() -> Connector {
+///
+/// TCP keepalive is enabled on connections if a duration is provided, and
+/// connections are bound to the local address if one is provided.
+pub fn create(tcp_keepalive: Option<Duration>, local_address: Option<IpAddr>) -> Connector {
     #[cfg(not(feature = "hickory"))]
     let mut connector = HttpConnector::new();
     #[cfg(feature = "hickory")]
     let mut connector = hyper_hickory::TokioHickoryResolver::default().into_http_connector();
 
     connector.enforce_http(false);
+    connector.set_keepalive(tcp_keepalive);
+    connector.set_local_address(local_address);
 
     #[cfg(any(
         feature = "rustls-native-roots",
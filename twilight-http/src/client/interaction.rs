@@ -68,7 +68,14 @@ impl<'a> InteractionClient<'a> {
     ///
     /// This endpoint is not bound to the application's global rate limit.
     ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`Validation`] if the data of the response is
+    /// invalid. Refer to the errors section of
+    /// [`twilight_validate::interaction::response_data`] for possible reasons.
+    ///
     /// [`InteractionResponseData`]: twilight_model::http::interaction::InteractionResponseData
+    /// [`Validation`]: crate::error::ErrorType::Validation
     /// [`twilight-util`]: https://docs.rs/twilight-util/latest/index.html
     /// [associated builder]: https://docs.rs/twilight-util/latest/twilight_util/builder/struct.InteractionResponseDataBuilder.html
     pub const fn create_response(
@@ -0,0 +1,107 @@
+use crate::{
+    request::{Method, RequestId},
+    response::StatusCode,
+};
+use http::header::HeaderMap;
+use std::{fmt::Debug, time::Duration};
+use twilight_http_ratelimiting::Path;
+
+/// Request passed to an [`Interceptor`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct InterceptedRequest {
+    /// ID of the request, as also attached to its errors.
+    pub id: RequestId,
+    /// Method of the request.
+    pub method: Method,
+    /// Ratelimit path of the request, identifying the route it's for.
+    pub path: Path,
+}
+
+/// Hooks called by the [`Client`] around each request it sends.
+///
+/// Configured via [`ClientBuilder::interceptor`]. Both methods do nothing by
+/// default, so implementations only need to override the ones they use.
+///
+/// Hooks are called synchronously while the request future is polled, so they
+/// should return quickly.
+///
+/// # Examples
+///
+/// Inject a header into every request and log the latency of each response:
+///
+/// ```no_run
+/// use http::header::{HeaderMap, HeaderValue};
+/// use std::time::Duration;
+/// use twilight_http::{
+///     client::{InterceptedRequest, Interceptor},
+///     response::StatusCode,
+///     Client,
+/// };
+///
+/// #[derive(Debug)]
+/// struct Logger;
+///
+/// impl Interceptor for Logger {
+///     fn before_send(&self, request: &InterceptedRequest, headers: &mut HeaderMap) {
+///         headers.insert("x-trace-id", HeaderValue::from(request.id.get()));
+///     }
+///
+///     fn after_receive(
+///         &self,
+///         request: &InterceptedRequest,
+///         status: StatusCode,
+///         _: &HeaderMap,
+///         latency: Duration,
+///     ) {
+///         let InterceptedRequest { id, method, path, .. } = request;
+///         println!("{id} {method:?} {path:?}: {status} in {latency:?}");
+///     }
+/// }
+///
+/// let client = Client::builder()
+///     .token("my token".to_owned())
+///     .interceptor(Some(Box::new(Logger)))
+///     .build();
+/// ```
+///
+/// [`Client`]: crate::Client
+/// [`ClientBuilder::interceptor`]: crate::client::ClientBuilder::interceptor
+pub trait Interceptor: Debug + Send + Sync {
+    /// Called once the headers of a request have been built, before it waits
+    /// in the ratelimiter's queue.
+    ///
+    /// Headers set by the client, including the authorization header, may be
+    /// inspected and replaced.
+    fn before_send(&self, request: &InterceptedRequest, headers: &mut HeaderMap) {
+        let _ = (request, headers);
+    }
+
+    /// Called once the status and headers of a response have been received,
+    /// before its body is read.
+    ///
+    /// The latency is the time between sending the request and receiving the
+    /// response, excluding time spent in the ratelimiter's queue. Requests
+    /// that fail without a response, such as by timing out, aren't passed to
+    /// this hook.
+    fn after_receive(
+        &self,
+        request: &InterceptedRequest,
+        status: StatusCode,
+        headers: &HeaderMap,
+        latency: Duration,
+    ) {
+        let _ = (request, status, headers, latency);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InterceptedRequest, Interceptor};
+    use static_assertions::{assert_fields, assert_impl_all, assert_obj_safe};
+    use std::fmt::Debug;
+
+    assert_fields!(InterceptedRequest: id, method, path);
+    assert_impl_all!(InterceptedRequest: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_obj_safe!(Interceptor);
+}
@@ -1,8 +1,18 @@
 mod builder;
+mod concurrency;
 mod connector;
 mod interaction;
+mod interceptor;
+mod webhook;
+
+pub use self::{
+    builder::ClientBuilder,
+    interaction::InteractionClient,
+    interceptor::{InterceptedRequest, Interceptor},
+    webhook::WebhookClient,
+};
 
-pub use self::{builder::ClientBuilder, interaction::InteractionClient};
+use self::concurrency::ConcurrencyLimiter;
 
 use crate::request::application::{
     emoji::{
@@ -12,6 +22,10 @@ use crate::request::application::{
         CreateTestEntitlement, CreateTestEntitlementOwner, DeleteTestEntitlement, GetEntitlements,
         GetSKUs,
     },
+    role_connection::{
+        GetApplicationRoleConnectionMetadataRecords, GetCurrentUserApplicationRoleConnection,
+        UpdateApplicationRoleConnectionMetadataRecords, UpdateCurrentUserApplicationRoleConnection,
+    },
 };
 #[allow(deprecated)]
 use crate::{
@@ -43,7 +57,8 @@ use crate::{
                 UpdateWebhookMessage, UpdateWebhookWithToken,
             },
             CreatePin, CreateTypingTrigger, DeleteChannel, DeleteChannelPermission, DeletePin,
-            FollowNewsChannel, GetChannel, GetPins, UpdateChannel, UpdateChannelPermission,
+            FollowNewsChannel, GetChannel, GetPins, SetVoiceChannelStatus, UpdateChannel,
+            UpdateChannelPermission,
         },
         guild::{
             auto_moderation::{
@@ -78,7 +93,7 @@ use crate::{
             CreateGuildScheduledEvent, DeleteGuildScheduledEvent, GetGuildScheduledEvent,
             GetGuildScheduledEventUsers, GetGuildScheduledEvents, UpdateGuildScheduledEvent,
         },
-        sticker::{GetNitroStickerPacks, GetSticker},
+        sticker::{GetNitroStickerPacks, GetSticker, GetStickerPack},
         template::{
             CreateGuildFromTemplate, CreateTemplate, DeleteTemplate, GetTemplate, GetTemplates,
             SyncTemplate, UpdateTemplate,
@@ -89,9 +104,9 @@ use crate::{
             UpdateCurrentUser,
         },
         GetCurrentAuthorizationInformation, GetGateway, GetUserApplicationInfo, GetVoiceRegions,
-        Method, Request, UpdateCurrentUserApplication,
+        Method, Request, RequestId, UpdateCurrentUserApplication,
     },
-    response::ResponseFuture,
+    response::{future::Retry, ResponseFuture},
     API_VERSION,
 };
 use http::header::{
@@ -107,11 +122,11 @@ use std::{
         atomic::{AtomicBool, Ordering},
         Arc,
     },
-    time::Duration,
+    time::{Duration, Instant},
 };
-use tokio::time;
-use twilight_http_ratelimiting::Ratelimiter;
+use twilight_http_ratelimiting::{GetTicketFuture, Path, Ratelimiter, WaitForTicketFuture};
 use twilight_model::{
+    application::role_connection::ApplicationRoleConnectionMetadata,
     channel::{message::AllowedMentions, ChannelType},
     guild::{
         auto_moderation::AutoModerationEventType, scheduled_event::PrivacyLevel, MfaLevel,
@@ -122,7 +137,8 @@ use twilight_model::{
         marker::{
             ApplicationMarker, AutoModerationRuleMarker, ChannelMarker, EmojiMarker,
             EntitlementMarker, GuildMarker, IntegrationMarker, MessageMarker, RoleMarker,
-            ScheduledEventMarker, SkuMarker, StickerMarker, UserMarker, WebhookMarker,
+            ScheduledEventMarker, SkuMarker, StickerMarker, StickerPackMarker, UserMarker,
+            WebhookMarker,
         },
         Id,
     },
@@ -238,11 +254,20 @@ impl Deref for Token {
 /// [here]: https://discord.com/developers/applications
 #[derive(Debug)]
 pub struct Client {
+    /// Limits on the number of concurrent requests.
+    ///
+    /// Configured via [`ClientBuilder::concurrency_limit`] and
+    /// [`ClientBuilder::path_concurrency_limit`].
+    concurrency: Option<ConcurrencyLimiter>,
     pub(crate) default_allowed_mentions: Option<AllowedMentions>,
     default_headers: Option<HeaderMap>,
     http: HyperClient<Connector, Full<Bytes>>,
+    /// Hooks called around each request.
+    ///
+    /// Configured via [`ClientBuilder::interceptor`].
+    interceptor: Option<Arc<dyn Interceptor>>,
     proxy: Option<Box<str>>,
-    ratelimiter: Option<Box<dyn Ratelimiter>>,
+    ratelimiter: Option<Arc<dyn Ratelimiter>>,
     timeout: Duration,
     /// Whether the token has been invalidated.
     ///
@@ -251,10 +276,18 @@ pub struct Client {
     token_invalidated: Option<Arc<AtomicBool>>,
     token: Option<Token>,
     use_http: bool,
+    /// User-Agent sent with requests, including the configured suffix.
+    user_agent: HeaderValue,
 }
 
 impl Client {
     /// Create a new client with a token.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the token is invalid. Refer to [`ClientBuilder::token`] for
+    /// which tokens are accepted.
+    #[track_caller]
     pub fn new(token: String) -> Self {
         ClientBuilder::default().token(token).build()
     }
@@ -562,6 +595,16 @@ impl Client {
         UpdateChannel::new(self, channel_id)
     }
 
+    /// Set the status of a voice channel.
+    ///
+    /// Requires the user to be connected to the channel.
+    pub const fn set_voice_channel_status(
+        &self,
+        channel_id: Id<ChannelMarker>,
+    ) -> SetVoiceChannelStatus<'_> {
+        SetVoiceChannelStatus::new(self, channel_id)
+    }
+
     /// Follows a news channel by [`Id<ChannelMarker>`].
     ///
     /// The type returned is [`FollowedChannel`].
@@ -629,6 +672,33 @@ impl Client {
         GetChannelMessages::new(self, channel_id)
     }
 
+    /// Clear the permissions of a member or role in a channel.
+    ///
+    /// The member or role must be set with [`DeleteChannelPermission::member`]
+    /// or [`DeleteChannelPermission::role`].
+    ///
+    /// # Examples
+    ///
+    /// Remove the permission overwrite of a role in a channel:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use twilight_http::Client;
+    /// # let client = Client::new("my token".to_owned());
+    /// #
+    /// use twilight_http::request::AuditLogReason;
+    /// use twilight_model::id::Id;
+    ///
+    /// let channel_id = Id::new(123);
+    /// let role_id = Id::new(432);
+    ///
+    /// client
+    ///     .delete_channel_permission(channel_id)
+    ///     .role(role_id)
+    ///     .reason("role no longer needs access")
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
     pub const fn delete_channel_permission(
         &self,
         channel_id: Id<ChannelMarker>,
@@ -990,7 +1060,7 @@ impl Client {
         &self,
         guild_id: Id<GuildMarker>,
         fields: UpdateGuildOnboardingFields,
-    ) -> UpdateGuildOnboarding {
+    ) -> UpdateGuildOnboarding<'_> {
         UpdateGuildOnboarding::new(self, guild_id, fields)
     }
 
@@ -1062,6 +1132,34 @@ impl Client {
     }
 
     /// Update a guild's MFA level.
+    ///
+    /// When set to [`MfaLevel::Elevated`], moderators must have multi-factor
+    /// authentication enabled to perform moderation actions.
+    ///
+    /// Requires the current user to be the owner of the guild.
+    ///
+    /// # Examples
+    ///
+    /// Require MFA for moderation actions:
+    ///
+    /// ```no_run
+    /// use twilight_http::{request::AuditLogReason, Client};
+    /// use twilight_model::{guild::MfaLevel, id::Id};
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new("my token".to_owned());
+    ///
+    /// let guild_id = Id::new(1);
+    /// let level = client
+    ///     .update_guild_mfa(guild_id, MfaLevel::Elevated)
+    ///     .reason("securing moderation")
+    ///     .await?
+    ///     .model()
+    ///     .await?;
+    ///
+    /// assert_eq!(MfaLevel::Elevated, level);
+    /// # Ok(()) }
+    /// ```
     pub const fn update_guild_mfa(
         &self,
         guild_id: Id<GuildMarker>,
@@ -1160,11 +1258,46 @@ impl Client {
     /// An access token for the user with `guilds.join` scope is required. All
     /// other fields are optional. See [Discord Docs/Add Guild Member].
     ///
+    /// If the user is already a member of the guild the response has a status
+    /// of [`StatusCode::NO_CONTENT`] and no body, so the member can't be
+    /// deserialized. Otherwise the status is [`StatusCode::CREATED`].
+    ///
+    /// # Examples
+    ///
+    /// Add a user to a guild after they authorized the application, such as
+    /// in an OAuth2 "join my server" flow:
+    ///
+    /// ```no_run
+    /// use twilight_http::{response::StatusCode, Client};
+    /// use twilight_model::id::Id;
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new("my token".to_owned());
+    /// # let access_token = String::new();
+    ///
+    /// let guild_id = Id::new(1);
+    /// let user_id = Id::new(2);
+    /// let response = client
+    ///     .add_guild_member(guild_id, user_id, &access_token)
+    ///     .nick("newcomer")
+    ///     .await?;
+    ///
+    /// if response.status() == StatusCode::NO_CONTENT {
+    ///     println!("user was already a member");
+    /// } else {
+    ///     let member = response.model().await?;
+    ///     println!("user joined at {:?}", member.joined_at);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
     /// # Errors
     ///
     /// Returns an error of type [`ValidationErrorType::Nickname`] if the
     /// nickname is too short or too long.
     ///
+    /// [`StatusCode::CREATED`]: crate::response::StatusCode::CREATED
+    /// [`StatusCode::NO_CONTENT`]: crate::response::StatusCode::NO_CONTENT
     /// [`ValidationErrorType::Nickname`]: twilight_validate::request::ValidationErrorType::Nickname
     /// [Discord Docs/Add Guild Member]: https://discord.com/developers/docs/resources/guild#add-guild-member
     pub const fn add_guild_member<'a>(
@@ -2085,6 +2218,38 @@ impl Client {
         GetWebhook::new(self, id)
     }
 
+    /// Use a webhook by the URL Discord provides for it.
+    ///
+    /// Returns a [`WebhookClient`] whose requests are authenticated with the
+    /// webhook's token.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use twilight_http::Client;
+    ///
+    /// let client = Client::new("my token".to_owned());
+    ///
+    /// let webhook = client
+    ///     .webhook_from_url("https://discord.com/api/webhooks/123/token")?
+    ///     .webhook()
+    ///     .await?
+    ///     .model()
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`WebhookUrlInvalid`] if the URL doesn't
+    /// contain a webhook ID and token.
+    ///
+    /// [`WebhookUrlInvalid`]: ErrorType::WebhookUrlInvalid
+    pub fn webhook_from_url<'a>(&'a self, url: &'a str) -> Result<WebhookClient<'a>, Error> {
+        WebhookClient::new(self, url)
+    }
+
     /// Create a webhook in a channel.
     ///
     /// # Examples
@@ -2419,6 +2584,28 @@ impl Client {
         GetSticker::new(self, sticker_id)
     }
 
+    /// Returns a single sticker pack by its ID.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use twilight_http::Client;
+    /// use twilight_model::id::Id;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new("my token".to_owned());
+    ///
+    /// let id = Id::new(847199849233514549);
+    /// let pack = client.sticker_pack(id).await?.model().await?;
+    ///
+    /// println!("{}", pack.name);
+    /// # Ok(()) }
+    /// ```
+    pub const fn sticker_pack(&self, pack_id: Id<StickerPackMarker>) -> GetStickerPack<'_> {
+        GetStickerPack::new(self, pack_id)
+    }
+
     /// Returns a list of sticker packs available to Nitro subscribers.
     ///
     /// # Examples
@@ -2690,7 +2877,7 @@ impl Client {
         DeleteTestEntitlement::new(self, application_id, entitlement_id)
     }
 
-    /// /// Get the voters for an answer in a poll.
+    /// Get the voters for an answer in a poll.
     ///
     /// # Examples
     ///
@@ -2710,6 +2897,7 @@ impl Client {
     ///
     /// println!("{:?}", voters);
     /// # Ok(()) }
+    /// ```
     pub const fn get_answer_voters(
         &self,
         channel_id: Id<ChannelMarker>,
@@ -2740,6 +2928,90 @@ impl Client {
         GetSKUs::new(self, application_id)
     }
 
+    /// Get the role connection metadata records of an application.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use twilight_http::Client;
+    /// use twilight_model::id::Id;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new("my token".to_owned());
+    ///
+    /// let application_id = Id::new(1);
+    ///
+    /// let records = client
+    ///     .application_role_connection_metadata_records(application_id)
+    ///     .await?
+    ///     .models()
+    ///     .await?;
+    ///
+    /// println!("application has {} records", records.len());
+    /// # Ok(()) }
+    /// ```
+    pub const fn application_role_connection_metadata_records(
+        &self,
+        application_id: Id<ApplicationMarker>,
+    ) -> GetApplicationRoleConnectionMetadataRecords<'_> {
+        GetApplicationRoleConnectionMetadataRecords::new(self, application_id)
+    }
+
+    /// Set the role connection metadata records of an application, replacing
+    /// any existing records.
+    ///
+    /// Applications can have a maximum of 5 records.
+    pub const fn update_application_role_connection_metadata_records<'a>(
+        &'a self,
+        application_id: Id<ApplicationMarker>,
+        records: &'a [ApplicationRoleConnectionMetadata],
+    ) -> UpdateApplicationRoleConnectionMetadataRecords<'a> {
+        UpdateApplicationRoleConnectionMetadataRecords::new(self, application_id, records)
+    }
+
+    /// Get the current user's role connection for an application.
+    ///
+    /// Requires an OAuth2 access token with the `role_connections.write` scope.
+    pub const fn current_user_application_role_connection(
+        &self,
+        application_id: Id<ApplicationMarker>,
+    ) -> GetCurrentUserApplicationRoleConnection<'_> {
+        GetCurrentUserApplicationRoleConnection::new(self, application_id)
+    }
+
+    /// Update the current user's role connection for an application.
+    ///
+    /// Requires an OAuth2 access token with the `role_connections.write` scope.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::collections::HashMap;
+    /// use twilight_http::Client;
+    /// use twilight_model::id::Id;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new("Bearer my token".to_owned());
+    ///
+    /// let application_id = Id::new(1);
+    /// let metadata = HashMap::from([("level".to_owned(), "10".to_owned())]);
+    ///
+    /// client
+    ///     .update_current_user_application_role_connection(application_id)
+    ///     .platform_name("Twilight")
+    ///     .metadata(&metadata)
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    pub const fn update_current_user_application_role_connection(
+        &self,
+        application_id: Id<ApplicationMarker>,
+    ) -> UpdateCurrentUserApplicationRoleConnection<'_> {
+        UpdateCurrentUserApplicationRoleConnection::new(self, application_id)
+    }
+
     /// Gets all emojis associated with an application
     ///
     /// # Examples
@@ -2855,20 +3127,60 @@ impl Client {
 
     /// Execute a request, returning a future resolving to a [`Response`].
     ///
+    /// Requests still go through the configured ratelimiter. This can be used
+    /// to call endpoints that don't yet have a dedicated request builder by
+    /// creating a request via [`RequestBuilder::raw`].
+    ///
+    /// # Examples
+    ///
+    /// Get the pinned messages in a channel and read the raw response body:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::str::FromStr;
+    /// use twilight_http::{
+    ///     request::{Method, RequestBuilder},
+    ///     response::marker::ListBody,
+    ///     routing::Path,
+    ///     Client,
+    /// };
+    /// use twilight_model::channel::Message;
+    ///
+    /// let client = Client::new("my token".to_owned());
+    ///
+    /// let path_and_query = "channels/123/pins".to_owned();
+    /// let ratelimit_path = Path::from_str(&path_and_query)?;
+    /// let request = RequestBuilder::raw(Method::Get, ratelimit_path, path_and_query).build()?;
+    ///
+    /// let response = client.request::<ListBody<Message>>(request).await?;
+    /// let bytes = response.bytes().await?;
+    ///
+    /// println!("{} bytes", bytes.len());
+    /// # Ok(()) }
+    /// ```
+    ///
     /// # Errors
     ///
     /// Returns an [`ErrorType::Unauthorized`] error type if the configured
     /// token has become invalid due to expiration, revocation, etc.
     ///
+    /// [`RequestBuilder::raw`]: crate::request::RequestBuilder::raw
     /// [`Response`]: super::response::Response
     pub fn request<T>(&self, request: Request) -> ResponseFuture<T> {
-        match self.try_request::<T>(request) {
+        let id = RequestId::next();
+        let path = request.ratelimit_path.clone();
+
+        let mut future = match self.try_request::<T>(id, request) {
             Ok(future) => future,
             Err(source) => ResponseFuture::error(source),
-        }
+        };
+        future.set_request(id, path);
+
+        future
     }
 
-    fn try_request<T>(&self, request: Request) -> Result<ResponseFuture<T>, Error> {
+    #[allow(clippy::too_many_lines)]
+    fn try_request<T>(&self, id: RequestId, request: Request) -> Result<ResponseFuture<T>, Error> {
         if let Some(token_invalidated) = self.token_invalidated.as_ref() {
             if token_invalidated.load(Ordering::Relaxed) {
                 return Err(Error {
@@ -2884,18 +3196,36 @@ impl Client {
             headers: req_headers,
             method,
             path,
+            priority,
             ratelimit_path,
+            timeout,
             use_authorization_token,
         } = request;
 
         let protocol = if self.use_http { "http" } else { "https" };
         let host = self.proxy.as_deref().unwrap_or("discord.com");
 
+        let span = tracing::debug_span!(
+            "http request",
+            %id,
+            method = method.name(),
+            path = ?ratelimit_path,
+        );
         let url = format!("{protocol}://{host}/api/v{API_VERSION}/{path}");
-        tracing::debug!(?url);
+        tracing::debug!(parent: &span, ?url, "sending request");
 
         let mut builder = hyper::Request::builder().method(method.name()).uri(&url);
 
+        let intercepted = self.interceptor.as_ref().map(|interceptor| {
+            let request = InterceptedRequest {
+                id,
+                method,
+                path: ratelimit_path.clone(),
+            };
+
+            (Arc::clone(interceptor), request)
+        });
+
         if use_authorization_token {
             if let Some(token) = self.token.as_deref() {
                 let value = HeaderValue::from_str(token).map_err(|source| {
@@ -2932,7 +3262,7 @@ impl Client {
                 HeaderValue::from_static("br"),
             );
 
-            headers.insert(USER_AGENT, HeaderValue::from_static(TWILIGHT_USER_AGENT));
+            headers.insert(USER_AGENT, self.user_agent.clone());
 
             if let Some(req_headers) = req_headers {
                 for (maybe_name, value) in req_headers {
@@ -2947,20 +3277,34 @@ impl Client {
                     headers.insert(name, value.clone());
                 }
             }
+
+            if let Some((interceptor, request)) = &intercepted {
+                interceptor.before_send(request, headers);
+            }
         }
 
-        let try_req = if let Some(form) = form {
-            builder.body(Full::from(form.build()))
+        let body = if let Some(form) = form {
+            Bytes::from(form.build())
         } else if let Some(bytes) = body {
-            builder.body(Full::from(bytes))
+            Bytes::from(bytes)
         } else {
-            builder.body(Full::default())
+            Bytes::new()
         };
 
-        let inner = self.http.request(try_req.map_err(|source| Error {
-            kind: ErrorType::BuildingRequest,
-            source: Some(Box::new(source)),
-        })?);
+        let req = builder
+            .body(Full::new(body.clone()))
+            .map_err(|source| Error {
+                kind: ErrorType::BuildingRequest,
+                source: Some(Box::new(source)),
+            })?;
+
+        let retry = self.retry(&req, body, ratelimit_path.clone(), priority);
+        let inner = self.http.request(req);
+
+        let concurrency = self
+            .concurrency
+            .as_ref()
+            .map(|concurrency| (concurrency.path(&ratelimit_path), concurrency.global()));
 
         // For requests that don't use an authorization token we don't need to
         // remember whether the token is invalid. This may be for requests such
@@ -2969,19 +3313,127 @@ impl Client {
             .then(|| self.token_invalidated.clone())
             .flatten();
 
-        Ok(if let Some(ratelimiter) = &self.ratelimiter {
-            let tx_future = ratelimiter.wait_for_ticket(ratelimit_path);
-
-            ResponseFuture::ratelimit(invalid_token, inner, self.timeout, tx_future)
+        let mut future = if let Some(ratelimiter) = &self.ratelimiter {
+            let wait = |get_ticket: GetTicketFuture| -> WaitForTicketFuture {
+                Box::pin(async move { get_ticket.await?.await.map_err(From::from) })
+            };
+
+            // Priority requests aren't failed ahead of time, as they skip
+            // the queue the deadline is estimated from.
+            let tx_future = if priority {
+                wait(ratelimiter.priority_ticket(ratelimit_path))
+            } else if let Some(timeout) = timeout {
+                // Fail fast if the ratelimiter won't hand out a ticket before
+                // the request would time out anyway.
+                wait(ratelimiter.ticket_with_deadline(ratelimit_path, Instant::now() + timeout))
+            } else {
+                ratelimiter.wait_for_ticket(ratelimit_path)
+            };
+
+            ResponseFuture::ratelimit(
+                invalid_token,
+                inner,
+                timeout.unwrap_or(self.timeout),
+                tx_future,
+            )
         } else {
-            ResponseFuture::new(Box::pin(time::timeout(self.timeout, inner)), invalid_token)
-        })
+            ResponseFuture::new(inner, timeout.unwrap_or(self.timeout), invalid_token)
+        };
+
+        if let Some((interceptor, request)) = intercepted {
+            future.set_interceptor(interceptor, request);
+        }
+
+        if let Some(retry) = retry {
+            future.set_retry(retry);
+        }
+
+        // Set after the interceptor and retry, which apply to the stage the
+        // concurrency limits wrap.
+        if let Some((path, global)) = concurrency {
+            future.set_concurrency(path, global);
+        }
+
+        if let Some(timeout) = timeout {
+            future.set_deadline(timeout);
+        }
+
+        future.set_span(span);
+
+        Ok(future)
+    }
+
+    /// Create a function to send a request again if it's ratelimited despite
+    /// the ratelimiter.
+    ///
+    /// The retry keeps its priority in the ratelimiter's queue, but isn't
+    /// failed ahead of time by the ratelimiter.
+    ///
+    /// Returns [`None`] if there is no ratelimiter configured, in which case
+    /// ratelimits are handled elsewhere.
+    fn retry(
+        &self,
+        request: &hyper::Request<Full<Bytes>>,
+        body: Bytes,
+        path: Path,
+        priority: bool,
+    ) -> Option<Retry> {
+        let ratelimiter = Arc::downgrade(self.ratelimiter.as_ref()?);
+        let http = self.http.clone();
+        let method = request.method().clone();
+        let uri = request.uri().clone();
+        let headers = request.headers().clone();
+
+        Some(Box::new(move || {
+            // The ratelimiter is dropped along with the client.
+            let ratelimiter = ratelimiter.upgrade()?;
+
+            let mut request = hyper::Request::new(Full::new(body));
+            *request.method_mut() = method;
+            *request.uri_mut() = uri;
+            *request.headers_mut() = headers;
+
+            let tx_future: WaitForTicketFuture = if priority {
+                let get_ticket = ratelimiter.priority_ticket(path);
+
+                Box::pin(async move { get_ticket.await?.await.map_err(From::from) })
+            } else {
+                ratelimiter.wait_for_ticket(path)
+            };
+
+            Some((tx_future, http.request(request)))
+        }))
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Client;
+    use super::{Client, InterceptedRequest, Interceptor};
+    use crate::{
+        error::ErrorType,
+        request::{Request, RequestId},
+        response::StatusCode,
+        routing::Route,
+    };
+    use http::header::{HeaderMap, HeaderValue};
+    use std::{
+        error::Error,
+        future::IntoFuture,
+        num::NonZeroUsize,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc, Mutex,
+        },
+        time::{Duration, Instant, SystemTime},
+    };
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+    use twilight_http_ratelimiting::{
+        in_memory::BucketSnapshot, InMemoryRatelimiter, Path, RatelimitHeaders,
+    };
+    use twilight_model::{gateway::connection_info::ConnectionInfo, id::Id};
 
     #[test]
     fn client_debug_with_token() {
@@ -2990,4 +3442,471 @@ mod tests {
         );
         assert!(format!("{:?}", Client::builder().build()).contains("token: None"));
     }
+
+    /// Test that requests can be served by a local mock server configured as
+    /// the client's proxy.
+    #[tokio::test]
+    async fn proxy_mock_server() -> Result<(), Box<dyn Error>> {
+        const BODY: &str = r#"{"url":"wss://gateway.discord.gg"}"#;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let address = listener.local_addr()?;
+
+        let server = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await?;
+            let mut request = Vec::new();
+            let mut buf = [0; 1024];
+
+            while !request.ends_with(b"\r\n\r\n") {
+                let read = stream.read(&mut buf).await?;
+
+                if read == 0 {
+                    break;
+                }
+
+                request.extend_from_slice(&buf[..read]);
+            }
+
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\
+                x-ratelimit-limit: 5\r\nx-ratelimit-remaining: 4\r\nx-ratelimit-reset: 1\r\n\
+                x-ratelimit-reset-after: 2.5\r\n\r\n{BODY}",
+                BODY.len(),
+            );
+            stream.write_all(response.as_bytes()).await?;
+
+            Ok::<_, std::io::Error>(String::from_utf8_lossy(&request).into_owned())
+        });
+
+        let client = Client::builder()
+            .proxy(address.to_string(), true)
+            .ratelimiter(None)
+            .build();
+
+        let response = client.gateway().await?;
+        // Ratelimit headers are passed through for the caller to handle.
+        let headers = response.ratelimit_headers()?;
+        assert!(matches!(
+            headers,
+            RatelimitHeaders::Present(present)
+                if present.limit() == 5 && present.remaining() == 4 && present.reset_after() == 2_500
+        ));
+
+        let info = response.model().await?;
+        assert_eq!("wss://gateway.discord.gg", info.url);
+
+        let request = server.await??;
+        assert!(request.starts_with("GET /api/v10/gateway HTTP/1.1\r\n"));
+
+        Ok(())
+    }
+
+    /// Test that errors carry the ID and path of their request, along with
+    /// the response's status and error code.
+    #[tokio::test]
+    async fn error_request_context() -> Result<(), Box<dyn Error>> {
+        const BODY: &str = r#"{"code":10003,"message":"Unknown Channel"}"#;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let address = listener.local_addr()?;
+
+        let server = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await?;
+            let mut buf = [0; 1024];
+            let _read = stream.read(&mut buf).await?;
+
+            let response = format!(
+                "HTTP/1.1 404 Not Found\r\ncontent-type: application/json\r\n\
+                content-length: {}\r\n\r\n{BODY}",
+                BODY.len(),
+            );
+            stream.write_all(response.as_bytes()).await?;
+
+            Ok::<_, std::io::Error>(stream)
+        });
+
+        let client = Client::builder()
+            .proxy(address.to_string(), true)
+            .ratelimiter(None)
+            .build();
+
+        let future = client.channel(Id::new(1)).into_future();
+        let id = future.request_id().unwrap();
+        let error = future.await.unwrap_err();
+
+        assert_eq!(Some(id), error.request_id());
+        assert_eq!(Some(&Path::ChannelsId(1)), error.path());
+        assert_eq!(Some(404), error.status().map(StatusCode::get));
+        assert_eq!(Some(10_003), error.code());
+
+        // Each request has its own ID.
+        let next = client.channel(Id::new(1)).into_future();
+        assert_ne!(Some(id), next.request_id());
+
+        server.await??;
+
+        Ok(())
+    }
+
+    /// Test that interceptors may add headers to requests and are passed the
+    /// status of responses.
+    #[tokio::test]
+    async fn interceptor() -> Result<(), Box<dyn Error>> {
+        #[derive(Debug, Default)]
+        struct Recorder {
+            statuses: Mutex<Vec<(RequestId, Path, StatusCode)>>,
+        }
+
+        impl Interceptor for Arc<Recorder> {
+            fn before_send(&self, request: &InterceptedRequest, headers: &mut HeaderMap) {
+                headers.insert("x-method", HeaderValue::from_static(request.method.name()));
+            }
+
+            fn after_receive(
+                &self,
+                request: &InterceptedRequest,
+                status: StatusCode,
+                _: &HeaderMap,
+                _: Duration,
+            ) {
+                self.statuses
+                    .lock()
+                    .unwrap()
+                    .push((request.id, request.path.clone(), status));
+            }
+        }
+
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let address = listener.local_addr()?;
+
+        let server = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await?;
+            let mut buf = [0; 1024];
+            let read = stream.read(&mut buf).await?;
+
+            stream
+                .write_all(b"HTTP/1.1 204 No Content\r\ncontent-length: 0\r\n\r\n")
+                .await?;
+
+            Ok::<_, std::io::Error>(String::from_utf8_lossy(&buf[..read]).into_owned())
+        });
+
+        let recorder = Arc::new(Recorder::default());
+        let client = Client::builder()
+            .interceptor(Some(Box::new(Arc::clone(&recorder))))
+            .proxy(address.to_string(), true)
+            .ratelimiter(None)
+            .build();
+
+        let future = client.request::<()>(Request::from_route(&Route::GetGateway));
+        let id = future.request_id().unwrap();
+        future.await?;
+
+        let request = server.await??;
+        assert!(request.contains("x-method: GET\r\n"));
+        assert_eq!(
+            [(id, Path::Gateway, StatusCode::NO_CONTENT)],
+            recorder.statuses.lock().unwrap().as_slice(),
+        );
+
+        Ok(())
+    }
+
+    /// Test that requests ratelimited despite the ratelimiter are retried
+    /// after the duration in the response's body.
+    #[tokio::test]
+    async fn ratelimited_retry() -> Result<(), Box<dyn Error>> {
+        const BODY: &str =
+            r#"{"global":false,"message":"You are being rate limited.","retry_after":0.1}"#;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let address = listener.local_addr()?;
+
+        let server = tokio::spawn(async move {
+            let ratelimited = format!(
+                "HTTP/1.1 429 Too Many Requests\r\nconnection: close\r\n\
+                content-type: application/json\r\ncontent-length: {}\r\nretry-after: 1\r\n\
+                x-ratelimit-limit: 5\r\nx-ratelimit-remaining: 4\r\nx-ratelimit-reset: 0\r\n\
+                x-ratelimit-reset-after: 1\r\nx-ratelimit-scope: shared\r\n\r\n{BODY}",
+                BODY.len(),
+            );
+            let responses = [
+                ratelimited.as_str(),
+                "HTTP/1.1 204 No Content\r\ncontent-length: 0\r\n\r\n",
+            ];
+
+            for response in responses {
+                let (mut stream, _) = listener.accept().await?;
+                let mut buf = [0; 1024];
+                let _read = stream.read(&mut buf).await?;
+                stream.write_all(response.as_bytes()).await?;
+            }
+
+            Ok::<_, std::io::Error>(())
+        });
+
+        let client = Client::builder().proxy(address.to_string(), true).build();
+
+        let start = Instant::now();
+        let response = client
+            .request::<()>(Request::from_route(&Route::GetGateway))
+            .await?;
+        assert_eq!(StatusCode::NO_CONTENT, response.status());
+
+        // The body's `retry_after` takes precedence over the header's.
+        let elapsed = start.elapsed();
+        assert!(elapsed >= Duration::from_millis(100));
+        assert!(elapsed < Duration::from_secs(1));
+
+        server.await??;
+
+        Ok(())
+    }
+
+    /// Test that ratelimited requests are only retried once, returning the
+    /// second ratelimited response.
+    #[tokio::test]
+    async fn ratelimited_retry_once() -> Result<(), Box<dyn Error>> {
+        const BODY: &str =
+            r#"{"global":false,"message":"You are being rate limited.","retry_after":0.01}"#;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let address = listener.local_addr()?;
+
+        let server = tokio::spawn(async move {
+            let response = format!(
+                "HTTP/1.1 429 Too Many Requests\r\nconnection: close\r\n\
+                content-type: application/json\r\ncontent-length: {}\r\n\r\n{BODY}",
+                BODY.len(),
+            );
+
+            for _ in 0..2 {
+                let (mut stream, _) = listener.accept().await?;
+                let mut buf = [0; 1024];
+                let _read = stream.read(&mut buf).await?;
+                stream.write_all(response.as_bytes()).await?;
+            }
+
+            Ok::<_, std::io::Error>(())
+        });
+
+        let client = Client::builder().proxy(address.to_string(), true).build();
+
+        let error = client
+            .request::<()>(Request::from_route(&Route::GetGateway))
+            .await
+            .unwrap_err();
+        assert!(matches!(error.kind(), ErrorType::Response { .. }));
+        assert_eq!(Some(429), error.status().map(StatusCode::get));
+
+        server.await??;
+
+        Ok(())
+    }
+
+    /// Test that ratelimited requests aren't retried if they would time out
+    /// first.
+    #[tokio::test]
+    async fn ratelimited_retry_after_timeout() -> Result<(), Box<dyn Error>> {
+        const BODY: &str =
+            r#"{"global":false,"message":"You are being rate limited.","retry_after":30}"#;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let address = listener.local_addr()?;
+
+        let server = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await?;
+            let mut buf = [0; 1024];
+            let _read = stream.read(&mut buf).await?;
+
+            let response = format!(
+                "HTTP/1.1 429 Too Many Requests\r\nconnection: close\r\n\
+                content-type: application/json\r\ncontent-length: {}\r\n\r\n{BODY}",
+                BODY.len(),
+            );
+            stream.write_all(response.as_bytes()).await?;
+
+            Ok::<_, std::io::Error>(())
+        });
+
+        let client = Client::builder()
+            .proxy(address.to_string(), true)
+            .timeout(Duration::from_secs(5))
+            .build();
+
+        let start = Instant::now();
+        let error = client
+            .request::<()>(Request::from_route(&Route::GetGateway))
+            .await
+            .unwrap_err();
+        assert!(matches!(error.kind(), ErrorType::Response { .. }));
+        assert_eq!(Some(429), error.status().map(StatusCode::get));
+        assert!(start.elapsed() < Duration::from_secs(1));
+
+        server.await??;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn request_timeout() -> Result<(), Box<dyn Error>> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let address = listener.local_addr()?;
+
+        // Accept the connection but never respond.
+        let server = tokio::spawn(async move { listener.accept().await });
+
+        let client = Client::builder()
+            .proxy(address.to_string(), true)
+            .ratelimiter(None)
+            .timeout(Duration::from_secs(60))
+            .build();
+
+        let request = Request::builder(&Route::GetGateway)
+            .timeout(Duration::from_millis(50))
+            .build()?;
+        let error = client.request::<ConnectionInfo>(request).await.unwrap_err();
+        assert!(matches!(error.kind(), ErrorType::RequestTimedOut));
+
+        server.abort();
+
+        Ok(())
+    }
+
+    /// Test that requests over the concurrency limit aren't sent until a
+    /// permit is released.
+    #[tokio::test]
+    async fn concurrency_limit() -> Result<(), Box<dyn Error>> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let address = listener.local_addr()?;
+        let connections = Arc::new(AtomicUsize::new(0));
+
+        // Accept connections but never respond.
+        let server = tokio::spawn({
+            let connections = Arc::clone(&connections);
+
+            async move {
+                let mut streams = Vec::new();
+
+                while let Ok((stream, _)) = listener.accept().await {
+                    connections.fetch_add(1, Ordering::Relaxed);
+                    streams.push(stream);
+                }
+            }
+        });
+
+        let client = Client::builder()
+            .concurrency_limit(NonZeroUsize::MIN)
+            .proxy(address.to_string(), true)
+            .ratelimiter(None)
+            .build();
+        let first = tokio::spawn(
+            client.request::<ConnectionInfo>(
+                Request::builder(&Route::GetGateway)
+                    .timeout(Duration::from_secs(1))
+                    .build()?,
+            ),
+        );
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        let error = client
+            .request::<ConnectionInfo>(
+                Request::builder(&Route::GetGateway)
+                    .timeout(Duration::from_millis(50))
+                    .build()?,
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(error.kind(), ErrorType::RequestTimedOut));
+        assert_eq!(1, connections.load(Ordering::Relaxed));
+
+        // Dropping the first request releases its permit.
+        first.abort();
+
+        let error = client
+            .request::<ConnectionInfo>(
+                Request::builder(&Route::GetGateway)
+                    .timeout(Duration::from_millis(50))
+                    .build()?,
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(error.kind(), ErrorType::RequestTimedOut));
+        assert_eq!(2, connections.load(Ordering::Relaxed));
+
+        server.abort();
+
+        Ok(())
+    }
+
+    /// Test that time spent waiting for a concurrency permit doesn't count
+    /// against the client's timeout.
+    #[tokio::test]
+    async fn concurrency_limit_timeout() -> Result<(), Box<dyn Error>> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let address = listener.local_addr()?;
+
+        // Respond to each request after a delay.
+        let server = tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                tokio::spawn(async move {
+                    let mut buf = [0; 1024];
+                    let _read = stream.read(&mut buf).await?;
+                    tokio::time::sleep(Duration::from_millis(300)).await;
+                    stream
+                        .write_all(
+                            b"HTTP/1.1 204 No Content\r\nconnection: close\r\n\
+                            content-length: 0\r\n\r\n",
+                        )
+                        .await?;
+
+                    Ok::<_, std::io::Error>(())
+                });
+            }
+        });
+
+        let client = Client::builder()
+            .concurrency_limit(NonZeroUsize::MIN)
+            .proxy(address.to_string(), true)
+            .ratelimiter(None)
+            .timeout(Duration::from_millis(400))
+            .build();
+
+        let (first, second) = tokio::join!(
+            client.request::<()>(Request::from_route(&Route::GetGateway)),
+            client.request::<()>(Request::from_route(&Route::GetGateway)),
+        );
+        assert_eq!(StatusCode::NO_CONTENT, first?.status());
+        assert_eq!(StatusCode::NO_CONTENT, second?.status());
+
+        server.abort();
+
+        Ok(())
+    }
+
+    /// Test that queued requests fail once the client is dropped instead of
+    /// waiting for their bucket.
+    #[tokio::test]
+    async fn client_dropped() -> Result<(), Box<dyn Error>> {
+        let ratelimiter = InMemoryRatelimiter::new();
+        ratelimiter.restore([BucketSnapshot {
+            limit: 1,
+            path: Path::Gateway,
+            remaining: 0,
+            reset_at: SystemTime::now() + Duration::from_secs(60),
+        }]);
+        let client = Client::builder()
+            .ratelimiter(Some(Box::new(ratelimiter)))
+            .build();
+
+        let future = client.gateway().into_future();
+        drop(client);
+
+        let error = tokio::time::timeout(Duration::from_secs(1), future)
+            .await?
+            .unwrap_err();
+        assert!(matches!(error.kind(), ErrorType::ClientDropped));
+
+        Ok(())
+    }
 }
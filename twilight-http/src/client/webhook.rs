@@ -0,0 +1,172 @@
+use crate::{
+    error::{Error, ErrorType},
+    request::channel::webhook::{
+        DeleteWebhook, DeleteWebhookMessage, ExecuteWebhook, GetWebhook, GetWebhookMessage,
+        UpdateWebhookMessage, UpdateWebhookWithToken,
+    },
+    Client,
+};
+use std::num::NonZeroU64;
+use twilight_model::id::{
+    marker::{MessageMarker, WebhookMarker},
+    Id,
+};
+
+/// Client interface for using a webhook by its URL.
+///
+/// Every request is authenticated with the webhook's token rather than the
+/// client's token.
+///
+/// # Examples
+///
+/// Execute a webhook by the URL Discord provides:
+///
+/// ```no_run
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use twilight_http::Client;
+///
+/// let client = Client::new("my token".to_owned());
+/// let url = "https://discord.com/api/webhooks/123/token";
+///
+/// client
+///     .webhook_from_url(url)?
+///     .execute()
+///     .content("Pinkie...")
+///     .await?;
+/// # Ok(()) }
+/// ```
+#[derive(Debug)]
+pub struct WebhookClient<'a> {
+    client: &'a Client,
+    id: Id<WebhookMarker>,
+    token: &'a str,
+}
+
+impl<'a> WebhookClient<'a> {
+    /// Create a new interface for using a webhook by its URL.
+    pub(super) fn new(client: &'a Client, url: &'a str) -> Result<Self, Error> {
+        let invalid = || Error {
+            kind: ErrorType::WebhookUrlInvalid,
+            source: None,
+        };
+
+        let path = url.split("/webhooks/").nth(1).ok_or_else(invalid)?;
+        let end = path.find(['?', '#']).unwrap_or(path.len());
+        let mut segments = path[..end].split('/');
+
+        let id = segments
+            .next()
+            .and_then(|segment| segment.parse::<NonZeroU64>().ok())
+            .ok_or_else(invalid)?;
+        let token = segments
+            .next()
+            .filter(|segment| !segment.is_empty())
+            .ok_or_else(invalid)?;
+
+        Ok(Self {
+            client,
+            id: Id::from(id),
+            token,
+        })
+    }
+
+    /// ID of the webhook.
+    pub const fn id(&self) -> Id<WebhookMarker> {
+        self.id
+    }
+
+    /// Token of the webhook.
+    pub const fn token(&self) -> &'a str {
+        self.token
+    }
+
+    /// Get the webhook.
+    pub const fn webhook(&self) -> GetWebhook<'a> {
+        GetWebhook::new(self.client, self.id).token(self.token)
+    }
+
+    /// Update the webhook.
+    pub const fn update(&self) -> UpdateWebhookWithToken<'a> {
+        UpdateWebhookWithToken::new(self.client, self.id, self.token)
+    }
+
+    /// Delete the webhook.
+    pub const fn delete(&self) -> DeleteWebhook<'a> {
+        DeleteWebhook::new(self.client, self.id).token(self.token)
+    }
+
+    /// Execute the webhook, sending a message to its channel.
+    ///
+    /// The message must include at least one of [`attachments`],
+    /// [`components`], [`content`], or [`embeds`].
+    ///
+    /// [`attachments`]: ExecuteWebhook::attachments
+    /// [`components`]: ExecuteWebhook::components
+    /// [`content`]: ExecuteWebhook::content
+    /// [`embeds`]: ExecuteWebhook::embeds
+    pub const fn execute(&self) -> ExecuteWebhook<'a> {
+        ExecuteWebhook::new(self.client, self.id, self.token)
+    }
+
+    /// Get a message sent by the webhook.
+    pub const fn message(&self, message_id: Id<MessageMarker>) -> GetWebhookMessage<'a> {
+        GetWebhookMessage::new(self.client, self.id, self.token, message_id)
+    }
+
+    /// Update a message sent by the webhook.
+    pub const fn update_message(&self, message_id: Id<MessageMarker>) -> UpdateWebhookMessage<'a> {
+        UpdateWebhookMessage::new(self.client, self.id, self.token, message_id)
+    }
+
+    /// Delete a message sent by the webhook.
+    pub const fn delete_message(&self, message_id: Id<MessageMarker>) -> DeleteWebhookMessage<'a> {
+        DeleteWebhookMessage::new(self.client, self.id, self.token, message_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WebhookClient;
+    use crate::{error::ErrorType, Client};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::id::Id;
+
+    assert_impl_all!(WebhookClient<'_>: Debug, Send, Sync);
+
+    #[test]
+    fn new() {
+        let client = Client::new(String::new());
+
+        let webhook = client
+            .webhook_from_url("https://discord.com/api/webhooks/123/token")
+            .unwrap();
+        assert_eq!(Id::new(123), webhook.id());
+        assert_eq!("token", webhook.token());
+
+        let webhook = client
+            .webhook_from_url("https://canary.discord.com/api/v10/webhooks/456/other/?wait=true")
+            .unwrap();
+        assert_eq!(Id::new(456), webhook.id());
+        assert_eq!("other", webhook.token());
+    }
+
+    #[test]
+    fn new_invalid() {
+        let client = Client::new(String::new());
+
+        for url in [
+            "https://discord.com/api/channels/123/token",
+            "https://discord.com/api/webhooks/123",
+            "https://discord.com/api/webhooks/123/",
+            "https://discord.com/api/webhooks/abc/token",
+            "https://discord.com/api/webhooks/0/token",
+        ] {
+            assert!(matches!(
+                client.webhook_from_url(url).unwrap_err().kind(),
+                ErrorType::WebhookUrlInvalid
+            ));
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{api_error::ApiError, json::JsonError, response::StatusCode};
+use crate::{api_error::ApiError, json::JsonError, request::RequestId, response::StatusCode};
 use http::Response;
 use hyper::body::Incoming;
 use std::{
@@ -6,9 +6,16 @@ use std::{
     fmt::{Debug, Display, Formatter, Result as FmtResult},
     str,
 };
+use twilight_http_ratelimiting::Path;
 
 #[derive(Debug)]
 pub struct Error {
+    /// Source of the error.
+    ///
+    /// Wrapped in an [`ErrorRequest`] if the error occurred in a request sent
+    /// by a [`Client`].
+    ///
+    /// [`Client`]: crate::Client
     pub(super) source: Option<Box<dyn StdError + Send + Sync>>,
     pub(super) kind: ErrorType,
 }
@@ -23,13 +30,55 @@ impl Error {
     /// Consume the error, returning the source error if there is any.
     #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
     pub fn into_source(self) -> Option<Box<dyn StdError + Send + Sync>> {
-        self.source
+        ErrorRequest::unwrap(self.source)
     }
 
     /// Consume the error, returning the owned error type and the source error.
     #[must_use = "consuming the error into its parts has no effect if left unused"]
     pub fn into_parts(self) -> (ErrorType, Option<Box<dyn StdError + Send + Sync>>) {
-        (self.kind, self.source)
+        (self.kind, ErrorRequest::unwrap(self.source))
+    }
+
+    /// ID of the request the error occurred in.
+    ///
+    /// Returns [`None`] if the error occurred before the request was sent to
+    /// the client, such as validation errors.
+    pub fn request_id(&self) -> Option<RequestId> {
+        self.request().map(|request| request.id)
+    }
+
+    /// Ratelimit path of the request the error occurred in, identifying its
+    /// route.
+    ///
+    /// Returns [`None`] in the same cases as [`request_id`].
+    ///
+    /// [`request_id`]: Self::request_id
+    pub fn path(&self) -> Option<&Path> {
+        self.request().map(|request| &request.path)
+    }
+
+    /// Status code of the response, if the API responded with an error.
+    pub fn status(&self) -> Option<StatusCode> {
+        match &self.kind {
+            ErrorType::Response { status, .. } => Some(*status),
+            ErrorType::ServiceUnavailable { response } => {
+                Some(StatusCode::new(response.status().as_u16()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Discord's [JSON error code], if the API responded with one.
+    ///
+    /// [JSON error code]: https://discord.com/developers/docs/topics/opcodes-and-status-codes#json-json-error-codes
+    pub const fn code(&self) -> Option<u64> {
+        match &self.kind {
+            ErrorType::Response {
+                error: ApiError::General(error),
+                ..
+            } => Some(error.code),
+            _ => None,
+        }
     }
 
     pub(super) fn json(source: JsonError) -> Self {
@@ -45,6 +94,57 @@ impl Error {
             source: Some(Box::new(source)),
         }
     }
+
+    /// Attach the request the error occurred in.
+    pub(super) fn with_request(mut self, id: RequestId, path: Path) -> Self {
+        let source = ErrorRequest::unwrap(self.source.take());
+        self.source = Some(Box::new(ErrorRequest { id, path, source }));
+
+        self
+    }
+
+    /// Request the error occurred in, if it was created by a [`Client`].
+    ///
+    /// [`Client`]: crate::Client
+    fn request(&self) -> Option<&ErrorRequest> {
+        self.source.as_deref()?.downcast_ref()
+    }
+}
+
+/// Request an [`Error`] occurred in, wrapping the error's source.
+#[derive(Debug)]
+pub(super) struct ErrorRequest {
+    id: RequestId,
+    path: Path,
+    source: Option<Box<dyn StdError + Send + Sync>>,
+}
+
+impl ErrorRequest {
+    /// Unwrap the source of an error, removing its request if there is one.
+    fn unwrap(
+        source: Option<Box<dyn StdError + Send + Sync>>,
+    ) -> Option<Box<dyn StdError + Send + Sync>> {
+        match source?.downcast::<Self>() {
+            Ok(request) => request.source,
+            Err(source) => Some(source),
+        }
+    }
+}
+
+impl Display for ErrorRequest {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("request ")?;
+        Display::fmt(&self.id, f)?;
+        f.write_str(" failed")
+    }
+}
+
+impl StdError for ErrorRequest {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn StdError + 'static))
+    }
 }
 
 impl Display for Error {
@@ -52,6 +152,9 @@ impl Display for Error {
         match &self.kind {
             ErrorType::BuildingRequest => f.write_str("failed to build the request"),
             ErrorType::ChunkingResponse => f.write_str("Chunking the response failed"),
+            ErrorType::ClientDropped => {
+                f.write_str("ratelimiter ticket was canceled as the client was dropped")
+            }
             ErrorType::CreatingHeader { name, .. } => {
                 f.write_str("Parsing the value for header {}")?;
                 f.write_str(name)?;
@@ -88,15 +191,20 @@ impl Display for Error {
                 f.write_str("token in use is invalid, expired, or is revoked")
             }
             ErrorType::Validation => f.write_str("request fields have invalid values"),
+            ErrorType::WebhookUrlInvalid => f.write_str("webhook url is invalid"),
         }
     }
 }
 
 impl StdError for Error {
     fn source(&self) -> Option<&(dyn StdError + 'static)> {
-        self.source
-            .as_ref()
-            .map(|source| &**source as &(dyn StdError + 'static))
+        match self.request() {
+            Some(request) => request.source(),
+            None => self
+                .source
+                .as_ref()
+                .map(|source| &**source as &(dyn StdError + 'static)),
+        }
     }
 }
 
@@ -105,6 +213,14 @@ impl StdError for Error {
 pub enum ErrorType {
     BuildingRequest,
     ChunkingResponse,
+    /// Request's ratelimiter ticket was canceled before the request was sent.
+    ///
+    /// This occurs when the client, and with it the default ratelimiter, is
+    /// dropped or the ratelimiter is shut down via
+    /// [`InMemoryRatelimiter::shutdown`] while the request is queued.
+    ///
+    /// [`InMemoryRatelimiter::shutdown`]: twilight_http_ratelimiting::InMemoryRatelimiter::shutdown
+    ClientDropped,
     CreatingHeader {
         name: String,
     },
@@ -190,6 +306,11 @@ pub enum ErrorType {
     /// [`IntoFuture`]: std::future::IntoFuture
     /// [`TryIntoRequest`]: crate::request::TryIntoRequest
     Validation,
+    /// URL passed to [`Client::webhook_from_url`] doesn't contain a webhook ID
+    /// and token.
+    ///
+    /// [`Client::webhook_from_url`]: crate::Client::webhook_from_url
+    WebhookUrlInvalid,
 }
 
 impl Debug for ErrorType {
@@ -197,6 +318,7 @@ impl Debug for ErrorType {
         match self {
             Self::BuildingRequest => f.write_str("BuildingRequest"),
             Self::ChunkingResponse => f.write_str("ChunkingResponse"),
+            Self::ClientDropped => f.write_str("ClientDropped"),
             Self::CreatingHeader { name } => f
                 .debug_struct("CreatingHeader")
                 .field("name", name)
@@ -238,17 +360,46 @@ impl Debug for ErrorType {
                 .finish(),
             Self::Unauthorized => f.write_str("Unauthorized"),
             Self::Validation => f.write_str("Validation"),
+            Self::WebhookUrlInvalid => f.write_str("WebhookUrlInvalid"),
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::ErrorType;
+    use super::{Error, ErrorType};
     use crate::{
         api_error::{ApiError, GeneralApiError},
+        request::RequestId,
         response::StatusCode,
     };
+    use std::{error::Error as _, fmt::Error as FmtError};
+    use twilight_http_ratelimiting::Path;
+
+    #[test]
+    fn with_request_source() {
+        let id = RequestId::next();
+        let error = Error {
+            kind: ErrorType::RequestError,
+            source: Some(Box::new(FmtError)),
+        }
+        .with_request(RequestId::next(), Path::ChannelsId(1))
+        .with_request(id, Path::ChannelsId(2));
+
+        assert_eq!(Some(id), error.request_id());
+        assert_eq!(Some(&Path::ChannelsId(2)), error.path());
+        assert!(error.source().unwrap().is::<FmtError>());
+        assert!(error.into_source().unwrap().is::<FmtError>());
+
+        let error = Error {
+            kind: ErrorType::RequestTimedOut,
+            source: None,
+        }
+        .with_request(id, Path::ChannelsId(1));
+
+        assert!(error.source().is_none());
+        assert!(error.into_parts().1.is_none());
+    }
 
     /// Ensure
     #[test]
@@ -19,6 +19,10 @@ struct AddApplicationEmojiFields<'a> {
     name: &'a str,
 }
 
+/// Add an emoji to an application.
+///
+/// The image must be a data URI of a PNG, JPEG, `WebP` or GIF image.
+#[must_use = "requests must be configured and executed"]
 pub struct AddApplicationEmoji<'a> {
     fields: AddApplicationEmojiFields<'a>,
     application_id: Id<ApplicationMarker>,
@@ -66,3 +70,24 @@ impl TryIntoRequest for AddApplicationEmoji<'_> {
         request.build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    #[test]
+    fn fields() -> Result<(), Box<dyn Error>> {
+        let client = Client::new(String::new());
+
+        let request = client
+            .add_application_emoji(Id::new(1), "name", "data:image/png;base64,")
+            .try_into_request()?;
+        assert_eq!(
+            Some(br#"{"image":"data:image/png;base64,","name":"name"}"#.as_slice()),
+            request.body()
+        );
+
+        Ok(())
+    }
+}
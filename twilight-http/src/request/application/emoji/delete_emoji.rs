@@ -6,11 +6,13 @@ use twilight_model::id::{
 
 use crate::{
     request::{Request, TryIntoRequest},
-    response::{Response, ResponseFuture},
+    response::{marker::EmptyBody, Response, ResponseFuture},
     routing::Route,
     Client, Error,
 };
 
+/// Delete an application's emoji.
+#[must_use = "requests must be configured and executed"]
 pub struct DeleteApplicationEmoji<'a> {
     application_id: Id<ApplicationMarker>,
     emoji_id: Id<EmojiMarker>,
@@ -32,9 +34,9 @@ impl<'a> DeleteApplicationEmoji<'a> {
 }
 
 impl IntoFuture for DeleteApplicationEmoji<'_> {
-    type Output = Result<Response<()>, Error>;
+    type Output = Result<Response<EmptyBody>, Error>;
 
-    type IntoFuture = ResponseFuture<()>;
+    type IntoFuture = ResponseFuture<EmptyBody>;
 
     fn into_future(self) -> Self::IntoFuture {
         let http = self.http;
@@ -11,6 +11,7 @@ use twilight_model::{
     id::{marker::ApplicationMarker, Id},
 };
 
+/// Get the emojis owned by an application.
 #[must_use = "requests must be configured and executed"]
 pub struct ListApplicationEmojis<'a> {
     http: &'a Client,
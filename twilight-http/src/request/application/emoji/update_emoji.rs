@@ -20,6 +20,8 @@ struct EditApplicationEmojiFields<'a> {
     name: &'a str,
 }
 
+/// Update the name of an application's emoji.
+#[must_use = "requests must be configured and executed"]
 pub struct UpdateApplicationEmoji<'a> {
     fields: EditApplicationEmojiFields<'a>,
     application_id: Id<ApplicationMarker>,
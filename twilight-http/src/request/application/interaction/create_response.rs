@@ -1,3 +1,4 @@
+use super::CreateResponseWithResponse;
 use crate::{
     client::Client,
     error::Error,
@@ -10,6 +11,7 @@ use twilight_model::{
     http::interaction::InteractionResponse,
     id::{marker::InteractionMarker, Id},
 };
+use twilight_validate::interaction::response_data as validate_response_data;
 
 /// Respond to an interaction, by its ID and token.
 ///
@@ -20,6 +22,7 @@ pub struct CreateResponse<'a> {
     interaction_token: &'a str,
     response: &'a InteractionResponse,
     http: &'a Client,
+    with_response: bool,
 }
 
 impl<'a> CreateResponse<'a> {
@@ -34,8 +37,21 @@ impl<'a> CreateResponse<'a> {
             interaction_token,
             response,
             http,
+            with_response: false,
         }
     }
+
+    /// Return the created interaction callback in the response.
+    ///
+    /// The callback contains the message created by the response, if any. See
+    /// [Discord Docs/Create Interaction Response].
+    ///
+    /// [Discord Docs/Create Interaction Response]: https://discord.com/developers/docs/interactions/receiving-and-responding#create-interaction-response-query-string-params
+    pub const fn with_response(mut self) -> CreateResponseWithResponse<'a> {
+        self.with_response = true;
+
+        CreateResponseWithResponse::new(self.http, self)
+    }
 }
 
 impl IntoFuture for CreateResponse<'_> {
@@ -55,10 +71,21 @@ impl IntoFuture for CreateResponse<'_> {
 
 impl TryIntoRequest for CreateResponse<'_> {
     fn try_into_request(self) -> Result<Request, Error> {
-        let mut request = Request::builder(&Route::InteractionCallback {
-            interaction_id: self.interaction_id.get(),
-            interaction_token: self.interaction_token,
-        });
+        if let Some(data) = &self.response.data {
+            validate_response_data(data).map_err(Error::validation)?;
+        }
+
+        let mut request = if self.with_response {
+            Request::builder(&Route::InteractionCallbackWithResponse {
+                interaction_id: self.interaction_id.get(),
+                interaction_token: self.interaction_token,
+            })
+        } else {
+            Request::builder(&Route::InteractionCallback {
+                interaction_id: self.interaction_id.get(),
+                interaction_token: self.interaction_token,
+            })
+        };
 
         // Interaction executions don't need the authorization token, only the
         // interaction token.
@@ -90,10 +117,12 @@ impl TryIntoRequest for CreateResponse<'_> {
 #[cfg(test)]
 mod tests {
     use crate::{client::Client, request::TryIntoRequest};
-    use std::error::Error;
+    use std::{error::Error, time::Duration};
     use twilight_http_ratelimiting::Path;
     use twilight_model::{
-        http::interaction::{InteractionResponse, InteractionResponseType},
+        http::interaction::{
+            InteractionResponse, InteractionResponseData, InteractionResponseType,
+        },
         id::Id,
     };
 
@@ -123,4 +152,94 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn interaction_callback_priority() -> Result<(), Box<dyn Error>> {
+        let client = Client::new(String::new());
+
+        let response = InteractionResponse {
+            kind: InteractionResponseType::DeferredUpdateMessage,
+            data: None,
+        };
+
+        let mut req = client
+            .interaction(Id::new(1))
+            .create_response(Id::new(2), "foo", &response)
+            .try_into_request()?;
+        assert!(!req.priority());
+
+        req.set_priority(true);
+        assert!(req.priority());
+
+        Ok(())
+    }
+
+    #[test]
+    fn interaction_callback_timeout() -> Result<(), Box<dyn Error>> {
+        let client = Client::new(String::new());
+
+        let response = InteractionResponse {
+            kind: InteractionResponseType::DeferredUpdateMessage,
+            data: None,
+        };
+
+        let mut req = client
+            .interaction(Id::new(1))
+            .create_response(Id::new(2), "foo", &response)
+            .try_into_request()?;
+        assert!(req.timeout().is_none());
+
+        req.set_timeout(Duration::from_secs(3));
+        assert_eq!(Some(Duration::from_secs(3)), req.timeout());
+
+        Ok(())
+    }
+
+    #[test]
+    fn interaction_callback_with_response() -> Result<(), Box<dyn Error>> {
+        let application_id = Id::new(1);
+        let interaction_id = Id::new(2);
+        let token = "foo".to_owned().into_boxed_str();
+
+        let client = Client::new(String::new());
+
+        let response = InteractionResponse {
+            kind: InteractionResponseType::DeferredChannelMessageWithSource,
+            data: None,
+        };
+
+        let req = client
+            .interaction(application_id)
+            .create_response(interaction_id, &token, &response)
+            .with_response()
+            .try_into_request()?;
+
+        assert!(!req.use_authorization_token());
+        assert_eq!(
+            &Path::InteractionCallback(interaction_id.get()),
+            req.ratelimit_path()
+        );
+        assert!(req.path().ends_with("/callback?with_response=true"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn interaction_callback_invalid() {
+        let client = Client::new(String::new());
+
+        let response = InteractionResponse {
+            kind: InteractionResponseType::ChannelMessageWithSource,
+            data: Some(InteractionResponseData {
+                content: Some("a".repeat(2001)),
+                ..Default::default()
+            }),
+        };
+
+        assert!(client
+            .interaction(Id::new(1))
+            .create_response(Id::new(2), "foo", &response)
+            .try_into_request()
+            .is_err());
+    }
 }
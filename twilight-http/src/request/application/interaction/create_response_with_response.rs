@@ -0,0 +1,79 @@
+use super::CreateResponse;
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Request, TryIntoRequest},
+    response::{Response, ResponseFuture},
+};
+use std::future::IntoFuture;
+use twilight_model::http::interaction::InteractionCallbackResponse;
+
+/// Respond to an interaction, by its ID and token, and return the created
+/// interaction callback.
+///
+/// This endpoint is not bound to the application's global rate limit.
+///
+/// # Examples
+///
+/// ```no_run
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::env;
+/// use twilight_http::Client;
+/// use twilight_model::{
+///     http::interaction::{InteractionResponse, InteractionResponseType},
+///     id::Id,
+/// };
+///
+/// let client = Client::new(env::var("DISCORD_TOKEN")?);
+/// let application_id = Id::new(1);
+/// let interaction_id = Id::new(2);
+///
+/// let response = InteractionResponse {
+///     kind: InteractionResponseType::DeferredChannelMessageWithSource,
+///     data: None,
+/// };
+///
+/// let callback = client
+///     .interaction(application_id)
+///     .create_response(interaction_id, "token here", &response)
+///     .with_response()
+///     .await?
+///     .model()
+///     .await?;
+///
+/// println!("loading: {:?}", callback.interaction.response_message_loading);
+/// # Ok(()) }
+/// ```
+#[must_use = "requests must be configured and executed"]
+pub struct CreateResponseWithResponse<'a> {
+    http: &'a Client,
+    inner: CreateResponse<'a>,
+}
+
+impl<'a> CreateResponseWithResponse<'a> {
+    pub(crate) const fn new(http: &'a Client, inner: CreateResponse<'a>) -> Self {
+        Self { http, inner }
+    }
+}
+
+impl IntoFuture for CreateResponseWithResponse<'_> {
+    type Output = Result<Response<InteractionCallbackResponse>, Error>;
+
+    type IntoFuture = ResponseFuture<InteractionCallbackResponse>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        let http = self.http;
+
+        match self.try_into_request() {
+            Ok(request) => http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+}
+
+impl TryIntoRequest for CreateResponseWithResponse<'_> {
+    fn try_into_request(self) -> Result<Request, Error> {
+        self.inner.try_into_request()
+    }
+}
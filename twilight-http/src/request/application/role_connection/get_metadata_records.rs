@@ -0,0 +1,53 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Request, TryIntoRequest},
+    response::{marker::ListBody, Response, ResponseFuture},
+    routing::Route,
+};
+use std::future::IntoFuture;
+use twilight_model::{
+    application::role_connection::ApplicationRoleConnectionMetadata,
+    id::{marker::ApplicationMarker, Id},
+};
+
+/// Get the role connection metadata records of an application.
+#[must_use = "requests must be configured and executed"]
+pub struct GetApplicationRoleConnectionMetadataRecords<'a> {
+    application_id: Id<ApplicationMarker>,
+    http: &'a Client,
+}
+
+impl<'a> GetApplicationRoleConnectionMetadataRecords<'a> {
+    pub(crate) const fn new(http: &'a Client, application_id: Id<ApplicationMarker>) -> Self {
+        Self {
+            application_id,
+            http,
+        }
+    }
+}
+
+impl IntoFuture for GetApplicationRoleConnectionMetadataRecords<'_> {
+    type Output = Result<Response<ListBody<ApplicationRoleConnectionMetadata>>, Error>;
+
+    type IntoFuture = ResponseFuture<ListBody<ApplicationRoleConnectionMetadata>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        let http = self.http;
+
+        match self.try_into_request() {
+            Ok(request) => http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+}
+
+impl TryIntoRequest for GetApplicationRoleConnectionMetadataRecords<'_> {
+    fn try_into_request(self) -> Result<Request, Error> {
+        Ok(Request::from_route(
+            &Route::GetApplicationRoleConnectionMetadataRecords {
+                application_id: self.application_id.get(),
+            },
+        ))
+    }
+}
@@ -0,0 +1,55 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Request, TryIntoRequest},
+    response::{Response, ResponseFuture},
+    routing::Route,
+};
+use std::future::IntoFuture;
+use twilight_model::{
+    application::role_connection::ApplicationRoleConnection,
+    id::{marker::ApplicationMarker, Id},
+};
+
+/// Get the current user's role connection for an application.
+///
+/// Requires an OAuth2 access token with the `role_connections.write` scope.
+#[must_use = "requests must be configured and executed"]
+pub struct GetCurrentUserApplicationRoleConnection<'a> {
+    application_id: Id<ApplicationMarker>,
+    http: &'a Client,
+}
+
+impl<'a> GetCurrentUserApplicationRoleConnection<'a> {
+    pub(crate) const fn new(http: &'a Client, application_id: Id<ApplicationMarker>) -> Self {
+        Self {
+            application_id,
+            http,
+        }
+    }
+}
+
+impl IntoFuture for GetCurrentUserApplicationRoleConnection<'_> {
+    type Output = Result<Response<ApplicationRoleConnection>, Error>;
+
+    type IntoFuture = ResponseFuture<ApplicationRoleConnection>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        let http = self.http;
+
+        match self.try_into_request() {
+            Ok(request) => http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+}
+
+impl TryIntoRequest for GetCurrentUserApplicationRoleConnection<'_> {
+    fn try_into_request(self) -> Result<Request, Error> {
+        Ok(Request::from_route(
+            &Route::GetCurrentUserApplicationRoleConnection {
+                application_id: self.application_id.get(),
+            },
+        ))
+    }
+}
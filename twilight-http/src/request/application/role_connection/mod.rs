@@ -0,0 +1,11 @@
+mod get_metadata_records;
+mod get_user_role_connection;
+mod update_metadata_records;
+mod update_user_role_connection;
+
+pub use self::{
+    get_metadata_records::GetApplicationRoleConnectionMetadataRecords,
+    get_user_role_connection::GetCurrentUserApplicationRoleConnection,
+    update_metadata_records::UpdateApplicationRoleConnectionMetadataRecords,
+    update_user_role_connection::UpdateCurrentUserApplicationRoleConnection,
+};
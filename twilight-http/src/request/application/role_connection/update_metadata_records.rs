@@ -0,0 +1,62 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Request, TryIntoRequest},
+    response::{marker::ListBody, Response, ResponseFuture},
+    routing::Route,
+};
+use std::future::IntoFuture;
+use twilight_model::{
+    application::role_connection::ApplicationRoleConnectionMetadata,
+    id::{marker::ApplicationMarker, Id},
+};
+
+/// Set the role connection metadata records of an application, replacing any
+/// existing records.
+///
+/// Applications can have a maximum of 5 records.
+#[must_use = "requests must be configured and executed"]
+pub struct UpdateApplicationRoleConnectionMetadataRecords<'a> {
+    application_id: Id<ApplicationMarker>,
+    http: &'a Client,
+    records: &'a [ApplicationRoleConnectionMetadata],
+}
+
+impl<'a> UpdateApplicationRoleConnectionMetadataRecords<'a> {
+    pub(crate) const fn new(
+        http: &'a Client,
+        application_id: Id<ApplicationMarker>,
+        records: &'a [ApplicationRoleConnectionMetadata],
+    ) -> Self {
+        Self {
+            application_id,
+            http,
+            records,
+        }
+    }
+}
+
+impl IntoFuture for UpdateApplicationRoleConnectionMetadataRecords<'_> {
+    type Output = Result<Response<ListBody<ApplicationRoleConnectionMetadata>>, Error>;
+
+    type IntoFuture = ResponseFuture<ListBody<ApplicationRoleConnectionMetadata>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        let http = self.http;
+
+        match self.try_into_request() {
+            Ok(request) => http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+}
+
+impl TryIntoRequest for UpdateApplicationRoleConnectionMetadataRecords<'_> {
+    fn try_into_request(self) -> Result<Request, Error> {
+        Request::builder(&Route::UpdateApplicationRoleConnectionMetadataRecords {
+            application_id: self.application_id.get(),
+        })
+        .json(&self.records)
+        .build()
+    }
+}
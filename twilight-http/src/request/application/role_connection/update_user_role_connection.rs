@@ -0,0 +1,128 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Request, TryIntoRequest},
+    response::{Response, ResponseFuture},
+    routing::Route,
+};
+use serde::Serialize;
+use std::{collections::HashMap, future::IntoFuture};
+use twilight_model::{
+    application::role_connection::ApplicationRoleConnection,
+    id::{marker::ApplicationMarker, Id},
+};
+
+#[derive(Serialize)]
+struct UpdateCurrentUserApplicationRoleConnectionFields<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    metadata: Option<&'a HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    platform_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    platform_username: Option<&'a str>,
+}
+
+/// Update the current user's role connection for an application.
+///
+/// Requires an OAuth2 access token with the `role_connections.write` scope.
+#[must_use = "requests must be configured and executed"]
+pub struct UpdateCurrentUserApplicationRoleConnection<'a> {
+    application_id: Id<ApplicationMarker>,
+    fields: UpdateCurrentUserApplicationRoleConnectionFields<'a>,
+    http: &'a Client,
+}
+
+impl<'a> UpdateCurrentUserApplicationRoleConnection<'a> {
+    pub(crate) const fn new(http: &'a Client, application_id: Id<ApplicationMarker>) -> Self {
+        Self {
+            application_id,
+            fields: UpdateCurrentUserApplicationRoleConnectionFields {
+                metadata: None,
+                platform_name: None,
+                platform_username: None,
+            },
+            http,
+        }
+    }
+
+    /// Set the mapping of the application's metadata keys to the user's
+    /// values.
+    ///
+    /// Values are stringified integers, ISO8601 timestamps, or booleans
+    /// represented as `"0"` or `"1"`, depending on the type of the metadata
+    /// record.
+    pub const fn metadata(mut self, metadata: &'a HashMap<String, String>) -> Self {
+        self.fields.metadata = Some(metadata);
+
+        self
+    }
+
+    /// Set the vanity name of the platform the application represents, up to
+    /// 50 characters.
+    pub const fn platform_name(mut self, platform_name: &'a str) -> Self {
+        self.fields.platform_name = Some(platform_name);
+
+        self
+    }
+
+    /// Set the username of the user on the platform, up to 100 characters.
+    pub const fn platform_username(mut self, platform_username: &'a str) -> Self {
+        self.fields.platform_username = Some(platform_username);
+
+        self
+    }
+}
+
+impl IntoFuture for UpdateCurrentUserApplicationRoleConnection<'_> {
+    type Output = Result<Response<ApplicationRoleConnection>, Error>;
+
+    type IntoFuture = ResponseFuture<ApplicationRoleConnection>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        let http = self.http;
+
+        match self.try_into_request() {
+            Ok(request) => http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+}
+
+impl TryIntoRequest for UpdateCurrentUserApplicationRoleConnection<'_> {
+    fn try_into_request(self) -> Result<Request, Error> {
+        Request::builder(&Route::UpdateCurrentUserApplicationRoleConnection {
+            application_id: self.application_id.get(),
+        })
+        .json(&self.fields)
+        .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn request() {
+        let client = Client::new("foo".to_owned());
+        let metadata = HashMap::from([("level".to_owned(), "10".to_owned())]);
+        let builder = UpdateCurrentUserApplicationRoleConnection::new(&client, Id::new(1))
+            .metadata(&metadata)
+            .platform_name("Twilight");
+        let actual = builder
+            .try_into_request()
+            .expect("failed to create request");
+
+        let body = crate::json::to_vec(&UpdateCurrentUserApplicationRoleConnectionFields {
+            metadata: Some(&metadata),
+            platform_name: Some("Twilight"),
+            platform_username: None,
+        })
+        .expect("failed to serialize payload");
+        let route = Route::UpdateCurrentUserApplicationRoleConnection { application_id: 1 };
+        let expected = Request::builder(&route).body(body).build().unwrap();
+
+        assert_eq!(expected.body, actual.body);
+        assert_eq!(expected.path, actual.path);
+    }
+}
@@ -1,10 +1,11 @@
 use super::{Form, Method};
 use crate::{
-    error::Error,
+    error::{Error, ErrorType},
     routing::{Path, Route},
 };
 use http::header::{HeaderMap, HeaderName, HeaderValue};
 use serde::Serialize;
+use std::time::Duration;
 
 /// Builder to create a customized request.
 ///
@@ -70,7 +71,9 @@ impl RequestBuilder {
             headers: None,
             method,
             path: path_and_query,
+            priority: false,
             ratelimit_path,
+            timeout: None,
             use_authorization_token: true,
         }))
     }
@@ -129,6 +132,49 @@ impl RequestBuilder {
         self
     }
 
+    /// Whether to send the request ahead of other queued requests to the same
+    /// ratelimit bucket, such as for moderation actions that shouldn't wait
+    /// behind bulk work.
+    ///
+    /// Priority requests are still bound to the bucket's ratelimit and the
+    /// ratelimiter doesn't fail them ahead of time if they have a [timeout].
+    /// Ratelimiters that don't support priorities queue them as usual.
+    ///
+    /// Defaults to false.
+    ///
+    /// [timeout]: Self::timeout
+    pub fn priority(mut self, priority: bool) -> Self {
+        if let Ok(request) = self.0.as_mut() {
+            request.priority = priority;
+        }
+
+        self
+    }
+
+    /// Set the maximum duration the request may take, overriding the client's
+    /// configured [timeout].
+    ///
+    /// Unlike the client's timeout this includes time spent waiting in the
+    /// ratelimiter queue. The request fails with an
+    /// [`ErrorType::RequestTimedOut`] error type if the duration elapses, or
+    /// immediately with an [`ErrorType::RatelimiterTicket`] error type whose
+    /// source is a [`WouldExceedDeadline`] if the ratelimiter estimates that
+    /// it can't be sent in time. [Priority] requests are not failed ahead of
+    /// time and only fail once the duration elapses.
+    ///
+    /// [Priority]: Self::priority
+    /// [`ErrorType::RatelimiterTicket`]: crate::error::ErrorType::RatelimiterTicket
+    /// [`ErrorType::RequestTimedOut`]: crate::error::ErrorType::RequestTimedOut
+    /// [`WouldExceedDeadline`]: twilight_http_ratelimiting::WouldExceedDeadline
+    /// [timeout]: crate::client::ClientBuilder::timeout
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        if let Ok(request) = self.0.as_mut() {
+            request.timeout = Some(timeout);
+        }
+
+        self
+    }
+
     /// Whether to use the client's authorization token in the request, if one
     /// is set.
     ///
@@ -149,7 +195,9 @@ pub struct Request {
     pub(crate) headers: Option<HeaderMap<HeaderValue>>,
     pub(crate) method: Method,
     pub(crate) path: String,
+    pub(crate) priority: bool,
     pub(crate) ratelimit_path: Path,
+    pub(crate) timeout: Option<Duration>,
     pub(crate) use_authorization_token: bool,
 }
 
@@ -204,7 +252,9 @@ impl Request {
             headers: None,
             method: route.method(),
             path: route.to_string(),
+            priority: false,
             ratelimit_path: route.to_path(),
+            timeout: None,
             use_authorization_token: true,
         }
     }
@@ -234,22 +284,188 @@ impl Request {
         &self.path
     }
 
+    /// Whether the request is sent ahead of other queued requests to the same
+    /// ratelimit bucket.
+    pub const fn priority(&self) -> bool {
+        self.priority
+    }
+
     /// Path used for ratelimiting.
     pub const fn ratelimit_path(&self) -> &Path {
         &self.ratelimit_path
     }
 
+    /// Maximum duration the request may take, if overridden.
+    pub const fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
     /// Whether to use the client's authorization token in the request.
     pub const fn use_authorization_token(&self) -> bool {
         self.use_authorization_token
     }
+
+    /// Set whether to send the request ahead of other queued requests to the
+    /// same ratelimit bucket.
+    ///
+    /// This allows prioritizing requests created by request builders, such as
+    /// moderation actions, by converting them into a request via
+    /// [`TryIntoRequest`]. Refer to [`RequestBuilder::priority`] for how
+    /// priority requests are queued.
+    ///
+    /// [`TryIntoRequest`]: super::TryIntoRequest
+    pub fn set_priority(&mut self, priority: bool) {
+        self.priority = priority;
+    }
+
+    /// Set the maximum duration the request may take, overriding the client's
+    /// configured timeout.
+    ///
+    /// This allows setting the timeout of requests created by request
+    /// builders, by converting them into a request via [`TryIntoRequest`].
+    /// Refer to [`RequestBuilder::timeout`] for how the timeout applies.
+    ///
+    /// # Examples
+    ///
+    /// Respond to an interaction, giving up if the response can't be sent
+    /// within three seconds:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::{env, time::Duration};
+    /// use twilight_http::{client::Client, request::TryIntoRequest};
+    /// use twilight_model::{
+    ///     http::interaction::{InteractionResponse, InteractionResponseType},
+    ///     id::Id,
+    /// };
+    ///
+    /// let client = Client::new(env::var("DISCORD_TOKEN")?);
+    /// let response = InteractionResponse {
+    ///     kind: InteractionResponseType::DeferredChannelMessageWithSource,
+    ///     data: None,
+    /// };
+    ///
+    /// let mut request = client
+    ///     .interaction(Id::new(1))
+    ///     .create_response(Id::new(2), "token", &response)
+    ///     .try_into_request()?;
+    /// request.set_timeout(Duration::from_secs(3));
+    ///
+    /// client.request::<()>(request).await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`TryIntoRequest`]: super::TryIntoRequest
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = Some(timeout);
+    }
+
+    /// Add a field to the JSON body of the request, replacing it if it's
+    /// already present.
+    ///
+    /// This allows using fields that request builders don't support yet by
+    /// converting the builder into a request via [`TryIntoRequest`] and
+    /// executing it via [`Client::request`]. Requests sent as multipart forms,
+    /// such as ones with attachments, are not supported.
+    ///
+    /// # Examples
+    ///
+    /// Create a message with a field the builder doesn't support:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::env;
+    /// use twilight_http::{client::Client, request::TryIntoRequest};
+    /// use twilight_model::{channel::Message, id::Id};
+    ///
+    /// let client = Client::new(env::var("DISCORD_TOKEN")?);
+    /// let request = client
+    ///     .create_message(Id::new(1))
+    ///     .content("test")
+    ///     .try_into_request()?
+    ///     .json_field("new_field", true)?;
+    ///
+    /// let message = client.request::<Message>(request).await?.model().await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ErrorType::BuildingRequest`] error type if the request is
+    /// sent as a multipart form.
+    ///
+    /// Returns an [`ErrorType::Json`] error type if the body is not a JSON
+    /// object or the value could not be serialized.
+    ///
+    /// [`Client::request`]: crate::client::Client::request
+    /// [`TryIntoRequest`]: super::TryIntoRequest
+    pub fn json_field(
+        mut self,
+        name: impl Into<String>,
+        value: impl Serialize,
+    ) -> Result<Self, Error> {
+        if self.form.is_some() {
+            return Err(Error {
+                kind: ErrorType::BuildingRequest,
+                source: None,
+            });
+        }
+
+        let mut fields: serde_json::Map<String, serde_json::Value> = match self.body.as_deref() {
+            Some(body) => crate::json::from_bytes(body).map_err(Error::json)?,
+            None => serde_json::Map::new(),
+        };
+        let value = serde_json::to_value(value).map_err(|source| Error {
+            kind: ErrorType::Json,
+            source: Some(Box::new(source)),
+        })?;
+        fields.insert(name.into(), value);
+
+        self.body = Some(crate::json::to_vec(&fields).map_err(Error::json)?);
+
+        Ok(self)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::RequestBuilder;
+    use super::{Request, RequestBuilder};
+    use crate::{error::ErrorType, request::Form, routing::Route};
     use static_assertions::assert_impl_all;
-    use std::fmt::Debug;
+    use std::{error::Error, fmt::Debug};
 
     assert_impl_all!(RequestBuilder: Debug, Send, Sync);
+
+    #[test]
+    fn json_field() -> Result<(), Box<dyn Error>> {
+        let route = Route::CreateMessage { channel_id: 1 };
+
+        let request = Request::from_route(&route).json_field("tts", true)?;
+        assert_eq!(Some(br#"{"tts":true}"#.as_slice()), request.body());
+
+        let request = Request::builder(&route)
+            .body(br#"{"content":"a","tts":false}"#.to_vec())
+            .build()?
+            .json_field("tts", true)?;
+        assert_eq!(
+            Some(br#"{"content":"a","tts":true}"#.as_slice()),
+            request.body()
+        );
+
+        let error = Request::builder(&route)
+            .body(b"[]".to_vec())
+            .build()?
+            .json_field("tts", true)
+            .unwrap_err();
+        assert!(matches!(error.kind(), ErrorType::Json));
+
+        let error = Request::builder(&route)
+            .form(Form::new())
+            .build()?
+            .json_field("tts", true)
+            .unwrap_err();
+        assert!(matches!(error.kind(), ErrorType::BuildingRequest));
+
+        Ok(())
+    }
 }
@@ -72,3 +72,44 @@ impl TryIntoRequest for DeleteChannelPermissionConfigured<'_> {
         request.build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        client::Client,
+        request::{AuditLogReason, TryIntoRequest, REASON_HEADER_NAME},
+    };
+    use http::header::HeaderValue;
+    use std::error::Error;
+    use twilight_http_ratelimiting::Method;
+    use twilight_model::id::{
+        marker::{ChannelMarker, RoleMarker},
+        Id,
+    };
+
+    #[test]
+    fn request() -> Result<(), Box<dyn Error>> {
+        const CHANNEL_ID: Id<ChannelMarker> = Id::new(1);
+        const REASON: &str = "sync";
+        const ROLE_ID: Id<RoleMarker> = Id::new(2);
+
+        let client = Client::new(String::new());
+        let request = client
+            .delete_channel_permission(CHANNEL_ID)
+            .role(ROLE_ID)
+            .reason(REASON)
+            .try_into_request()?;
+
+        assert!(request.body().is_none());
+        assert_eq!(Method::Delete, request.method());
+        assert_eq!("channels/1/permissions/2", request.path());
+
+        let header = HeaderValue::from_static(REASON);
+        assert!(matches!(
+            request.headers(),
+            Some(map)
+            if map.len() == 1 && map.get(REASON_HEADER_NAME) == Some(&header)));
+
+        Ok(())
+    }
+}
@@ -14,6 +14,7 @@ mod delete_pin;
 mod follow_news_channel;
 mod get_channel;
 mod get_pins;
+mod set_voice_channel_status;
 mod update_channel;
 mod update_channel_permission;
 
@@ -22,5 +23,6 @@ pub use self::{
     delete_channel::DeleteChannel, delete_channel_permission::DeleteChannelPermission,
     delete_channel_permission_configured::DeleteChannelPermissionConfigured, delete_pin::DeletePin,
     follow_news_channel::FollowNewsChannel, get_channel::GetChannel, get_pins::GetPins,
-    update_channel::UpdateChannel, update_channel_permission::UpdateChannelPermission,
+    set_voice_channel_status::SetVoiceChannelStatus, update_channel::UpdateChannel,
+    update_channel_permission::UpdateChannelPermission,
 };
@@ -12,9 +12,15 @@ pub use self::{
 };
 use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 use std::fmt::{Display, Formatter, Result as FmtResult};
-use twilight_model::id::{marker::EmojiMarker, Id};
+use twilight_model::{
+    channel::message::EmojiReactionType,
+    id::{marker::EmojiMarker, Id},
+};
 
 /// Handle a reaction of either a custom or unicode emoji.
+///
+/// Reactions of received messages may be converted via the [`From`]
+/// implementation for [`EmojiReactionType`], such as to remove them.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum RequestReactionType<'a> {
     /// Reaction of a custom emoji.
@@ -87,6 +93,18 @@ impl Display for RequestReactionType<'_> {
     }
 }
 
+impl<'a> From<&'a EmojiReactionType> for RequestReactionType<'a> {
+    fn from(value: &'a EmojiReactionType) -> Self {
+        match value {
+            EmojiReactionType::Custom { id, name, .. } => Self::Custom {
+                id: *id,
+                name: name.as_deref(),
+            },
+            EmojiReactionType::Unicode { name } => Self::Unicode { name },
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     // `clippy::non_ascii_literal` can't be allowed on an item level; it can
@@ -99,12 +117,36 @@ mod tests {
         fmt::{Debug, Display},
         hash::Hash,
     };
-    use twilight_model::id::Id;
+    use twilight_model::{channel::message::EmojiReactionType, id::Id};
 
     assert_fields!(RequestReactionType::Custom: id, name);
     assert_fields!(RequestReactionType::Unicode: name);
     assert_impl_all!(RequestReactionType<'_>: Clone, Copy, Debug, Display, Eq, Hash, PartialEq, Send, Sync);
 
+    #[test]
+    fn from_emoji_reaction_type() {
+        let custom = EmojiReactionType::Custom {
+            animated: true,
+            id: Id::new(123),
+            name: Some("foo".to_owned()),
+        };
+        assert_eq!(
+            RequestReactionType::Custom {
+                id: Id::new(123),
+                name: Some("foo"),
+            },
+            RequestReactionType::from(&custom)
+        );
+
+        let unicode = EmojiReactionType::Unicode {
+            name: "🌈".to_owned(),
+        };
+        assert_eq!(
+            RequestReactionType::Unicode { name: "🌈" },
+            RequestReactionType::from(&unicode)
+        );
+    }
+
     #[test]
     fn display_custom_with_name() {
         let reaction = RequestReactionType::Custom {
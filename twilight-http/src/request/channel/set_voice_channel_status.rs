@@ -0,0 +1,155 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{self, AuditLogReason, Request, TryIntoRequest},
+    response::{marker::EmptyBody, Response, ResponseFuture},
+    routing::Route,
+};
+use serde::Serialize;
+use std::future::IntoFuture;
+use twilight_model::id::{marker::ChannelMarker, Id};
+use twilight_validate::{
+    channel::{voice_status as validate_voice_status, ChannelValidationError},
+    request::{audit_reason as validate_audit_reason, ValidationError},
+};
+
+#[derive(Serialize)]
+struct SetVoiceChannelStatusFields<'a> {
+    status: Option<&'a str>,
+}
+
+/// Set the status of a voice channel.
+///
+/// Requires the user to be connected to the channel and have the
+/// [`SET_VOICE_CHANNEL_STATUS`] permission.
+///
+/// # Examples
+///
+/// ```no_run
+/// use twilight_http::Client;
+/// use twilight_model::id::Id;
+///
+/// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new("my token".to_owned());
+///
+/// let channel_id = Id::new(123);
+/// client
+///     .set_voice_channel_status(channel_id)
+///     .status(Some("Playing games"))
+///     .await?;
+/// # Ok(()) }
+/// ```
+///
+/// [`SET_VOICE_CHANNEL_STATUS`]: twilight_model::guild::Permissions::SET_VOICE_CHANNEL_STATUS
+#[must_use = "requests must be configured and executed"]
+pub struct SetVoiceChannelStatus<'a> {
+    channel_id: Id<ChannelMarker>,
+    fields: Result<SetVoiceChannelStatusFields<'a>, ChannelValidationError>,
+    http: &'a Client,
+    reason: Result<Option<&'a str>, ValidationError>,
+}
+
+impl<'a> SetVoiceChannelStatus<'a> {
+    pub(crate) const fn new(http: &'a Client, channel_id: Id<ChannelMarker>) -> Self {
+        Self {
+            channel_id,
+            fields: Ok(SetVoiceChannelStatusFields { status: None }),
+            http,
+            reason: Ok(None),
+        }
+    }
+
+    /// Set the status of the channel.
+    ///
+    /// Pass [`None`] to clear the status. Defaults to [`None`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`VoiceStatusInvalid`] if the status is longer
+    /// than 500 characters.
+    ///
+    /// [`VoiceStatusInvalid`]: twilight_validate::channel::ChannelValidationErrorType::VoiceStatusInvalid
+    pub fn status(mut self, status: Option<&'a str>) -> Self {
+        self.fields = self.fields.and_then(|mut fields| {
+            if let Some(status) = status {
+                validate_voice_status(status)?;
+            }
+
+            fields.status = status;
+
+            Ok(fields)
+        });
+
+        self
+    }
+}
+
+impl<'a> AuditLogReason<'a> for SetVoiceChannelStatus<'a> {
+    fn reason(mut self, reason: &'a str) -> Self {
+        self.reason = validate_audit_reason(reason).and(Ok(Some(reason)));
+
+        self
+    }
+}
+
+impl IntoFuture for SetVoiceChannelStatus<'_> {
+    type Output = Result<Response<EmptyBody>, Error>;
+
+    type IntoFuture = ResponseFuture<EmptyBody>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        let http = self.http;
+
+        match self.try_into_request() {
+            Ok(request) => http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+}
+
+impl TryIntoRequest for SetVoiceChannelStatus<'_> {
+    fn try_into_request(self) -> Result<Request, Error> {
+        let fields = self.fields.map_err(Error::validation)?;
+        let mut request = Request::builder(&Route::SetVoiceChannelStatus {
+            channel_id: self.channel_id.get(),
+        })
+        .json(&fields);
+
+        if let Some(reason) = self.reason.map_err(Error::validation)? {
+            request = request.headers(request::audit_header(reason)?);
+        }
+
+        request.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    #[test]
+    fn status() -> Result<(), Box<dyn Error>> {
+        let client = Client::new(String::new());
+        let channel_id = Id::new(1);
+
+        let request = client
+            .set_voice_channel_status(channel_id)
+            .status(Some("status"))
+            .try_into_request()?;
+        assert_eq!(Some(br#"{"status":"status"}"#.as_slice()), request.body());
+
+        let request = client
+            .set_voice_channel_status(channel_id)
+            .try_into_request()?;
+        assert_eq!(Some(br#"{"status":null}"#.as_slice()), request.body());
+
+        assert!(client
+            .set_voice_channel_status(channel_id)
+            .status(Some(&"a".repeat(501)))
+            .try_into_request()
+            .is_err());
+
+        Ok(())
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Request, TryIntoRequest},
+    request::{self, AuditLogReason, Request, TryIntoRequest},
     response::{Response, ResponseFuture},
     routing::Route,
 };
@@ -11,8 +11,12 @@ use twilight_model::{
     channel::{thread::AutoArchiveDuration, Channel, ChannelType},
     id::{marker::ChannelMarker, Id},
 };
-use twilight_validate::channel::{
-    is_thread as validate_is_thread, name as validate_name, ChannelValidationError,
+use twilight_validate::{
+    channel::{
+        is_thread as validate_is_thread, name as validate_name,
+        rate_limit_per_user as validate_rate_limit_per_user, ChannelValidationError,
+    },
+    request::{audit_reason as validate_audit_reason, ValidationError},
 };
 
 #[derive(Serialize)]
@@ -24,6 +28,8 @@ struct CreateThreadFields<'a> {
     #[serde(rename = "type")]
     kind: ChannelType,
     name: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rate_limit_per_user: Option<u16>,
 }
 
 /// Start a thread that is not connected to a message.
@@ -37,6 +43,7 @@ pub struct CreateThread<'a> {
     channel_id: Id<ChannelMarker>,
     fields: Result<CreateThreadFields<'a>, ChannelValidationError>,
     http: &'a Client,
+    reason: Result<Option<&'a str>, ValidationError>,
 }
 
 impl<'a> CreateThread<'a> {
@@ -51,6 +58,7 @@ impl<'a> CreateThread<'a> {
             invitable: None,
             kind,
             name,
+            rate_limit_per_user: None,
         })
         .and_then(|fields| {
             validate_name(name)?;
@@ -63,6 +71,7 @@ impl<'a> CreateThread<'a> {
             channel_id,
             fields,
             http,
+            reason: Ok(None),
         }
     }
 
@@ -86,6 +95,38 @@ impl<'a> CreateThread<'a> {
 
         self
     }
+
+    /// Set the number of seconds that a user must wait before they are
+    /// able to send another message.
+    ///
+    /// The minimum is 0 and the maximum is 21600. This is also known as "Slow
+    /// Mode". See [Discord Docs/Channel Object].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`RateLimitPerUserInvalid`] if the rate limit
+    /// is invalid.
+    ///
+    /// [`RateLimitPerUserInvalid`]: twilight_validate::channel::ChannelValidationErrorType::RateLimitPerUserInvalid
+    /// [Discord Docs/Channel Object]: https://discordapp.com/developers/docs/resources/channel#channel-object-channel-structure
+    pub fn rate_limit_per_user(mut self, rate_limit_per_user: u16) -> Self {
+        self.fields = self.fields.and_then(|mut fields| {
+            validate_rate_limit_per_user(rate_limit_per_user)?;
+            fields.rate_limit_per_user = Some(rate_limit_per_user);
+
+            Ok(fields)
+        });
+
+        self
+    }
+}
+
+impl<'a> AuditLogReason<'a> for CreateThread<'a> {
+    fn reason(mut self, reason: &'a str) -> Self {
+        self.reason = validate_audit_reason(reason).and(Ok(Some(reason)));
+
+        self
+    }
 }
 
 impl IntoFuture for CreateThread<'_> {
@@ -107,10 +148,15 @@ impl TryIntoRequest for CreateThread<'_> {
     fn try_into_request(self) -> Result<Request, Error> {
         let fields = self.fields.map_err(Error::validation)?;
 
-        Request::builder(&Route::CreateThread {
+        let mut request = Request::builder(&Route::CreateThread {
             channel_id: self.channel_id.get(),
         })
-        .json(&fields)
-        .build()
+        .json(&fields);
+
+        if let Some(reason) = self.reason.map_err(Error::validation)? {
+            request = request.headers(request::audit_header(reason)?);
+        }
+
+        request.build()
     }
 }
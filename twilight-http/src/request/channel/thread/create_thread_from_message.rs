@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Request, TryIntoRequest},
+    request::{self, AuditLogReason, Request, TryIntoRequest},
     response::{Response, ResponseFuture},
     routing::Route,
 };
@@ -14,13 +14,21 @@ use twilight_model::{
         Id,
     },
 };
-use twilight_validate::channel::{name as validate_name, ChannelValidationError};
+use twilight_validate::{
+    channel::{
+        name as validate_name, rate_limit_per_user as validate_rate_limit_per_user,
+        ChannelValidationError,
+    },
+    request::{audit_reason as validate_audit_reason, ValidationError},
+};
 
 #[derive(Serialize)]
 struct CreateThreadFromMessageFields<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     auto_archive_duration: Option<AutoArchiveDuration>,
     name: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rate_limit_per_user: Option<u16>,
 }
 
 /// Create a new thread from an existing message.
@@ -49,6 +57,7 @@ pub struct CreateThreadFromMessage<'a> {
     fields: Result<CreateThreadFromMessageFields<'a>, ChannelValidationError>,
     http: &'a Client,
     message_id: Id<MessageMarker>,
+    reason: Result<Option<&'a str>, ValidationError>,
 }
 
 impl<'a> CreateThreadFromMessage<'a> {
@@ -61,6 +70,7 @@ impl<'a> CreateThreadFromMessage<'a> {
         let fields = Ok(CreateThreadFromMessageFields {
             auto_archive_duration: None,
             name,
+            rate_limit_per_user: None,
         })
         .and_then(|fields| {
             validate_name(name)?;
@@ -73,6 +83,7 @@ impl<'a> CreateThreadFromMessage<'a> {
             fields,
             http,
             message_id,
+            reason: Ok(None),
         }
     }
 
@@ -87,6 +98,38 @@ impl<'a> CreateThreadFromMessage<'a> {
 
         self
     }
+
+    /// Set the number of seconds that a user must wait before they are
+    /// able to send another message.
+    ///
+    /// The minimum is 0 and the maximum is 21600. This is also known as "Slow
+    /// Mode". See [Discord Docs/Channel Object].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`RateLimitPerUserInvalid`] if the rate limit
+    /// is invalid.
+    ///
+    /// [`RateLimitPerUserInvalid`]: twilight_validate::channel::ChannelValidationErrorType::RateLimitPerUserInvalid
+    /// [Discord Docs/Channel Object]: https://discordapp.com/developers/docs/resources/channel#channel-object-channel-structure
+    pub fn rate_limit_per_user(mut self, rate_limit_per_user: u16) -> Self {
+        self.fields = self.fields.and_then(|mut fields| {
+            validate_rate_limit_per_user(rate_limit_per_user)?;
+            fields.rate_limit_per_user = Some(rate_limit_per_user);
+
+            Ok(fields)
+        });
+
+        self
+    }
+}
+
+impl<'a> AuditLogReason<'a> for CreateThreadFromMessage<'a> {
+    fn reason(mut self, reason: &'a str) -> Self {
+        self.reason = validate_audit_reason(reason).and(Ok(Some(reason)));
+
+        self
+    }
 }
 
 impl IntoFuture for CreateThreadFromMessage<'_> {
@@ -108,11 +151,16 @@ impl TryIntoRequest for CreateThreadFromMessage<'_> {
     fn try_into_request(self) -> Result<Request, Error> {
         let fields = self.fields.map_err(Error::validation)?;
 
-        Request::builder(&Route::CreateThreadFromMessage {
+        let mut request = Request::builder(&Route::CreateThreadFromMessage {
             channel_id: self.channel_id.get(),
             message_id: self.message_id.get(),
         })
-        .json(&fields)
-        .build()
+        .json(&fields);
+
+        if let Some(reason) = self.reason.map_err(Error::validation)? {
+            request = request.headers(request::audit_header(reason)?);
+        }
+
+        request.build()
     }
 }
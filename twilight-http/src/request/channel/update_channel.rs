@@ -59,7 +59,7 @@ struct UpdateChannelFields<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     rtc_region: Option<Nullable<&'a str>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    topic: Option<&'a str>,
+    topic: Option<Nullable<&'a str>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     user_limit: Option<u16>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -210,6 +210,8 @@ impl<'a> UpdateChannel<'a> {
 
     /// Set the forum topic.
     ///
+    /// Set to `None` to clear.
+    ///
     /// The maximum length is 4096 UTF-16 characters. See
     /// [Discord Docs/Channel Object].
     ///
@@ -227,7 +229,7 @@ impl<'a> UpdateChannel<'a> {
                 validate_forum_topic(topic)?;
             }
 
-            fields.topic = topic;
+            fields.topic = Some(Nullable(topic));
 
             Ok(fields)
         });
@@ -337,6 +339,8 @@ impl<'a> UpdateChannel<'a> {
 
     /// Set the topic.
     ///
+    /// Set to `None` to clear.
+    ///
     /// The maximum length is 1024 UTF-16 characters. See
     /// [Discord Docs/Channel Object].
     ///
@@ -346,10 +350,13 @@ impl<'a> UpdateChannel<'a> {
     ///
     /// [Discord Docs/Channel Object]: https://discordapp.com/developers/docs/resources/channel#channel-object-channel-structure
     /// [`TopicInvalid`]: twilight_validate::channel::ChannelValidationErrorType::TopicInvalid
-    pub fn topic(mut self, topic: &'a str) -> Self {
+    pub fn topic(mut self, topic: Option<&'a str>) -> Self {
         self.fields = self.fields.and_then(|mut fields| {
-            validate_topic(topic)?;
-            fields.topic.replace(topic);
+            if let Some(topic) = topic {
+                validate_topic(topic)?;
+            }
+
+            fields.topic = Some(Nullable(topic));
 
             Ok(fields)
         });
@@ -442,3 +449,19 @@ impl TryIntoRequest for UpdateChannel<'_> {
         request.build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clear_topic() {
+        let client = Client::new("foo".to_owned());
+        let actual = UpdateChannel::new(&client, Id::new(1))
+            .topic(None)
+            .try_into_request()
+            .expect("failed to create request");
+
+        assert_eq!(Some(br#"{"topic":null}"#.as_slice()), actual.body());
+    }
+}
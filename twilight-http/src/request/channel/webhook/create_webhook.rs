@@ -69,7 +69,11 @@ impl<'a> CreateWebhook<'a> {
     /// `data:image/{type};base64,{data}` where `{type}` is the image MIME type
     /// and `{data}` is the base64-encoded image. See [Discord Docs/Image Data].
     ///
+    /// [`ImageData`] in the `twilight-util` crate creates one from raw image
+    /// bytes.
+    ///
     /// [Discord Docs/Image Data]: https://discord.com/developers/docs/reference#image-data
+    /// [`ImageData`]: https://docs.rs/twilight-util/latest/twilight_util/image/struct.ImageData.html
     pub fn avatar(mut self, avatar: &'a str) -> Self {
         self.fields = self.fields.map(|mut fields| {
             fields.avatar = Some(avatar);
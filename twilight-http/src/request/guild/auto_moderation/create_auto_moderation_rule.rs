@@ -183,6 +183,24 @@ impl<'a> CreateAutoModerationRule<'a> {
         self
     }
 
+    /// Append an action of type [`BlockMemberInteraction`].
+    ///
+    /// [`BlockMemberInteraction`]: AutoModerationActionType::BlockMemberInteraction
+    pub fn action_block_member_interaction(mut self) -> Self {
+        self.fields = self.fields.map(|mut fields| {
+            fields.actions.get_or_insert_with(Vec::new).push(
+                CreateAutoModerationRuleFieldsAction {
+                    kind: AutoModerationActionType::BlockMemberInteraction,
+                    metadata: CreateAutoModerationRuleFieldsActionMetadata::default(),
+                },
+            );
+
+            fields
+        });
+
+        self
+    }
+
     /// Append an action of type [`SendAlertMessage`].
     ///
     /// [`SendAlertMessage`]: AutoModerationActionType::SendAlertMessage
@@ -436,6 +454,69 @@ impl<'a> CreateAutoModerationRule<'a> {
         self.exec()
     }
 
+    /// Create the request with the trigger type [`MemberProfile`], then
+    /// execute it.
+    ///
+    /// Rules of this type require the `keyword_filter`, `regex_patterns` and
+    /// `allow_list` fields specified, and this method ensures this. The rule's
+    /// event type should be [`MemberUpdate`]. See
+    /// [Discord Docs/Trigger Metadata] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValidationErrorType::AutoModerationMetadataKeywordFilter`] if the `keyword_filter`
+    /// field is invalid.
+    ///
+    /// Returns [`ValidationErrorType::AutoModerationMetadataKeywordFilterItem`] if a `keyword_filter`
+    /// item is invalid.
+    ///
+    /// Returns [`ValidationErrorType::AutoModerationMetadataAllowList`] if the `allow_list` field is
+    /// invalid.
+    ///
+    /// Returns [`ValidationErrorType::AutoModerationMetadataAllowListItem`] if an `allow_list` item
+    /// is invalid.
+    ///
+    /// Returns [`ValidationErrorType::AutoModerationMetadataRegexPatterns`] if the `regex_patterns`
+    /// field is invalid.
+    ///
+    /// Returns [`ValidationErrorType::AutoModerationMetadataRegexPatternsItem`] if a `regex_patterns`
+    /// item is invalid.
+    ///
+    /// [`MemberProfile`]: AutoModerationTriggerType::MemberProfile
+    /// [`MemberUpdate`]: AutoModerationEventType::MemberUpdate
+    /// [Discord Docs/Trigger Metadata]: https://discord.com/developers/docs/resources/auto-moderation#auto-moderation-rule-object-trigger-metadata
+    /// [`ValidationErrorType::AutoModerationMetadataKeywordFilter`]: twilight_validate::request::ValidationErrorType::AutoModerationMetadataKeywordFilter
+    /// [`ValidationErrorType::AutoModerationMetadataKeywordFilterItem`]: twilight_validate::request::ValidationErrorType::AutoModerationMetadataKeywordFilterItem
+    /// [`ValidationErrorType::AutoModerationMetadataAllowList`]: twilight_validate::request::ValidationErrorType::AutoModerationMetadataAllowList
+    /// [`ValidationErrorType::AutoModerationMetadataAllowListItem`]: twilight_validate::request::ValidationErrorType::AutoModerationMetadataAllowListItem
+    /// [`ValidationErrorType::AutoModerationMetadataRegexPatterns`]: twilight_validate::request::ValidationErrorType::AutoModerationMetadataRegexPatterns
+    /// [`ValidationErrorType::AutoModerationMetadataRegexPatternsItem`]: twilight_validate::request::ValidationErrorType::AutoModerationMetadataRegexPatternsItem
+    pub fn with_member_profile(
+        mut self,
+        keyword_filter: &'a [&'a str],
+        regex_patterns: &'a [&'a str],
+        allow_list: &'a [&'a str],
+    ) -> ResponseFuture<AutoModerationRule> {
+        self.fields = self.fields.and_then(|mut fields| {
+            validate_auto_moderation_metadata_keyword_allow_list(allow_list)?;
+            validate_auto_moderation_metadata_keyword_filter(keyword_filter)?;
+            validate_auto_moderation_metadata_regex_patterns(regex_patterns)?;
+            fields.trigger_metadata = Some(CreateAutoModerationRuleFieldsTriggerMetadata {
+                allow_list: Some(allow_list),
+                keyword_filter: Some(keyword_filter),
+                presets: None,
+                mention_total_limit: None,
+                regex_patterns: Some(regex_patterns),
+            });
+
+            fields.trigger_type = Some(AutoModerationTriggerType::MemberProfile);
+
+            Ok(fields)
+        });
+
+        self.exec()
+    }
+
     /// Execute the request, returning a future resolving to a [`Response`].
     ///
     /// [`Response`]: crate::response::Response
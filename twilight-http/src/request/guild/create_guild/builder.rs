@@ -355,6 +355,23 @@ impl TextFieldsBuilder {
         self.0
     }
 
+    /// Set the placeholder ID of the channel, such as to refer to it in
+    /// [`CreateGuild::afk_channel_id`] or [`CreateGuild::system_channel_id`].
+    ///
+    /// Defaults to 1. IDs must be unique among the guild's channels, and
+    /// categories added via [`GuildChannelFieldsBuilder::add_category_builder`]
+    /// are assigned IDs counting up from 2.
+    ///
+    /// [`CreateGuild::afk_channel_id`]: super::CreateGuild::afk_channel_id
+    /// [`CreateGuild::system_channel_id`]: super::CreateGuild::system_channel_id
+    pub fn id(mut self, id: Id<ChannelMarker>) -> Self {
+        if let Ok(fields) = self.0.as_mut() {
+            fields.id = id;
+        }
+
+        self
+    }
+
     /// Make the channel NSFW.
     pub fn nsfw(mut self) -> Self {
         if let Ok(fields) = self.0.as_mut() {
@@ -555,6 +572,23 @@ impl VoiceFieldsBuilder {
         self
     }
 
+    /// Set the placeholder ID of the channel, such as to refer to it in
+    /// [`CreateGuild::afk_channel_id`] or [`CreateGuild::system_channel_id`].
+    ///
+    /// Defaults to 1. IDs must be unique among the guild's channels, and
+    /// categories added via [`GuildChannelFieldsBuilder::add_category_builder`]
+    /// are assigned IDs counting up from 2.
+    ///
+    /// [`CreateGuild::afk_channel_id`]: super::CreateGuild::afk_channel_id
+    /// [`CreateGuild::system_channel_id`]: super::CreateGuild::system_channel_id
+    pub fn id(mut self, id: Id<ChannelMarker>) -> Self {
+        if let Ok(fields) = self.0.as_mut() {
+            fields.id = id;
+        }
+
+        self
+    }
+
     /// Set the channel's permission overwrites.
     pub fn permission_overwrites(mut self, overwrites: Vec<PermissionOverwrite>) -> Self {
         if let Ok(fields) = self.0.as_mut() {
@@ -489,6 +489,15 @@ impl<'a> CreateGuild<'a> {
 
         self
     }
+
+    /// Set the verification level required for members to be able to talk.
+    pub fn verification_level(mut self, verification_level: VerificationLevel) -> Self {
+        if let Ok(fields) = self.fields.as_mut() {
+            fields.verification_level = Some(verification_level);
+        }
+
+        self
+    }
 }
 
 impl IntoFuture for CreateGuild<'_> {
@@ -513,3 +522,89 @@ impl TryIntoRequest for CreateGuild<'_> {
         Request::builder(&Route::CreateGuild).json(&fields).build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CategoryFieldsBuilder, CreateGuild, GuildChannelFieldsBuilder, RoleFieldsBuilder,
+        TextFieldsBuilder, VoiceFieldsBuilder,
+    };
+    use crate::{client::Client, request::TryIntoRequest};
+    use serde_json::{json, Value};
+    use std::error::Error;
+    use twilight_model::{
+        guild::{DefaultMessageNotificationLevel, Permissions, VerificationLevel},
+        http::permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
+        id::Id,
+    };
+
+    #[test]
+    fn request() -> Result<(), Box<dyn Error>> {
+        let moderator = RoleFieldsBuilder::new("moderator".to_owned())
+            .id(Id::new(2))
+            .build()?;
+        let rules = TextFieldsBuilder::new("rules".to_owned())
+            .id(Id::new(10))
+            .build()?;
+        let staff = TextFieldsBuilder::new("staff".to_owned())
+            .id(Id::new(11))
+            .permission_overwrites(vec![PermissionOverwrite {
+                allow: None,
+                deny: Some(Permissions::VIEW_CHANNEL),
+                id: Id::new(1),
+                kind: PermissionOverwriteType::Role,
+            }])
+            .build()?;
+        let lounge = VoiceFieldsBuilder::new("lounge".to_owned())
+            .id(Id::new(12))
+            .build()?;
+        let channels = GuildChannelFieldsBuilder::new()
+            .add_text(rules)
+            .add_category_builder(
+                CategoryFieldsBuilder::new("staff".to_owned())
+                    .add_text(staff)
+                    .add_voice(lounge),
+            )
+            .build()?;
+
+        let client = Client::new(String::new());
+        let request = CreateGuild::new(&client, "guild".to_owned())
+            .roles(vec![moderator])
+            .channels(channels)
+            .afk_channel_id(Id::new(12))
+            .default_message_notifications(DefaultMessageNotificationLevel::Mentions)
+            .system_channel_id(Id::new(10))
+            .verification_level(VerificationLevel::Medium)
+            .try_into_request()?;
+
+        let body: Value = serde_json::from_slice(request.body().ok_or("missing body")?)?;
+        let expected = json!({
+            "afk_channel_id": "12",
+            "channels": [
+                {"id": "10", "name": "rules", "type": 0},
+                {"id": "2", "name": "staff", "type": 4},
+                {
+                    "id": "11",
+                    "name": "staff",
+                    "parent_id": "2",
+                    "permission_overwrites": [
+                        {"deny": "1024", "id": "1", "type": 0},
+                    ],
+                    "type": 0,
+                },
+                {"id": "12", "name": "lounge", "parent_id": "2", "type": 2},
+            ],
+            "default_message_notifications": 1,
+            "name": "guild",
+            "roles": [
+                {"id": "1", "name": "@everyone"},
+                {"id": "2", "name": "moderator"},
+            ],
+            "system_channel_id": "10",
+            "verification_level": 2,
+        });
+        assert_eq!(expected, body);
+
+        Ok(())
+    }
+}
@@ -30,7 +30,11 @@ struct CreateEmojiFields<'a> {
 /// `data:image/{type};base64,{data}` where `{type}` is the image MIME type and
 /// `{data}` is the base64-encoded image. See [Discord Docs/Image Data].
 ///
+/// [`ImageData`] in the `twilight-util` crate creates one from raw image
+/// bytes.
+///
 /// [Discord Docs/Image Data]: https://discord.com/developers/docs/reference#image-data
+/// [`ImageData`]: https://docs.rs/twilight-util/latest/twilight_util/image/struct.ImageData.html
 #[must_use = "requests must be configured and executed"]
 pub struct CreateEmoji<'a> {
     fields: CreateEmojiFields<'a>,
@@ -42,6 +42,9 @@ pub struct AddGuildMember<'a> {
 /// An access token for the user with `guilds.join` scope is required. All other
 /// fields are optional. See [Discord Docs/Add Guild Member].
 ///
+/// Responds with the new member, or with no body if the user is already a
+/// member of the guild. Refer to [`Client::add_guild_member`] for an example.
+///
 /// [Discord Docs/Add Guild Member]: https://discord.com/developers/docs/resources/guild#add-guild-member
 impl<'a> AddGuildMember<'a> {
     pub(crate) const fn new(
@@ -143,3 +146,32 @@ impl TryIntoRequest for AddGuildMember<'_> {
         .build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{client::Client, request::TryIntoRequest};
+    use std::error::Error;
+    use twilight_http_ratelimiting::Method;
+    use twilight_model::id::Id;
+
+    #[test]
+    fn request() -> Result<(), Box<dyn Error>> {
+        let client = Client::new(String::new());
+        let roles = [Id::new(3)];
+        let request = client
+            .add_guild_member(Id::new(1), Id::new(2), "token")
+            .mute(true)
+            .nick("nick")
+            .roles(&roles)
+            .try_into_request()?;
+
+        assert_eq!(
+            Some(br#"{"access_token":"token","mute":true,"nick":"nick","roles":["3"]}"#.as_slice()),
+            request.body()
+        );
+        assert_eq!(Method::Put, request.method());
+        assert_eq!("guilds/1/members/2", request.path());
+
+        Ok(())
+    }
+}
@@ -20,7 +20,7 @@ struct CreateRoleFields<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     hoist: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    icon: Option<&'a [u8]>,
+    icon: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     mentionable: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -104,7 +104,22 @@ impl<'a> CreateRole<'a> {
     /// See [Discord Docs/Image Data].
     ///
     /// [Discord Docs/Image Data]: https://discord.com/developers/docs/reference#image-data
-    pub const fn icon(mut self, icon: &'a [u8]) -> Self {
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use twilight_http::Client;
+    /// use twilight_model::id::Id;
+    ///
+    /// let client = Client::new("token".to_owned());
+    /// let guild_id = Id::new(1);
+    /// let icon = "data:image/png;base64,BASE64_ENCODED_PNG_IMAGE_DATA";
+    ///
+    /// client.create_role(guild_id).icon(icon).await?;
+    /// # Ok(()) }
+    /// ```
+    pub const fn icon(mut self, icon: &'a str) -> Self {
         self.fields.icon = Some(icon);
 
         self
@@ -179,3 +194,22 @@ impl TryIntoRequest for CreateRole<'_> {
         request.build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn icon() {
+        let client = Client::new("foo".to_owned());
+        let actual = CreateRole::new(&client, Id::new(1))
+            .icon("data:image/png;base64,AAAA")
+            .try_into_request()
+            .expect("failed to create request");
+
+        assert_eq!(
+            Some(br#"{"icon":"data:image/png;base64,AAAA"}"#.as_slice()),
+            actual.body()
+        );
+    }
+}
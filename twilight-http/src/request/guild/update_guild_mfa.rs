@@ -18,7 +18,9 @@ struct UpdateGuildMfaFields {
     level: MfaLevel,
 }
 
-/// Update a guild's MFA level.
+/// Update a guild's MFA level, returning the new level.
+///
+/// Requires the current user to be the owner of the guild.
 #[must_use = "requests must be configured and executed"]
 pub struct UpdateGuildMfa<'a> {
     fields: UpdateGuildMfaFields,
@@ -75,3 +77,42 @@ impl TryIntoRequest for UpdateGuildMfa<'_> {
         request.build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        client::Client,
+        request::{AuditLogReason, TryIntoRequest, REASON_HEADER_NAME},
+    };
+    use http::header::HeaderValue;
+    use std::error::Error;
+    use twilight_http_ratelimiting::Method;
+    use twilight_model::{
+        guild::MfaLevel,
+        id::{marker::GuildMarker, Id},
+    };
+
+    #[test]
+    fn request() -> Result<(), Box<dyn Error>> {
+        const GUILD_ID: Id<GuildMarker> = Id::new(1);
+        const REASON: &str = "security";
+
+        let client = Client::new(String::new());
+        let request = client
+            .update_guild_mfa(GUILD_ID, MfaLevel::Elevated)
+            .reason(REASON)
+            .try_into_request()?;
+
+        assert_eq!(Some(br#"{"level":1}"#.as_slice()), request.body());
+        assert_eq!(Method::Post, request.method());
+        assert_eq!("guilds/1/mfa", request.path());
+
+        let header = HeaderValue::from_static(REASON);
+        assert!(matches!(
+            request.headers(),
+            Some(map)
+            if map.len() == 1 && map.get(REASON_HEADER_NAME) == Some(&header)));
+
+        Ok(())
+    }
+}
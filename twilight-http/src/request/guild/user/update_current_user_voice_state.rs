@@ -74,14 +74,17 @@ impl<'a> UpdateCurrentUserVoiceState<'a> {
         self
     }
 
-    /// Toggle the user's suppress state.
+    /// Set whether the user is suppressed.
+    ///
+    /// Suppressed users in a stage channel are in the audience, while
+    /// unsuppressed users are speakers.
     ///
     /// # Caveats
     ///
     /// - You must have the `MUTE_MEMBERS` permission to unsuppress yourself.
     /// - You can always suppress yourself.
-    pub const fn suppress(mut self) -> Self {
-        self.fields.suppress = Some(true);
+    pub const fn suppress(mut self, suppress: bool) -> Self {
+        self.fields.suppress = Some(suppress);
 
         self
     }
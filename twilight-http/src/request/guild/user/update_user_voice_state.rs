@@ -46,7 +46,10 @@ impl<'a> UpdateUserVoiceState<'a> {
         }
     }
 
-    /// Toggle the user's suppress state.
+    /// Set whether the user is suppressed.
+    ///
+    /// Suppressed users in a stage channel are in the audience, while
+    /// unsuppressed users are speakers.
     ///
     /// # Caveats
     ///
@@ -58,8 +61,8 @@ impl<'a> UpdateUserVoiceState<'a> {
     ///   removed.
     ///
     /// [`MUTE_MEMBERS`]: twilight_model::guild::Permissions::MUTE_MEMBERS
-    pub const fn suppress(mut self) -> Self {
-        self.fields.suppress = Some(true);
+    pub const fn suppress(mut self, suppress: bool) -> Self {
+        self.fields.suppress = Some(suppress);
 
         self
     }
@@ -90,3 +93,32 @@ impl TryIntoRequest for UpdateUserVoiceState<'_> {
         .build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn request() {
+        let client = Client::new("foo".to_owned());
+        let builder =
+            UpdateUserVoiceState::new(&client, Id::new(1), Id::new(2), Id::new(3)).suppress(false);
+        let actual = builder
+            .try_into_request()
+            .expect("failed to create request");
+
+        let body = crate::json::to_vec(&UpdateUserVoiceStateFields {
+            channel_id: Id::new(3),
+            suppress: Some(false),
+        })
+        .expect("failed to serialize payload");
+        let route = Route::UpdateUserVoiceState {
+            guild_id: 1,
+            user_id: 2,
+        };
+        let expected = Request::builder(&route).body(body).build().unwrap();
+
+        assert_eq!(expected.body, actual.body);
+        assert_eq!(expected.path, actual.path);
+    }
+}
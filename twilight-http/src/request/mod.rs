@@ -58,6 +58,7 @@ mod get_gateway_authed;
 mod get_user_application;
 mod get_voice_regions;
 mod multipart;
+mod request_id;
 mod try_into_request;
 mod update_user_application;
 
@@ -70,6 +71,7 @@ pub use self::{
     get_user_application::GetUserApplicationInfo,
     get_voice_regions::GetVoiceRegions,
     multipart::Form,
+    request_id::RequestId,
     try_into_request::TryIntoRequest,
     update_user_application::UpdateCurrentUserApplication,
 };
@@ -14,6 +14,9 @@ use twilight_model::{
     },
     user::User,
 };
+use twilight_validate::request::{
+    get_answer_voters_limit as validate_get_answer_voters_limit, ValidationError,
+};
 
 #[derive(Serialize)]
 struct GetAnswerVotersFields {
@@ -27,7 +30,7 @@ struct GetAnswerVotersFields {
 /// Gets the data for a poll answer.
 #[must_use = "requests must be configured and executed"]
 pub struct GetAnswerVoters<'a> {
-    fields: GetAnswerVotersFields,
+    fields: Result<GetAnswerVotersFields, ValidationError>,
     http: &'a Client,
 }
 
@@ -44,20 +47,22 @@ impl<'a> GetAnswerVoters<'a> {
         answer_id: u8,
     ) -> Self {
         Self {
-            fields: GetAnswerVotersFields {
+            fields: Ok(GetAnswerVotersFields {
                 after: None,
                 answer_id,
                 channel_id,
                 limit: None,
                 message_id,
-            },
+            }),
             http,
         }
     }
 
     /// Set the user ID to get voters after.
     pub fn after(mut self, after: Id<UserMarker>) -> Self {
-        self.fields.after.replace(after);
+        if let Ok(fields) = self.fields.as_mut() {
+            fields.after = Some(after);
+        }
 
         self
     }
@@ -65,8 +70,20 @@ impl<'a> GetAnswerVoters<'a> {
     /// Set the limit of voters to get.
     ///
     /// The minimum is 1 and the maximum is 100.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`GetAnswerVoters`] if the limit is not
+    /// between 1 and 100.
+    ///
+    /// [`GetAnswerVoters`]: twilight_validate::request::ValidationErrorType::GetAnswerVoters
     pub fn limit(mut self, limit: u8) -> Self {
-        self.fields.limit.replace(limit);
+        self.fields = self.fields.and_then(|mut fields| {
+            validate_get_answer_voters_limit(limit)?;
+            fields.limit = Some(limit);
+
+            Ok(fields)
+        });
 
         self
     }
@@ -88,12 +105,14 @@ impl IntoFuture for GetAnswerVoters<'_> {
 
 impl TryIntoRequest for GetAnswerVoters<'_> {
     fn try_into_request(self) -> Result<Request, Error> {
+        let fields = self.fields.map_err(Error::validation)?;
+
         Ok(Request::from_route(&Route::GetAnswerVoters {
-            after: self.fields.after.map(Id::get),
-            answer_id: self.fields.answer_id,
-            channel_id: self.fields.channel_id.get(),
-            limit: self.fields.limit,
-            message_id: self.fields.message_id.get(),
+            after: fields.after.map(Id::get),
+            answer_id: fields.answer_id,
+            channel_id: fields.channel_id.get(),
+            limit: fields.limit,
+            message_id: fields.message_id.get(),
         }))
     }
 }
@@ -0,0 +1,96 @@
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+/// ID of the next request.
+static NEXT: AtomicU64 = AtomicU64::new(1);
+
+/// Unique ID of a request sent by a [`Client`], for tracing it through logs.
+///
+/// IDs are unique within the process and increase with each request. They are
+/// never sent to Discord.
+///
+/// The ID is recorded as the `id` field of the `http request` span the
+/// request's events are emitted in, and may be retrieved via
+/// [`ResponseFuture::request_id`] and [`Error::request_id`].
+///
+/// # Examples
+///
+/// Log the ID of a failed request:
+///
+/// ```no_run
+/// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::env;
+/// use twilight_http::Client;
+/// use twilight_model::id::Id;
+///
+/// let client = Client::new(env::var("DISCORD_TOKEN")?);
+///
+/// if let Err(source) = client.channel(Id::new(1)).await {
+///     tracing::warn!(
+///         request_id = ?source.request_id(),
+///         path = ?source.path(),
+///         status = ?source.status(),
+///         code = ?source.code(),
+///         "request failed: {source}",
+///     );
+/// }
+/// # Ok(()) }
+/// ```
+///
+/// [`Client`]: crate::Client
+/// [`Error::request_id`]: crate::Error::request_id
+/// [`ResponseFuture::request_id`]: crate::response::ResponseFuture::request_id
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct RequestId(u64);
+
+impl RequestId {
+    /// Create the ID of a new request.
+    pub(crate) fn next() -> Self {
+        Self(NEXT.fetch_add(1, Ordering::Relaxed))
+    }
+
+    /// Value of the ID.
+    pub const fn get(self) -> u64 {
+        self.0
+    }
+}
+
+impl Display for RequestId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&self.0, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RequestId;
+    use static_assertions::assert_impl_all;
+    use std::{
+        fmt::{Debug, Display},
+        hash::Hash,
+    };
+
+    assert_impl_all!(
+        RequestId: Clone,
+        Copy,
+        Debug,
+        Display,
+        Eq,
+        Hash,
+        Ord,
+        PartialEq,
+        Send,
+        Sync
+    );
+
+    #[test]
+    fn next() {
+        let first = RequestId::next();
+        let second = RequestId::next();
+
+        assert!(second > first);
+        assert_eq!(first.get().to_string(), first.to_string());
+    }
+}
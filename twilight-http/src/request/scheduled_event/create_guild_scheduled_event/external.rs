@@ -8,7 +8,7 @@ use crate::{
 };
 use std::future::IntoFuture;
 use twilight_model::{
-    guild::scheduled_event::{EntityType, GuildScheduledEvent},
+    guild::scheduled_event::{EntityType, GuildScheduledEvent, RecurrenceRule},
     util::Timestamp,
 };
 use twilight_validate::request::{
@@ -80,6 +80,17 @@ impl<'a> CreateGuildExternalScheduledEvent<'a> {
 
         self
     }
+
+    /// Set the rule describing how often the event repeats.
+    pub fn recurrence_rule(mut self, recurrence_rule: &'a RecurrenceRule) -> Self {
+        self.0.fields = self.0.fields.map(|mut fields| {
+            fields.recurrence_rule = Some(recurrence_rule);
+
+            fields
+        });
+
+        self
+    }
 }
 
 impl<'a> AuditLogReason<'a> for CreateGuildExternalScheduledEvent<'a> {
@@ -11,13 +11,13 @@ use super::EntityMetadataFields;
 use crate::{
     client::Client,
     error::Error,
-    request::{AuditLogReason, Request},
+    request::{self, AuditLogReason, Request},
     response::ResponseFuture,
     routing::Route,
 };
 use serde::Serialize;
 use twilight_model::{
-    guild::scheduled_event::{EntityType, GuildScheduledEvent, PrivacyLevel},
+    guild::scheduled_event::{EntityType, GuildScheduledEvent, PrivacyLevel, RecurrenceRule},
     id::{
         marker::{ChannelMarker, GuildMarker},
         Id,
@@ -46,6 +46,8 @@ struct CreateGuildScheduledEventFields<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     privacy_level: Option<PrivacyLevel>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    recurrence_rule: Option<&'a RecurrenceRule>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     scheduled_end_time: Option<&'a Timestamp>,
     #[serde(skip_serializing_if = "Option::is_none")]
     scheduled_start_time: Option<&'a Timestamp>,
@@ -139,6 +141,7 @@ impl<'a> CreateGuildScheduledEvent<'a> {
                 image: None,
                 name: None,
                 privacy_level: Some(privacy_level),
+                recurrence_rule: None,
                 scheduled_end_time: None,
                 scheduled_start_time: None,
             }),
@@ -240,12 +243,16 @@ impl<'a> CreateGuildScheduledEvent<'a> {
 
     fn try_into_request(self) -> Result<Request, Error> {
         let fields = self.fields.map_err(Error::validation)?;
-
-        Request::builder(&Route::CreateGuildScheduledEvent {
+        let mut request = Request::builder(&Route::CreateGuildScheduledEvent {
             guild_id: self.guild_id.get(),
         })
-        .json(&fields)
-        .build()
+        .json(&fields);
+
+        if let Some(reason) = self.reason.map_err(Error::validation)? {
+            request = request.headers(request::audit_header(reason)?);
+        }
+
+        request.build()
     }
 }
 
@@ -256,3 +263,59 @@ impl<'a> AuditLogReason<'a> for CreateGuildScheduledEvent<'a> {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        client::Client,
+        request::{AuditLogReason, TryIntoRequest, REASON_HEADER_NAME},
+    };
+    use http::header::HeaderValue;
+    use std::error::Error;
+    use twilight_http_ratelimiting::Method;
+    use twilight_model::{
+        guild::scheduled_event::{PrivacyLevel, RecurrenceRule, RecurrenceRuleFrequency},
+        id::Id,
+        util::Timestamp,
+    };
+
+    #[test]
+    fn request() -> Result<(), Box<dyn Error>> {
+        const REASON: &str = "garfield";
+
+        let start = Timestamp::parse("2022-01-01T14:00:00+00:00")?;
+        let recurrence_rule = RecurrenceRule {
+            by_month: None,
+            by_month_day: None,
+            by_n_weekday: None,
+            by_weekday: None,
+            by_year_day: None,
+            count: None,
+            end: None,
+            frequency: RecurrenceRuleFrequency::Daily,
+            interval: 1,
+            start,
+        };
+
+        let client = Client::new(String::new());
+        let request = client
+            .create_guild_scheduled_event(Id::new(1), PrivacyLevel::GuildOnly)
+            .voice(Id::new(2), "Garfield Appreciation Hour", &start)
+            .recurrence_rule(&recurrence_rule)
+            .reason(REASON)
+            .try_into_request()?;
+
+        assert_eq!(Method::Post, request.method());
+        let body: serde_json::Value =
+            serde_json::from_slice(request.body().ok_or("missing body")?)?;
+        assert_eq!(body["recurrence_rule"]["frequency"], 3);
+
+        let header = HeaderValue::from_static(REASON);
+        assert!(matches!(
+            request.headers(),
+            Some(map)
+            if map.len() == 1 && map.get(REASON_HEADER_NAME) == Some(&header)));
+
+        Ok(())
+    }
+}
@@ -6,7 +6,7 @@ use crate::{
 };
 use std::future::IntoFuture;
 use twilight_model::{
-    guild::scheduled_event::{EntityType, GuildScheduledEvent},
+    guild::scheduled_event::{EntityType, GuildScheduledEvent, RecurrenceRule},
     id::{marker::ChannelMarker, Id},
     util::Timestamp,
 };
@@ -75,6 +75,17 @@ impl<'a> CreateGuildStageInstanceScheduledEvent<'a> {
         self
     }
 
+    /// Set the rule describing how often the event repeats.
+    pub fn recurrence_rule(mut self, recurrence_rule: &'a RecurrenceRule) -> Self {
+        self.0.fields = self.0.fields.map(|mut fields| {
+            fields.recurrence_rule = Some(recurrence_rule);
+
+            fields
+        });
+
+        self
+    }
+
     /// Set the scheduled end time of the event.
     ///
     /// This is not a required field for stage instance events.
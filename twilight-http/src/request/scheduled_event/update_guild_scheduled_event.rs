@@ -2,14 +2,16 @@ use super::EntityMetadataFields;
 use crate::{
     client::Client,
     error::Error,
-    request::{AuditLogReason, Nullable, Request, TryIntoRequest},
+    request::{self, AuditLogReason, Nullable, Request, TryIntoRequest},
     response::{Response, ResponseFuture},
     routing::Route,
 };
 use serde::Serialize;
 use std::future::IntoFuture;
 use twilight_model::{
-    guild::scheduled_event::{EntityType, GuildScheduledEvent, PrivacyLevel, Status},
+    guild::scheduled_event::{
+        EntityType, GuildScheduledEvent, PrivacyLevel, RecurrenceRule, Status,
+    },
     id::{
         marker::{ChannelMarker, GuildMarker, ScheduledEventMarker},
         Id,
@@ -39,6 +41,8 @@ struct UpdateGuildScheduledEventFields<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     privacy_level: Option<PrivacyLevel>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    recurrence_rule: Option<Nullable<&'a RecurrenceRule>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     scheduled_end_time: Option<Nullable<&'a Timestamp>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     scheduled_start_time: Option<&'a Timestamp>,
@@ -86,6 +90,7 @@ impl<'a> UpdateGuildScheduledEvent<'a> {
                 image: None,
                 name: None,
                 privacy_level: None,
+                recurrence_rule: None,
                 scheduled_end_time: None,
                 scheduled_start_time: None,
                 status: None,
@@ -206,6 +211,19 @@ impl<'a> UpdateGuildScheduledEvent<'a> {
         self
     }
 
+    /// Set the rule describing how often the event repeats.
+    ///
+    /// Pass [`None`] to stop the event from repeating.
+    pub fn recurrence_rule(mut self, recurrence_rule: Option<&'a RecurrenceRule>) -> Self {
+        self.fields = self.fields.map(|mut fields| {
+            fields.recurrence_rule = Some(Nullable(recurrence_rule));
+
+            fields
+        });
+
+        self
+    }
+
     /// Set the scheduled end time of the event.
     ///
     /// Required for external events.
@@ -277,12 +295,52 @@ impl IntoFuture for UpdateGuildScheduledEvent<'_> {
 impl TryIntoRequest for UpdateGuildScheduledEvent<'_> {
     fn try_into_request(self) -> Result<Request, Error> {
         let fields = self.fields.map_err(Error::validation)?;
-
-        Request::builder(&Route::UpdateGuildScheduledEvent {
+        let mut request = Request::builder(&Route::UpdateGuildScheduledEvent {
             guild_id: self.guild_id.get(),
             scheduled_event_id: self.scheduled_event_id.get(),
         })
-        .json(&fields)
-        .build()
+        .json(&fields);
+
+        if let Some(reason) = self.reason.map_err(Error::validation)? {
+            request = request.headers(request::audit_header(reason)?);
+        }
+
+        request.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        client::Client,
+        request::{AuditLogReason, TryIntoRequest, REASON_HEADER_NAME},
+    };
+    use http::header::HeaderValue;
+    use std::error::Error;
+    use twilight_model::id::Id;
+
+    #[test]
+    fn request() -> Result<(), Box<dyn Error>> {
+        const REASON: &str = "garfield";
+
+        let client = Client::new(String::new());
+        let request = client
+            .update_guild_scheduled_event(Id::new(1), Id::new(2))
+            .recurrence_rule(None)
+            .reason(REASON)
+            .try_into_request()?;
+
+        assert_eq!(
+            request.body(),
+            Some(br#"{"recurrence_rule":null}"#.as_slice())
+        );
+
+        let header = HeaderValue::from_static(REASON);
+        assert!(matches!(
+            request.headers(),
+            Some(map)
+            if map.len() == 1 && map.get(REASON_HEADER_NAME) == Some(&header)));
+
+        Ok(())
     }
 }
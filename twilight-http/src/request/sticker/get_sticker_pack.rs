@@ -0,0 +1,63 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Request, TryIntoRequest},
+    response::{Response, ResponseFuture},
+    routing::Route,
+};
+use std::future::IntoFuture;
+use twilight_model::{
+    channel::message::sticker::StickerPack,
+    id::{marker::StickerPackMarker, Id},
+};
+
+/// Returns a single sticker pack by its ID.
+///
+/// # Examples
+///
+/// ```no_run
+/// use twilight_http::Client;
+/// use twilight_model::id::Id;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new("my token".to_owned());
+///
+/// let id = Id::new(123);
+/// let pack = client.sticker_pack(id).await?.model().await?;
+/// # Ok(()) }
+/// ```
+#[must_use = "requests must be configured and executed"]
+pub struct GetStickerPack<'a> {
+    http: &'a Client,
+    pack_id: Id<StickerPackMarker>,
+}
+
+impl<'a> GetStickerPack<'a> {
+    pub(crate) const fn new(http: &'a Client, pack_id: Id<StickerPackMarker>) -> Self {
+        Self { http, pack_id }
+    }
+}
+
+impl IntoFuture for GetStickerPack<'_> {
+    type Output = Result<Response<StickerPack>, Error>;
+
+    type IntoFuture = ResponseFuture<StickerPack>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        let http = self.http;
+
+        match self.try_into_request() {
+            Ok(request) => http.request(request),
+            Err(source) => ResponseFuture::error(source),
+        }
+    }
+}
+
+impl TryIntoRequest for GetStickerPack<'_> {
+    fn try_into_request(self) -> Result<Request, Error> {
+        Ok(Request::from_route(&Route::GetStickerPack {
+            pack_id: self.pack_id.get(),
+        }))
+    }
+}
@@ -19,13 +19,19 @@ mod private {
                 UpdateApplicationEmoji,
             },
             interaction::{
-                CreateFollowup, CreateResponse, DeleteFollowup, DeleteResponse, GetFollowup,
-                GetResponse, UpdateFollowup, UpdateResponse,
+                CreateFollowup, CreateResponse, CreateResponseWithResponse, DeleteFollowup,
+                DeleteResponse, GetFollowup, GetResponse, UpdateFollowup, UpdateResponse,
             },
             monetization::{
                 create_test_entitlement::CreateTestEntitlement, get_entitlements::GetEntitlements,
                 DeleteTestEntitlement, GetSKUs,
             },
+            role_connection::{
+                GetApplicationRoleConnectionMetadataRecords,
+                GetCurrentUserApplicationRoleConnection,
+                UpdateApplicationRoleConnectionMetadataRecords,
+                UpdateCurrentUserApplicationRoleConnection,
+            },
         },
         channel::{
             invite::{CreateInvite, DeleteInvite, GetChannelInvites, GetInvite},
@@ -52,7 +58,7 @@ mod private {
             },
             CreatePin, CreateTypingTrigger, DeleteChannel, DeleteChannelPermission,
             DeleteChannelPermissionConfigured, DeletePin, FollowNewsChannel, GetChannel, GetPins,
-            UpdateChannel, UpdateChannelPermission,
+            SetVoiceChannelStatus, UpdateChannel, UpdateChannelPermission,
         },
         guild::{
             auto_moderation::{
@@ -88,7 +94,7 @@ mod private {
             CreateGuildVoiceScheduledEvent, DeleteGuildScheduledEvent, GetGuildScheduledEvent,
             GetGuildScheduledEventUsers, GetGuildScheduledEvents, UpdateGuildScheduledEvent,
         },
-        sticker::{GetNitroStickerPacks, GetSticker},
+        sticker::{GetNitroStickerPacks, GetSticker, GetStickerPack},
         template::{
             CreateGuildFromTemplate, CreateTemplate, DeleteTemplate, GetTemplate, GetTemplates,
             SyncTemplate, UpdateTemplate,
@@ -136,6 +142,7 @@ mod private {
     impl Sealed for CreatePrivateChannel<'_> {}
     impl Sealed for CreateReaction<'_> {}
     impl Sealed for CreateResponse<'_> {}
+    impl Sealed for CreateResponseWithResponse<'_> {}
     impl Sealed for CreateRole<'_> {}
     impl Sealed for CreateStageInstance<'_> {}
     impl Sealed for CreateTemplate<'_> {}
@@ -240,8 +247,13 @@ mod private {
     impl Sealed for GetResponse<'_> {}
     impl Sealed for GetRole<'_> {}
     impl Sealed for GetSKUs<'_> {}
+    impl Sealed for GetApplicationRoleConnectionMetadataRecords<'_> {}
+    impl Sealed for GetCurrentUserApplicationRoleConnection<'_> {}
+    impl Sealed for UpdateApplicationRoleConnectionMetadataRecords<'_> {}
+    impl Sealed for UpdateCurrentUserApplicationRoleConnection<'_> {}
     impl Sealed for GetStageInstance<'_> {}
     impl Sealed for GetSticker<'_> {}
+    impl Sealed for GetStickerPack<'_> {}
     impl Sealed for GetTemplate<'_> {}
     impl Sealed for GetTemplates<'_> {}
     impl Sealed for GetThreadMember<'_> {}
@@ -260,6 +272,7 @@ mod private {
     impl Sealed for SearchGuildMembers<'_> {}
     impl Sealed for SetGlobalCommands<'_> {}
     impl Sealed for SetGuildCommands<'_> {}
+    impl Sealed for SetVoiceChannelStatus<'_> {}
     impl Sealed for SyncTemplate<'_> {}
     impl Sealed for UpdateAutoModerationRule<'_> {}
     impl Sealed for UpdateChannel<'_> {}
@@ -26,6 +26,11 @@ struct UpdateCurrentUserFields<'a> {
 ///
 /// All parameters are optional. If the username is changed, it may cause the discriminator to be
 /// randomized.
+///
+/// The avatar and banner are set as image Data URIs, which [`ImageData`] in
+/// the `twilight-util` crate creates from raw image bytes.
+///
+/// [`ImageData`]: https://docs.rs/twilight-util/latest/twilight_util/image/struct.ImageData.html
 #[must_use = "requests must be configured and executed"]
 pub struct UpdateCurrentUser<'a> {
     fields: Result<UpdateCurrentUserFields<'a>, ValidationError>,
@@ -1,9 +1,12 @@
 use super::{Response, StatusCode};
 use crate::{
-    api_error::ApiError,
+    api_error::{ApiError, RatelimitedApiError},
+    client::{InterceptedRequest, Interceptor},
     error::{Error, ErrorType},
+    request::RequestId,
 };
-use http::StatusCode as HyperStatusCode;
+use http::{Response as HyperResponse, StatusCode as HyperStatusCode};
+use hyper::body::Incoming;
 use hyper_util::client::legacy::ResponseFuture as HyperResponseFuture;
 use std::{
     future::Future,
@@ -15,21 +18,102 @@ use std::{
         Arc,
     },
     task::{Context, Poll},
-    time::Duration,
+    time::{Duration, Instant},
+};
+use tokio::{
+    sync::{oneshot::error::RecvError, AcquireError, OwnedSemaphorePermit, Semaphore},
+    time::{self, Sleep},
+};
+use tracing::Span;
+use twilight_http_ratelimiting::{
+    ticket::TicketSender, Path, RatelimitHeaders, WaitForTicketFuture,
 };
-use tokio::time::{self, Timeout};
-use twilight_http_ratelimiting::{ticket::TicketSender, RatelimitHeaders, WaitForTicketFuture};
 
 type Output<T> = Result<Response<T>, Error>;
 
+type AcquireFuture =
+    Pin<Box<dyn Future<Output = Result<OwnedSemaphorePermit, AcquireError>> + Send + Sync>>;
+
+type ChunkingFuture = Pin<Box<dyn Future<Output = Result<Vec<u8>, Error>> + Send + Sync + 'static>>;
+
+/// Function sending a ratelimited request again, returning the futures to
+/// wait for its ticket and response.
+///
+/// Returns [`None`] if the ratelimiter has been dropped.
+pub(crate) type Retry =
+    Box<dyn FnOnce() -> Option<(WaitForTicketFuture, HyperResponseFuture)> + Send + Sync + 'static>;
+
 enum InnerPoll<T> {
     Advance(ResponseFutureStage),
     Pending(ResponseFutureStage),
     Ready(Output<T>),
 }
 
+/// Read the body of a response.
+fn chunk(resp: HyperResponse<Incoming>) -> ChunkingFuture {
+    Box::pin(async {
+        Response::<()>::new(resp)
+            .bytes()
+            .await
+            .map_err(|source| Error {
+                kind: ErrorType::ChunkingResponse,
+                source: Some(Box::new(source)),
+            })
+    })
+}
+
+/// Create the error for an unsuccessful response from its body.
+fn response_error(bytes: Vec<u8>, status: HyperStatusCode) -> Error {
+    let error = match crate::json::from_bytes::<ApiError>(&bytes) {
+        Ok(error) => error,
+        Err(source) => {
+            return Error {
+                kind: ErrorType::Parsing { body: bytes },
+                source: Some(Box::new(source)),
+            };
+        }
+    };
+
+    Error {
+        kind: ErrorType::Response {
+            body: bytes,
+            error,
+            status: StatusCode::new(status.as_u16()),
+        },
+        source: None,
+    }
+}
+
+/// Parse the ratelimit headers of a response.
+fn ratelimit_headers(resp: &HyperResponse<Incoming>) -> Option<RatelimitHeaders> {
+    let headers = resp
+        .headers()
+        .iter()
+        .map(|(key, value)| (key.as_str(), value.as_bytes()));
+
+    match RatelimitHeaders::from_pairs(headers) {
+        Ok(headers) => Some(headers),
+        Err(source) => {
+            tracing::warn!("header parsing failed: {source:?}; {resp:?}");
+
+            None
+        }
+    }
+}
+
+/// Duration to wait before retrying a ratelimited request.
+fn retry_after(headers: Option<&RatelimitHeaders>) -> Option<Duration> {
+    match headers? {
+        RatelimitHeaders::Global(global) => Some(Duration::from_secs(global.retry_after())),
+        RatelimitHeaders::Present(present) => present.retry_after().map(Duration::from_millis),
+        _ => None,
+    }
+}
+
 struct Chunking {
-    future: Pin<Box<dyn Future<Output = Result<Vec<u8>, Error>> + Send + Sync + 'static>>,
+    future: ChunkingFuture,
+    /// Concurrency permits, released once the body is received.
+    permits: Vec<OwnedSemaphorePermit>,
     status: HyperStatusCode,
 }
 
@@ -41,24 +125,43 @@ impl Chunking {
             Poll::Pending => return InnerPoll::Pending(ResponseFutureStage::Chunking(self)),
         };
 
-        let error = match crate::json::from_bytes::<ApiError>(&bytes) {
-            Ok(error) => error,
-            Err(source) => {
+        InnerPoll::Ready(Err(response_error(bytes, self.status)))
+    }
+}
+
+struct Concurrency {
+    future: AcquireFuture,
+    next: Box<ResponseFutureStage>,
+    queued: Instant,
+}
+
+impl Concurrency {
+    fn new(semaphore: Arc<Semaphore>, next: ResponseFutureStage) -> Self {
+        Self {
+            future: Box::pin(semaphore.acquire_owned()),
+            next: Box::new(next),
+            queued: Instant::now(),
+        }
+    }
+
+    fn poll<T>(mut self, cx: &mut Context<'_>) -> InnerPoll<T> {
+        let permit = match Pin::new(&mut self.future).poll(cx) {
+            Poll::Ready(Ok(permit)) => permit,
+            Poll::Ready(Err(source)) => {
                 return InnerPoll::Ready(Err(Error {
-                    kind: ErrorType::Parsing { body: bytes },
+                    kind: ErrorType::RequestCanceled,
                     source: Some(Box::new(source)),
-                }));
+                }))
             }
+            Poll::Pending => return InnerPoll::Pending(ResponseFutureStage::Concurrency(self)),
         };
 
-        InnerPoll::Ready(Err(Error {
-            kind: ErrorType::Response {
-                body: bytes,
-                error,
-                status: StatusCode::new(self.status.as_u16()),
-            },
-            source: None,
-        }))
+        tracing::debug!(wait = ?self.queued.elapsed(), "acquired concurrency permit");
+
+        let mut next = *self.next;
+        next.add_permit(permit);
+
+        InnerPoll::Advance(next)
     }
 }
 
@@ -73,58 +176,96 @@ impl Failed {
 }
 
 struct InFlight {
-    future: Pin<Box<Timeout<HyperResponseFuture>>>,
+    /// Deadline of the request, which its retry is also bound to.
+    ///
+    /// Started when the request is first polled in flight, so that waiting
+    /// for concurrency permits doesn't count against the timeout.
+    deadline: Option<Pin<Box<Sleep>>>,
+    future: HyperResponseFuture,
+    /// Interceptor to pass the response to, along with the request.
+    interceptor: Option<(Arc<dyn Interceptor>, InterceptedRequest)>,
     invalid_token: Option<Arc<AtomicBool>>,
+    /// Concurrency permits, released once the response is received.
+    permits: Vec<OwnedSemaphorePermit>,
+    /// Function to send the request again if it's ratelimited.
+    retry: Option<Retry>,
+    started: Instant,
+    timeout: Duration,
     tx: Option<TicketSender>,
 }
 
 impl InFlight {
     fn poll<T>(mut self, cx: &mut Context<'_>) -> InnerPoll<T> {
+        let timeout = self.timeout;
+        let deadline = self
+            .deadline
+            .get_or_insert_with(|| Box::pin(time::sleep(timeout)));
+
         let resp = match Pin::new(&mut self.future).poll(cx) {
-            Poll::Ready(Ok(Ok(resp))) => resp,
-            Poll::Ready(Ok(Err(source))) => {
+            Poll::Ready(Ok(resp)) => resp,
+            Poll::Ready(Err(source)) => {
                 return InnerPoll::Ready(Err(Error {
                     kind: ErrorType::RequestError,
                     source: Some(Box::new(source)),
                 }))
             }
-            Poll::Ready(Err(source)) => {
+            Poll::Pending if deadline.as_mut().poll(cx).is_ready() => {
                 return InnerPoll::Ready(Err(Error {
                     kind: ErrorType::RequestTimedOut,
-                    source: Some(Box::new(source)),
+                    source: None,
                 }))
             }
             Poll::Pending => return InnerPoll::Pending(ResponseFutureStage::InFlight(self)),
         };
 
+        let latency = self.started.elapsed();
+        tracing::debug!(
+            ?latency,
+            status = resp.status().as_u16(),
+            "received response"
+        );
+
+        if let Some((interceptor, request)) = &self.interceptor {
+            let status = StatusCode::new(resp.status().as_u16());
+            interceptor.after_receive(request, status, resp.headers(), latency);
+        }
+
         // If the API sent back an Unauthorized response, then the client's
         // configured token is permanently invalid and future requests must be
         // ignored to avoid API bans.
         if resp.status() == HyperStatusCode::UNAUTHORIZED {
-            if let Some(invalid_token) = self.invalid_token {
+            if let Some(invalid_token) = &self.invalid_token {
                 invalid_token.store(true, Ordering::Relaxed);
             }
         }
 
-        if let Some(tx) = self.tx {
-            let headers = resp
-                .headers()
-                .iter()
-                .map(|(key, value)| (key.as_str(), value.as_bytes()));
-
-            match RatelimitHeaders::from_pairs(headers) {
-                Ok(v) => {
-                    let _res = tx.headers(Some(v));
-                }
-                Err(source) => {
-                    tracing::warn!("header parsing failed: {source:?}; {resp:?}");
+        let status = resp.status();
 
-                    let _res = tx.headers(None);
-                }
+        // Requests ratelimited despite the ratelimiter are sent again once it
+        // allows, after reporting how long to wait from the response's body.
+        if status == HyperStatusCode::TOO_MANY_REQUESTS {
+            if let (Some(retry), Some(tx)) = (self.retry.take(), self.tx.take()) {
+                tracing::debug!("429 response, retrying: {resp:?}");
+                let headers = ratelimit_headers(&resp);
+
+                return InnerPoll::Advance(ResponseFutureStage::Ratelimited(Ratelimited {
+                    deadline: self
+                        .deadline
+                        .unwrap_or_else(|| Box::pin(time::sleep(timeout))),
+                    future: chunk(resp),
+                    headers,
+                    interceptor: self.interceptor,
+                    invalid_token: self.invalid_token,
+                    permits: self.permits,
+                    retry,
+                    tx,
+                }));
             }
         }
 
-        let status = resp.status();
+        if let Some(tx) = self.tx {
+            let _res = tx.headers(ratelimit_headers(&resp));
+        }
 
         if status.is_success() {
             #[cfg(feature = "decompression")]
@@ -149,35 +290,135 @@ impl InFlight {
             _ => {}
         }
 
-        let fut = async {
-            Response::<()>::new(resp)
-                .bytes()
-                .await
-                .map_err(|source| Error {
-                    kind: ErrorType::ChunkingResponse,
-                    source: Some(Box::new(source)),
-                })
-        };
-
         InnerPoll::Advance(ResponseFutureStage::Chunking(Chunking {
-            future: Box::pin(fut),
+            future: chunk(resp),
+            permits: self.permits,
             status,
         }))
     }
 }
 
+/// Ratelimited request whose response body is read to determine when it may
+/// be retried.
+///
+/// Requests are retried at most once, within the deadline of their first
+/// attempt.
+struct Ratelimited {
+    /// Deadline of the request's first attempt.
+    deadline: Pin<Box<Sleep>>,
+    future: ChunkingFuture,
+    headers: Option<RatelimitHeaders>,
+    interceptor: Option<(Arc<dyn Interceptor>, InterceptedRequest)>,
+    invalid_token: Option<Arc<AtomicBool>>,
+    /// Concurrency permits, held until the retried request completes.
+    permits: Vec<OwnedSemaphorePermit>,
+    retry: Retry,
+    tx: TicketSender,
+}
+
+impl Ratelimited {
+    fn poll<T>(mut self, cx: &mut Context<'_>) -> InnerPoll<T> {
+        let bytes = match Pin::new(&mut self.future).poll(cx) {
+            Poll::Ready(bytes) => bytes,
+            Poll::Pending => return InnerPoll::Pending(ResponseFutureStage::Ratelimited(self)),
+        };
+
+        let mut headers = self.headers;
+
+        // The body's `retry_after` is more precise than the header's.
+        let wait = if let Some(ApiError::Ratelimited(RatelimitedApiError { retry_after, .. })) =
+            bytes
+                .as_deref()
+                .ok()
+                .and_then(|bytes| crate::json::from_bytes(bytes).ok())
+        {
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let millis = (retry_after * 1000.).ceil() as u64;
+
+            if let Some(headers) = headers.as_mut() {
+                headers.set_retry_after(millis);
+            }
+
+            Some(Duration::from_millis(millis))
+        } else {
+            retry_after(headers.as_ref())
+        };
+        let wait = wait.unwrap_or_default();
+        let _res = self.tx.headers(headers);
+
+        // Give up if the request couldn't be retried before its deadline, or
+        // if the ratelimiter is gone along with the client.
+        let retry = if time::Instant::now() + wait < self.deadline.deadline() {
+            (self.retry)()
+        } else {
+            None
+        };
+
+        let Some((wait_for_sender, response_future)) = retry else {
+            return InnerPoll::Ready(Err(match bytes {
+                Ok(bytes) => response_error(bytes, HyperStatusCode::TOO_MANY_REQUESTS),
+                Err(source) => source,
+            }));
+        };
+
+        tracing::debug!(?wait, "retrying ratelimited request");
+
+        InnerPoll::Advance(ResponseFutureStage::RatelimitQueue(RatelimitQueue {
+            deadline: Some(self.deadline),
+            global: None,
+            interceptor: self.interceptor,
+            invalid_token: self.invalid_token,
+            permits: self.permits,
+            response_future,
+            retry: None,
+            timeout: Duration::ZERO,
+            pre_flight_check: None,
+            queued: Instant::now(),
+            wait_for_sender,
+        }))
+    }
+}
+
 struct RatelimitQueue {
+    /// Deadline of the request's previous attempt, which a retry is bound to
+    /// instead of its own timeout.
+    deadline: Option<Pin<Box<Sleep>>>,
+    /// Semaphore to acquire a permit from after clearing the queue.
+    global: Option<Arc<Semaphore>>,
+    /// Interceptor to hand to the request once it's sent.
+    interceptor: Option<(Arc<dyn Interceptor>, InterceptedRequest)>,
     invalid_token: Option<Arc<AtomicBool>>,
+    /// Concurrency permits, held while waiting in the queue.
+    permits: Vec<OwnedSemaphorePermit>,
     response_future: HyperResponseFuture,
+    /// Function to send the request again if it's ratelimited.
+    retry: Option<Retry>,
     timeout: Duration,
     pre_flight_check: Option<Box<dyn FnOnce() -> bool + Send + 'static>>,
+    queued: Instant,
     wait_for_sender: WaitForTicketFuture,
 }
 
 impl RatelimitQueue {
     fn poll<T>(mut self, cx: &mut Context<'_>) -> InnerPoll<T> {
+        if let Some(deadline) = self.deadline.as_mut() {
+            if deadline.as_mut().poll(cx).is_ready() {
+                return InnerPoll::Ready(Err(Error {
+                    kind: ErrorType::RequestTimedOut,
+                    source: None,
+                }));
+            }
+        }
+
         let tx = match Pin::new(&mut self.wait_for_sender).poll(cx) {
             Poll::Ready(Ok(tx)) => tx,
+            // The ratelimiter dropped the ticket, such as when shutting down.
+            Poll::Ready(Err(source)) if source.is::<RecvError>() => {
+                return InnerPoll::Ready(Err(Error {
+                    kind: ErrorType::ClientDropped,
+                    source: Some(source),
+                }))
+            }
             Poll::Ready(Err(source)) => {
                 return InnerPoll::Ready(Err(Error {
                     kind: ErrorType::RatelimiterTicket,
@@ -187,6 +428,8 @@ impl RatelimitQueue {
             Poll::Pending => return InnerPoll::Pending(ResponseFutureStage::RatelimitQueue(self)),
         };
 
+        tracing::debug!(wait = ?self.queued.elapsed(), "cleared ratelimit queue");
+
         if let Some(pre_flight_check) = self.pre_flight_check {
             if !pre_flight_check() {
                 return InnerPoll::Ready(Err(Error {
@@ -196,22 +439,62 @@ impl RatelimitQueue {
             }
         }
 
-        InnerPoll::Advance(ResponseFutureStage::InFlight(InFlight {
-            future: Box::pin(time::timeout(self.timeout, self.response_future)),
+        let in_flight = ResponseFutureStage::InFlight(InFlight {
+            deadline: self.deadline,
+            future: self.response_future,
+            interceptor: self.interceptor,
             invalid_token: self.invalid_token,
+            permits: self.permits,
+            retry: self.retry,
+            started: Instant::now(),
+            timeout: self.timeout,
             tx: Some(tx),
-        }))
+        });
+
+        // Only acquire a global permit once the request is ready to be sent,
+        // so that requests waiting on buckets don't block requests that
+        // aren't.
+        InnerPoll::Advance(match self.global {
+            Some(global) => ResponseFutureStage::Concurrency(Concurrency::new(global, in_flight)),
+            None => in_flight,
+        })
     }
 }
 
 enum ResponseFutureStage {
     Chunking(Chunking),
     Completed,
+    Concurrency(Concurrency),
     Failed(Failed),
     InFlight(InFlight),
+    Ratelimited(Ratelimited),
     RatelimitQueue(RatelimitQueue),
 }
 
+impl ResponseFutureStage {
+    /// Hold a concurrency permit until the stages after this one complete.
+    fn add_permit(&mut self, permit: OwnedSemaphorePermit) {
+        match self {
+            Self::Chunking(chunking) => chunking.permits.push(permit),
+            Self::Concurrency(concurrency) => concurrency.next.add_permit(permit),
+            Self::InFlight(in_flight) => in_flight.permits.push(permit),
+            Self::Ratelimited(ratelimited) => ratelimited.permits.push(permit),
+            Self::RatelimitQueue(queue) => queue.permits.push(permit),
+            Self::Completed | Self::Failed(_) => {}
+        }
+    }
+
+    /// Mutable reference to the ratelimit queue stage, if the request has not
+    /// cleared it yet.
+    fn ratelimit_queue(&mut self) -> Option<&mut RatelimitQueue> {
+        match self {
+            Self::Concurrency(concurrency) => concurrency.next.ratelimit_queue(),
+            Self::RatelimitQueue(queue) => Some(queue),
+            _ => None,
+        }
+    }
+}
+
 /// Future that will resolve to a [`Response`].
 ///
 /// # Canceling a response future pre-flight
@@ -222,6 +505,16 @@ enum ResponseFutureStage {
 /// which may be necessary in scenarios where requests are being spammed. Refer
 /// to its documentation for more information.
 ///
+/// # Ratelimited requests
+///
+/// Requests that are ratelimited despite the [ratelimiter], such as by
+/// ratelimits on shared resources, are sent again once the ratelimiter allows.
+/// The `retry_after` field of the response's body is reported to the
+/// ratelimiter to determine how long to wait. Requests are only retried once,
+/// and only if the retry can be sent before the request times out; the timeout
+/// covers both attempts. Otherwise, or if there is no ratelimiter configured,
+/// the response is returned as an [`ErrorType::Response`] error type.
+///
 /// # Errors
 ///
 /// Returns an [`ErrorType::Json`] error type if serializing the response body
@@ -237,7 +530,8 @@ enum ResponseFutureStage {
 /// failed.
 ///
 /// Returns an [`ErrorType::RequestTimedOut`] error type if the request timed
-/// out. The timeout value is configured via [`ClientBuilder::timeout`].
+/// out. The timeout value is configured via [`ClientBuilder::timeout`] or
+/// per request via [`RequestBuilder::timeout`].
 ///
 /// Returns an [`ErrorType::Response`] error type if the request failed.
 ///
@@ -245,6 +539,8 @@ enum ResponseFutureStage {
 /// is unavailable.
 ///
 /// [`ClientBuilder::timeout`]: crate::client::ClientBuilder::timeout
+/// [ratelimiter]: crate::client::ClientBuilder::ratelimiter
+/// [`RequestBuilder::timeout`]: crate::request::RequestBuilder::timeout
 /// [`ErrorType::Json`]: crate::error::ErrorType::Json
 /// [`ErrorType::Parsing`]: crate::error::ErrorType::Parsing
 /// [`ErrorType::RequestCanceled`]: crate::error::ErrorType::RequestCanceled
@@ -255,20 +551,38 @@ enum ResponseFutureStage {
 /// [`Response`]: super::Response
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct ResponseFuture<T> {
+    /// Deadline for the whole request, including time spent in the
+    /// ratelimiter queue.
+    deadline: Option<Pin<Box<Sleep>>>,
     phantom: PhantomData<T>,
+    /// ID and ratelimit path of the request, attached to its errors.
+    request: Option<(RequestId, Path)>,
+    /// Span entered while polling, carrying the method and ratelimit path of
+    /// the request.
+    span: Span,
     stage: ResponseFutureStage,
 }
 
 impl<T> ResponseFuture<T> {
-    pub(crate) const fn new(
-        future: Pin<Box<Timeout<HyperResponseFuture>>>,
+    pub(crate) fn new(
+        future: HyperResponseFuture,
+        timeout: Duration,
         invalid_token: Option<Arc<AtomicBool>>,
     ) -> Self {
         Self {
+            deadline: None,
             phantom: PhantomData,
+            request: None,
+            span: Span::none(),
             stage: ResponseFutureStage::InFlight(InFlight {
+                deadline: None,
                 future,
+                interceptor: None,
                 invalid_token,
+                permits: Vec::new(),
+                retry: None,
+                started: Instant::now(),
+                timeout,
                 tx: None,
             }),
         }
@@ -331,7 +645,7 @@ impl<T> ResponseFuture<T> {
         &mut self,
         pre_flight: Box<dyn FnOnce() -> bool + Send + 'static>,
     ) -> bool {
-        if let ResponseFutureStage::RatelimitQueue(queue) = &mut self.stage {
+        if let Some(queue) = self.stage.ratelimit_queue() {
             queue.pre_flight_check = Some(pre_flight);
 
             true
@@ -342,7 +656,10 @@ impl<T> ResponseFuture<T> {
 
     pub(crate) const fn error(source: Error) -> Self {
         Self {
+            deadline: None,
             phantom: PhantomData,
+            request: None,
+            span: Span::none(),
             stage: ResponseFutureStage::Failed(Failed { source }),
         }
     }
@@ -354,30 +671,143 @@ impl<T> ResponseFuture<T> {
         wait_for_sender: WaitForTicketFuture,
     ) -> Self {
         Self {
+            deadline: None,
             phantom: PhantomData,
+            request: None,
+            span: Span::none(),
             stage: ResponseFutureStage::RatelimitQueue(RatelimitQueue {
+                deadline: None,
+                global: None,
+                interceptor: None,
                 invalid_token,
+                permits: Vec::new(),
                 response_future,
+                retry: None,
                 timeout,
                 pre_flight_check: None,
+                queued: Instant::now(),
                 wait_for_sender,
             }),
         }
     }
+
+    /// Set the interceptor to pass the response to once it's received.
+    pub(crate) fn set_interceptor(
+        &mut self,
+        interceptor: Arc<dyn Interceptor>,
+        request: InterceptedRequest,
+    ) {
+        let intercepted = Some((interceptor, request));
+
+        match &mut self.stage {
+            ResponseFutureStage::InFlight(in_flight) => in_flight.interceptor = intercepted,
+            ResponseFutureStage::RatelimitQueue(queue) => queue.interceptor = intercepted,
+            _ => {}
+        }
+    }
+
+    /// Set the function to send the request again if it's ratelimited despite
+    /// the ratelimiter.
+    ///
+    /// This is a no-op if there is no ratelimiter configured.
+    pub(crate) fn set_retry(&mut self, retry: Retry) {
+        if let ResponseFutureStage::RatelimitQueue(queue) = &mut self.stage {
+            queue.retry = Some(retry);
+        }
+    }
+
+    /// Set a deadline after which the request fails with an
+    /// [`ErrorType::RequestTimedOut`] error type, regardless of its stage.
+    pub(crate) fn set_deadline(&mut self, timeout: Duration) {
+        self.deadline = Some(Box::pin(time::sleep(timeout)));
+    }
+
+    /// Limit the concurrency of the request.
+    ///
+    /// A permit from the path semaphore is held from now on, and a permit from
+    /// the global semaphore from when the request is about to be sent. Both
+    /// are released once the response is received.
+    pub(crate) fn set_concurrency(
+        &mut self,
+        path: Option<Arc<Semaphore>>,
+        global: Option<Arc<Semaphore>>,
+    ) {
+        let mut stage = mem::replace(&mut self.stage, ResponseFutureStage::Completed);
+
+        if let Some(global) = global {
+            if let ResponseFutureStage::RatelimitQueue(queue) = &mut stage {
+                queue.global = Some(global);
+            } else {
+                stage = ResponseFutureStage::Concurrency(Concurrency::new(global, stage));
+            }
+        }
+
+        if let Some(path) = path {
+            stage = ResponseFutureStage::Concurrency(Concurrency::new(path, stage));
+        }
+
+        self.stage = stage;
+    }
+
+    /// ID of the request, for correlating it with its logs and errors.
+    ///
+    /// Returns [`None`] if the request failed before being sent to the
+    /// client, such as when its fields failed validation.
+    pub const fn request_id(&self) -> Option<RequestId> {
+        match &self.request {
+            Some((id, _)) => Some(*id),
+            None => None,
+        }
+    }
+
+    /// Set the ID and ratelimit path of the request to attach to errors.
+    pub(crate) fn set_request(&mut self, id: RequestId, path: Path) {
+        self.request = Some((id, path));
+    }
+
+    /// Set the span to enter while polling.
+    pub(crate) fn set_span(&mut self, span: Span) {
+        self.span = span;
+    }
+
+    /// Attach the ID and ratelimit path of the request to an error.
+    fn with_request(&self, error: Error) -> Error {
+        match &self.request {
+            Some((id, path)) => error.with_request(*id, path.clone()),
+            None => error,
+        }
+    }
 }
 
 impl<T: Unpin> Future for ResponseFuture<T> {
     type Output = Output<T>;
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let span = self.span.clone();
+        let _entered = span.enter();
+
+        if let Some(deadline) = self.deadline.as_mut() {
+            if deadline.as_mut().poll(cx).is_ready() {
+                self.deadline = None;
+                self.stage = ResponseFutureStage::Completed;
+
+                return Poll::Ready(Err(self.with_request(Error {
+                    kind: ErrorType::RequestTimedOut,
+                    source: None,
+                })));
+            }
+        }
+
         loop {
             let stage = mem::replace(&mut self.stage, ResponseFutureStage::Completed);
 
             let result = match stage {
                 ResponseFutureStage::Chunking(chunking) => chunking.poll(cx),
                 ResponseFutureStage::Completed => panic!("future already completed"),
+                ResponseFutureStage::Concurrency(concurrency) => concurrency.poll(cx),
                 ResponseFutureStage::Failed(failed) => failed.poll(cx),
                 ResponseFutureStage::InFlight(in_flight) => in_flight.poll(cx),
+                ResponseFutureStage::Ratelimited(ratelimited) => ratelimited.poll(cx),
                 ResponseFutureStage::RatelimitQueue(queue) => queue.poll(cx),
             };
 
@@ -393,9 +823,19 @@ impl<T: Unpin> Future for ResponseFuture<T> {
                 InnerPoll::Ready(output) => {
                     self.stage = ResponseFutureStage::Completed;
 
-                    return Poll::Ready(output);
+                    return Poll::Ready(output.map_err(|source| self.with_request(source)));
                 }
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ResponseFuture;
+    use static_assertions::assert_impl_all;
+    use std::future::Future;
+    use twilight_model::channel::Message;
+
+    assert_impl_all!(ResponseFuture<Message>: Future, Send);
+}
@@ -69,6 +69,7 @@ use std::{
     pin::Pin,
     task::{Context, Poll},
 };
+use twilight_http_ratelimiting::{headers::HeaderParsingError, RatelimitHeaders};
 
 /// Failure when processing a response body.
 #[derive(Debug)]
@@ -193,6 +194,28 @@ impl<T> Response<T> {
         HeaderIter(self.inner.headers().iter())
     }
 
+    /// Parse the ratelimit headers of the response.
+    ///
+    /// This is useful when the client's [ratelimiter] is disabled, such as
+    /// when requests are sent through a central ratelimiting proxy, to
+    /// schedule further requests based on the remaining ratelimits.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`HeaderParsingError`] if a ratelimit header is missing or
+    /// invalid.
+    ///
+    /// [ratelimiter]: crate::client::ClientBuilder::ratelimiter
+    pub fn ratelimit_headers(&self) -> Result<RatelimitHeaders, HeaderParsingError> {
+        let headers = self
+            .inner
+            .headers()
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_bytes()));
+
+        RatelimitHeaders::from_pairs(headers)
+    }
+
     /// Status code of the response.
     #[must_use = "retrieving the status code has no use on its own"]
     pub fn status(&self) -> StatusCode {
@@ -300,6 +323,41 @@ impl<T> Response<T> {
     pub fn text(self) -> TextFuture {
         TextFuture(self.bytes())
     }
+
+    /// Convert the response into a response with a different body type.
+    ///
+    /// This allows deserializing the body into a caller-provided type via
+    /// [`model`], such as one capturing fields the library's models don't
+    /// have.
+    ///
+    /// # Examples
+    ///
+    /// Deserialize the current user into a custom type:
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use serde::Deserialize;
+    /// use std::env;
+    /// use twilight_http::Client;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct PartialUser {
+    ///     username: String,
+    /// }
+    ///
+    /// let client = Client::new(env::var("DISCORD_TOKEN")?);
+    /// let response = client.current_user().await?;
+    /// let user = response.cast::<PartialUser>().model().await?;
+    ///
+    /// println!("username: {}", user.username);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`model`]: Response::model
+    pub fn cast<U>(self) -> Response<U> {
+        Response::new(self.inner)
+    }
 }
 
 impl<T: DeserializeOwned> Response<T> {
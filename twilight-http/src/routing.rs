@@ -404,6 +404,12 @@ pub enum Route<'a> {
         /// The ID of the application.
         application_id: u64,
     },
+    /// Route information to get an application's role connection metadata
+    /// records.
+    GetApplicationRoleConnectionMetadataRecords {
+        /// The ID of the application.
+        application_id: u64,
+    },
     /// Route information for fetching poll vote information.
     GetAnswerVoters {
         /// Get users after this user ID.
@@ -497,6 +503,12 @@ pub enum Route<'a> {
     GetCurrentUser,
     /// Route information to get info about application the current bot user belongs to
     GetCurrentUserApplicationInfo,
+    /// Route information to get the current user's role connection for an
+    /// application.
+    GetCurrentUserApplicationRoleConnection {
+        /// The ID of the application.
+        application_id: u64,
+    },
     /// Route information to get the current user as a member object within a guild.
     GetCurrentUserGuildMember {
         /// ID of the guild.
@@ -851,6 +863,11 @@ pub enum Route<'a> {
         /// ID of the sticker.
         sticker_id: u64,
     },
+    /// Route information to get a sticker pack.
+    GetStickerPack {
+        /// ID of the sticker pack.
+        pack_id: u64,
+    },
     /// Route information to get a template.
     GetTemplate {
         /// The template code.
@@ -867,6 +884,8 @@ pub enum Route<'a> {
         channel_id: u64,
         /// ID of the member.
         user_id: u64,
+        /// Whether to include the guild member of the thread member.
+        with_member: Option<bool>,
     },
     /// Route information to get members of a thread.
     GetThreadMembers {
@@ -918,6 +937,14 @@ pub enum Route<'a> {
         /// The token for the interaction.
         interaction_token: &'a str,
     },
+    /// Route information to respond to an interaction and return the created
+    /// interaction callback.
+    InteractionCallbackWithResponse {
+        /// The ID of the interaction.
+        interaction_id: u64,
+        /// The token for the interaction.
+        interaction_token: &'a str,
+    },
     /// Route information to join a thread as the current user.
     JoinThread {
         /// ID of the thread.
@@ -984,6 +1011,11 @@ pub enum Route<'a> {
         /// The ID of the guild.
         guild_id: u64,
     },
+    /// Route information to set the status of a voice channel.
+    SetVoiceChannelStatus {
+        /// The ID of the channel.
+        channel_id: u64,
+    },
     /// Route information to sync a guild's integration.
     SyncGuildIntegration {
         /// The ID of the guild.
@@ -1006,6 +1038,12 @@ pub enum Route<'a> {
         message_id: u64,
     },
     /// Route information to update an auto moderation rule for a guild.
+    /// Route information to update an application's role connection metadata
+    /// records.
+    UpdateApplicationRoleConnectionMetadataRecords {
+        /// The ID of the application.
+        application_id: u64,
+    },
     UpdateAutoModerationRule {
         /// ID of the auto moderation rule.
         auto_moderation_rule_id: u64,
@@ -1033,6 +1071,12 @@ pub enum Route<'a> {
     },
     /// Route information to update the current user.
     UpdateCurrentUser,
+    /// Route information to update the current user's role connection for an
+    /// application.
+    UpdateCurrentUserApplicationRoleConnection {
+        /// The ID of the application.
+        application_id: u64,
+    },
     /// Route information to update the current user's voice state.
     UpdateCurrentUserVoiceState {
         /// ID of the guild.
@@ -1249,6 +1293,7 @@ impl Route<'_> {
             | Self::UnpinMessage { .. } => Method::Delete,
             Self::GetActiveThreads { .. }
             | Self::GetApplicationEmojis { .. }
+            | Self::GetApplicationRoleConnectionMetadataRecords { .. }
             | Self::GetAnswerVoters { .. }
             | Self::GetAuditLogs { .. }
             | Self::GetAutoModerationRule { .. }
@@ -1263,6 +1308,7 @@ impl Route<'_> {
             | Self::GetCommandPermissions { .. }
             | Self::GetCurrentAuthorizationInformation
             | Self::GetCurrentUserApplicationInfo
+            | Self::GetCurrentUserApplicationRoleConnection { .. }
             | Self::GetCurrentUser
             | Self::GetCurrentUserGuildMember { .. }
             | Self::GetEmoji { .. }
@@ -1312,6 +1358,7 @@ impl Route<'_> {
             | Self::GetSKUs { .. }
             | Self::GetStageInstance { .. }
             | Self::GetSticker { .. }
+            | Self::GetStickerPack { .. }
             | Self::GetTemplate { .. }
             | Self::GetTemplates { .. }
             | Self::GetThreadMember { .. }
@@ -1333,7 +1380,6 @@ impl Route<'_> {
             | Self::UpdateGuild { .. }
             | Self::UpdateGuildChannels { .. }
             | Self::UpdateGuildCommand { .. }
-            | Self::UpdateGuildMfa { .. }
             | Self::UpdateGuildWidgetSettings { .. }
             | Self::UpdateGuildIntegration { .. }
             | Self::UpdateGuildScheduledEvent { .. }
@@ -1382,7 +1428,9 @@ impl Route<'_> {
             | Self::ExecuteWebhook { .. }
             | Self::FollowNewsChannel { .. }
             | Self::InteractionCallback { .. }
-            | Self::SyncGuildIntegration { .. } => Method::Post,
+            | Self::InteractionCallbackWithResponse { .. }
+            | Self::SyncGuildIntegration { .. }
+            | Self::UpdateGuildMfa { .. } => Method::Post,
             Self::AddGuildMember { .. }
             | Self::AddMemberRole { .. }
             | Self::AddThreadMember { .. }
@@ -1392,8 +1440,11 @@ impl Route<'_> {
             | Self::PinMessage { .. }
             | Self::SetGlobalCommands { .. }
             | Self::SetGuildCommands { .. }
+            | Self::SetVoiceChannelStatus { .. }
             | Self::SyncTemplate { .. }
+            | Self::UpdateApplicationRoleConnectionMetadataRecords { .. }
             | Self::UpdateCommandPermissions { .. }
+            | Self::UpdateCurrentUserApplicationRoleConnection { .. }
             | Self::UpdateGuildOnboarding { .. }
             | Self::UpdatePermissionOverwrite { .. } => Method::Put,
         }
@@ -1637,6 +1688,10 @@ impl Route<'_> {
             | Self::DeleteApplicationEmoji { application_id, .. } => {
                 Path::ApplicationEmojis(application_id)
             }
+            Self::GetApplicationRoleConnectionMetadataRecords { application_id }
+            | Self::UpdateApplicationRoleConnectionMetadataRecords { application_id } => {
+                Path::ApplicationIdRoleConnectionsMetadata(application_id)
+            }
             Self::GetAuditLogs { guild_id, .. } => Path::GuildsIdAuditLogs(guild_id),
             Self::GetBan { guild_id, .. } => Path::GuildsIdBansId(guild_id),
             Self::GetBans { guild_id } | Self::GetBansWithParameters { guild_id, .. } => {
@@ -1657,6 +1712,10 @@ impl Route<'_> {
             Self::GetCurrentUserApplicationInfo | Self::UpdateCurrentUserApplication => {
                 Path::ApplicationsMe
             }
+            Self::GetCurrentUserApplicationRoleConnection { .. }
+            | Self::UpdateCurrentUserApplicationRoleConnection { .. } => {
+                Path::UsersIdApplicationsIdRoleConnection
+            }
             Self::GetCurrentUser | Self::GetUser { .. } | Self::UpdateCurrentUser => Path::UsersId,
             Self::GetCurrentUserGuildMember { .. } => Path::UsersIdGuildsIdMember,
             Self::GetEmoji { guild_id, .. } | Self::UpdateEmoji { guild_id, .. } => {
@@ -1699,7 +1758,7 @@ impl Route<'_> {
             Self::GetMessage { channel_id, .. } => {
                 Path::ChannelsIdMessagesId(Method::Get, channel_id)
             }
-            Self::GetNitroStickerPacks { .. } => Path::StickerPacks,
+            Self::GetNitroStickerPacks { .. } | Self::GetStickerPack { .. } => Path::StickerPacks,
             Self::GetPins { channel_id } | Self::PinMessage { channel_id, .. } => {
                 Path::ChannelsIdPins(channel_id)
             }
@@ -1707,11 +1766,13 @@ impl Route<'_> {
             Self::GetSticker { .. } => Path::Stickers,
             Self::GetUserConnections => Path::UsersIdConnections,
             Self::GetVoiceRegions => Path::VoiceRegions,
-            Self::InteractionCallback { interaction_id, .. } => {
+            Self::InteractionCallback { interaction_id, .. }
+            | Self::InteractionCallbackWithResponse { interaction_id, .. } => {
                 Path::InteractionCallback(interaction_id)
             }
             Self::LeaveGuild { .. } => Path::UsersIdGuildsId,
             Self::SearchGuildMembers { guild_id, .. } => Path::GuildsIdMembersSearch(guild_id),
+            Self::SetVoiceChannelStatus { channel_id } => Path::ChannelsIdVoiceStatus(channel_id),
             Self::SyncGuildIntegration { guild_id, .. } => {
                 Path::GuildsIdIntegrationsIdSync(guild_id)
             }
@@ -1801,10 +1862,6 @@ impl Display for Route<'_> {
                 channel_id,
                 user_id,
             }
-            | Route::GetThreadMember {
-                channel_id,
-                user_id,
-            }
             | Route::RemoveThreadMember {
                 channel_id,
                 user_id,
@@ -2468,6 +2525,13 @@ impl Display for Route<'_> {
 
                 f.write_str("/emojis")
             }
+            Route::GetApplicationRoleConnectionMetadataRecords { application_id }
+            | Route::UpdateApplicationRoleConnectionMetadataRecords { application_id } => {
+                f.write_str("applications/")?;
+                Display::fmt(application_id, f)?;
+
+                f.write_str("/role-connections/metadata")
+            }
             Route::GetAuditLogs {
                 action_type,
                 after,
@@ -2535,6 +2599,13 @@ impl Display for Route<'_> {
                 f.write_str("applications/@me")
             }
             Route::GetCurrentUser | Route::UpdateCurrentUser => f.write_str("users/@me"),
+            Route::GetCurrentUserApplicationRoleConnection { application_id }
+            | Route::UpdateCurrentUserApplicationRoleConnection { application_id } => {
+                f.write_str("users/@me/applications/")?;
+                Display::fmt(application_id, f)?;
+
+                f.write_str("/role-connection")
+            }
             Route::GetCurrentUserGuildMember { guild_id } => {
                 f.write_str("users/@me/guilds/")?;
                 Display::fmt(guild_id, f)?;
@@ -2876,6 +2947,23 @@ impl Display for Route<'_> {
 
                 Display::fmt(sticker_id, f)
             }
+            Route::GetStickerPack { pack_id } => {
+                f.write_str("sticker-packs/")?;
+
+                Display::fmt(pack_id, f)
+            }
+            Route::GetThreadMember {
+                channel_id,
+                user_id,
+                with_member,
+            } => {
+                f.write_str("channels/")?;
+                Display::fmt(channel_id, f)?;
+                f.write_str("/thread-members/")?;
+                Display::fmt(user_id, f)?;
+
+                QueryStringFormatter::new(f).write_opt_param("with_member", with_member.as_ref())
+            }
             Route::GetThreadMembers {
                 after,
                 channel_id,
@@ -2910,6 +2998,17 @@ impl Display for Route<'_> {
 
                 f.write_str("/callback")
             }
+            Route::InteractionCallbackWithResponse {
+                interaction_id,
+                interaction_token,
+            } => {
+                f.write_str("interactions/")?;
+                Display::fmt(interaction_id, f)?;
+                f.write_str("/")?;
+                f.write_str(interaction_token)?;
+
+                f.write_str("/callback?with_response=true")
+            }
             Route::JoinThread { channel_id } | Route::LeaveThread { channel_id } => {
                 f.write_str("channels/")?;
                 Display::fmt(channel_id, f)?;
@@ -2950,6 +3049,12 @@ impl Display for Route<'_> {
                     .write_param("query", &utf8_percent_encode(query, NON_ALPHANUMERIC))?;
                 query_formatter.write_opt_param("limit", limit.as_ref())
             }
+            Route::SetVoiceChannelStatus { channel_id } => {
+                f.write_str("channels/")?;
+                Display::fmt(channel_id, f)?;
+
+                f.write_str("/voice-status")
+            }
             Route::SyncGuildIntegration {
                 guild_id,
                 integration_id,
@@ -3077,6 +3182,7 @@ mod tests {
     const USER_ID: u64 = 11;
     const SCHEDULED_EVENT_ID: u64 = 12;
     const AUTO_MODERATION_RULE_ID: u64 = 13;
+    const STICKER_PACK_ID: u64 = 14;
 
     const fn emoji() -> RequestReactionType<'static> {
         RequestReactionType::Custom {
@@ -3202,11 +3308,22 @@ mod tests {
         let route = Route::GetThreadMember {
             channel_id: CHANNEL_ID,
             user_id: USER_ID,
+            with_member: None,
         };
         assert_eq!(
             route.to_string(),
             format!("channels/{CHANNEL_ID}/thread-members/{USER_ID}")
         );
+
+        let route = Route::GetThreadMember {
+            channel_id: CHANNEL_ID,
+            user_id: USER_ID,
+            with_member: Some(true),
+        };
+        assert_eq!(
+            route.to_string(),
+            format!("channels/{CHANNEL_ID}/thread-members/{USER_ID}?with_member=true")
+        );
     }
 
     #[test]
@@ -3349,6 +3466,17 @@ mod tests {
         );
     }
 
+    #[test]
+    fn set_voice_channel_status() {
+        let route = Route::SetVoiceChannelStatus {
+            channel_id: CHANNEL_ID,
+        };
+        assert_eq!(
+            route.to_string(),
+            format!("channels/{CHANNEL_ID}/voice-status")
+        );
+    }
+
     #[test]
     fn create_guild_from_template() {
         let route = Route::CreateGuildFromTemplate {
@@ -4147,6 +4275,28 @@ mod tests {
         assert_eq!(route.to_string(), "applications/@me");
     }
 
+    #[test]
+    fn get_application_role_connection_metadata_records() {
+        let route = Route::GetApplicationRoleConnectionMetadataRecords {
+            application_id: APPLICATION_ID,
+        };
+        assert_eq!(
+            route.to_string(),
+            format!("applications/{APPLICATION_ID}/role-connections/metadata")
+        );
+    }
+
+    #[test]
+    fn get_current_user_application_role_connection() {
+        let route = Route::GetCurrentUserApplicationRoleConnection {
+            application_id: APPLICATION_ID,
+        };
+        assert_eq!(
+            route.to_string(),
+            format!("users/@me/applications/{APPLICATION_ID}/role-connection")
+        );
+    }
+
     #[test]
     fn update_current_user_application() {
         let route = Route::UpdateCurrentUserApplication;
@@ -4317,6 +4467,17 @@ mod tests {
         assert_eq!(route.to_string(), format!("stickers/{STICKER_ID}"));
     }
 
+    #[test]
+    fn get_sticker_pack() {
+        let route = Route::GetStickerPack {
+            pack_id: STICKER_PACK_ID,
+        };
+        assert_eq!(
+            route.to_string(),
+            format!("sticker-packs/{STICKER_PACK_ID}")
+        );
+    }
+
     #[test]
     fn get_thread_members() {
         let route = Route::GetThreadMembers {
@@ -4375,6 +4536,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn interaction_callback_with_response() {
+        let route = Route::InteractionCallbackWithResponse {
+            interaction_id: INTERACTION_ID,
+            interaction_token: INTERACTION_TOKEN,
+        };
+        assert_eq!(
+            route.to_string(),
+            format!(
+                "interactions/{INTERACTION_ID}/{INTERACTION_TOKEN}/callback?with_response=true"
+            )
+        );
+    }
+
     #[test]
     fn join_thread() {
         let route = Route::JoinThread {
@@ -4758,6 +4933,7 @@ mod tests {
     fn update_guild_mfa() {
         let route = Route::UpdateGuildMfa { guild_id: GUILD_ID };
         assert_eq!(route.to_string(), format!("guilds/{GUILD_ID}/mfa"));
+        assert_eq!(route.method(), Method::Post);
     }
 
     #[test]
@@ -45,3 +45,26 @@ pub struct CommandData {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub target_id: Option<Id<GenericMarker>>,
 }
+
+impl CommandData {
+    /// Option that the user is currently typing in, if any.
+    ///
+    /// Only [`ApplicationCommandAutocomplete`] interactions have a focused
+    /// option. Options of subcommands and subcommand groups are searched.
+    ///
+    /// [`ApplicationCommandAutocomplete`]: crate::application::interaction::InteractionType::ApplicationCommandAutocomplete
+    pub fn focused(&self) -> Option<&CommandDataOption> {
+        focused(&self.options)
+    }
+}
+
+/// Find the focused option in a list of options.
+fn focused(options: &[CommandDataOption]) -> Option<&CommandDataOption> {
+    options.iter().find_map(|option| match &option.value {
+        CommandOptionValue::Focused(_, _) => Some(option),
+        CommandOptionValue::SubCommand(options) | CommandOptionValue::SubCommandGroup(options) => {
+            focused(options)
+        }
+        _ => None,
+    })
+}
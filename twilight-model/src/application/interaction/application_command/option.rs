@@ -478,6 +478,39 @@ mod tests {
         )
     }
 
+    #[test]
+    fn focused() {
+        let focused = CommandDataOption {
+            name: "dog".to_owned(),
+            value: CommandOptionValue::Focused("Shiba".to_owned(), CommandOptionType::String),
+        };
+        let mut value = CommandData {
+            guild_id: None,
+            id: Id::new(1),
+            name: "pets".to_owned(),
+            kind: CommandType::ChatInput,
+            options: Vec::from([CommandDataOption {
+                name: "group".to_owned(),
+                value: CommandOptionValue::SubCommandGroup(Vec::from([CommandDataOption {
+                    name: "adopt".to_owned(),
+                    value: CommandOptionValue::SubCommand(Vec::from([
+                        CommandDataOption {
+                            name: "cat".to_owned(),
+                            value: CommandOptionValue::Integer(42),
+                        },
+                        focused.clone(),
+                    ])),
+                }])),
+            }]),
+            resolved: None,
+            target_id: None,
+        };
+        assert_eq!(Some(&focused), value.focused());
+
+        value.options.clear();
+        assert!(value.focused().is_none());
+    }
+
     #[test]
     fn with_normal_option_and_autocomplete() {
         let value = CommandData {
@@ -55,6 +55,10 @@ pub struct Interaction {
     pub app_permissions: Option<Permissions>,
     /// ID of the associated application.
     pub application_id: Id<ApplicationMarker>,
+    /// Maximum size of attachments in bytes that may be sent in responses to
+    /// the interaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachment_size_limit: Option<u32>,
     /// Mapping of installation contexts that the interaction was
     /// authorized for to related user or guild IDs.
     pub authorizing_integration_owners:
@@ -191,6 +195,7 @@ impl<'de> Deserialize<'de> for Interaction {
 enum InteractionField {
     AppPermissions,
     ApplicationId,
+    AttachmentSizeLimit,
     Context,
     Channel,
     ChannelId,
@@ -223,6 +228,7 @@ impl<'de> Visitor<'de> for InteractionVisitor {
     fn visit_map<V: MapAccess<'de>>(self, mut map: V) -> Result<Self::Value, V::Error> {
         let mut app_permissions: Option<Permissions> = None;
         let mut application_id: Option<Id<ApplicationMarker>> = None;
+        let mut attachment_size_limit: Option<u32> = None;
         let mut channel: Option<Channel> = None;
         let mut channel_id: Option<Id<ChannelMarker>> = None;
         let mut context: Option<InteractionContextType> = None;
@@ -268,6 +274,13 @@ impl<'de> Visitor<'de> for InteractionVisitor {
 
                     application_id = Some(map.next_value()?);
                 }
+                InteractionField::AttachmentSizeLimit => {
+                    if attachment_size_limit.is_some() {
+                        return Err(DeError::duplicate_field("attachment_size_limit"));
+                    }
+
+                    attachment_size_limit = map.next_value()?;
+                }
                 InteractionField::Context => {
                     if context.is_some() {
                         return Err(DeError::duplicate_field("context"));
@@ -436,6 +449,7 @@ impl<'de> Visitor<'de> for InteractionVisitor {
         Ok(Self::Value {
             app_permissions,
             application_id,
+            attachment_size_limit,
             authorizing_integration_owners,
             channel,
             channel_id,
@@ -524,6 +538,7 @@ mod tests {
         let value = Interaction {
             app_permissions: Some(Permissions::SEND_MESSAGES),
             application_id: Id::new(100),
+            attachment_size_limit: Some(26_214_400),
             authorizing_integration_owners: ApplicationIntegrationMap {
                 guild: None,
                 user: None,
@@ -544,6 +559,7 @@ mod tests {
                 rate_limit_per_user: None,
                 recipients: None,
                 rtc_region: None,
+                status: None,
                 topic: None,
                 user_limit: None,
                 application_id: None,
@@ -684,7 +700,7 @@ mod tests {
             &[
                 Token::Struct {
                     name: "Interaction",
-                    len: 14,
+                    len: 15,
                 },
                 Token::Str("app_permissions"),
                 Token::Some,
@@ -692,6 +708,9 @@ mod tests {
                 Token::Str("application_id"),
                 Token::NewtypeStruct { name: "Id" },
                 Token::Str("100"),
+                Token::Str("attachment_size_limit"),
+                Token::Some,
+                Token::U32(26_214_400),
                 Token::Str("authorizing_integration_owners"),
                 Token::Struct {
                     name: "ApplicationIntegrationMap",
@@ -24,6 +24,20 @@ pub struct ModalInteractionData {
     pub custom_id: String,
 }
 
+impl ModalInteractionData {
+    /// Value submitted by the user for a component, by its custom ID.
+    ///
+    /// Returns [`None`] if there is no component with the custom ID or it has
+    /// no value.
+    pub fn value(&self, custom_id: &str) -> Option<&str> {
+        self.components
+            .iter()
+            .flat_map(|row| &row.components)
+            .find(|component| component.custom_id == custom_id)
+            .and_then(|component| component.value.as_deref())
+    }
+}
+
 /// User filled in [`ActionRow`].
 ///
 /// See [Discord Docs/Modal Submit Data Structure].
@@ -123,6 +137,9 @@ mod tests {
             }]),
         };
 
+        assert_eq!(Some("input value"), value.value("the-data-id"));
+        assert!(value.value("other-id").is_none());
+
         serde_test::assert_tokens(
             &value,
             &[
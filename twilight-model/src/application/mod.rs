@@ -2,5 +2,6 @@ pub mod command;
 mod emoji;
 pub mod interaction;
 pub mod monetization;
+pub mod role_connection;
 
 pub use emoji::EmojiList;
@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Role connection of a user for an application.
+///
+/// Requires an OAuth2 access token with the `role_connections.write` scope to
+/// be retrieved or updated.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ApplicationRoleConnection {
+    /// Mapping of the application's metadata keys to the user's values.
+    ///
+    /// Values are stringified integers, ISO8601 timestamps, or booleans
+    /// represented as `"0"` or `"1"`, depending on the type of the metadata
+    /// record.
+    pub metadata: HashMap<String, String>,
+    /// Vanity name of the platform the application represents, up to 50
+    /// characters.
+    pub platform_name: Option<String>,
+    /// Username of the user on the platform, up to 100 characters.
+    pub platform_username: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ApplicationRoleConnection;
+    use serde_test::Token;
+    use std::collections::HashMap;
+
+    #[test]
+    fn application_role_connection() {
+        let value = ApplicationRoleConnection {
+            metadata: HashMap::from([("level".to_owned(), "10".to_owned())]),
+            platform_name: Some("Twilight".to_owned()),
+            platform_username: None,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "ApplicationRoleConnection",
+                    len: 3,
+                },
+                Token::Str("metadata"),
+                Token::Map { len: Some(1) },
+                Token::Str("level"),
+                Token::Str("10"),
+                Token::MapEnd,
+                Token::Str("platform_name"),
+                Token::Some,
+                Token::Str("Twilight"),
+                Token::Str("platform_username"),
+                Token::None,
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -0,0 +1,78 @@
+use super::ApplicationRoleConnectionMetadataType;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Record of metadata that can be used to configure linked role requirements
+/// of an application.
+///
+/// Applications can have up to 5 metadata records.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ApplicationRoleConnectionMetadata {
+    /// Description of the metadata field, between 1 and 200 characters.
+    pub description: String,
+    /// Localization dictionary for the `description` field.
+    ///
+    /// Defaults to [`None`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description_localizations: Option<HashMap<String, String>>,
+    /// Dictionary key for the metadata field.
+    ///
+    /// Must be composed of `a-z`, `0-9`, or `_` characters and between 1 and
+    /// 50 characters.
+    pub key: String,
+    /// Type of comparison performed against the user's metadata value.
+    #[serde(rename = "type")]
+    pub kind: ApplicationRoleConnectionMetadataType,
+    /// Name of the metadata field, between 1 and 100 characters.
+    pub name: String,
+    /// Localization dictionary for the `name` field.
+    ///
+    /// Defaults to [`None`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_localizations: Option<HashMap<String, String>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ApplicationRoleConnectionMetadata;
+    use crate::application::role_connection::ApplicationRoleConnectionMetadataType;
+    use serde_test::Token;
+    use std::collections::HashMap;
+
+    #[test]
+    fn application_role_connection_metadata() {
+        let value = ApplicationRoleConnectionMetadata {
+            description: "days since joining".to_owned(),
+            description_localizations: None,
+            key: "joined".to_owned(),
+            kind: ApplicationRoleConnectionMetadataType::DatetimeLessThanOrEqual,
+            name: "joined".to_owned(),
+            name_localizations: Some(HashMap::from([("fr".to_owned(), "rejoint".to_owned())])),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "ApplicationRoleConnectionMetadata",
+                    len: 5,
+                },
+                Token::Str("description"),
+                Token::Str("days since joining"),
+                Token::Str("key"),
+                Token::Str("joined"),
+                Token::Str("type"),
+                Token::U8(5),
+                Token::Str("name"),
+                Token::Str("joined"),
+                Token::Str("name_localizations"),
+                Token::Some,
+                Token::Map { len: Some(1) },
+                Token::Str("fr"),
+                Token::Str("rejoint"),
+                Token::MapEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -0,0 +1,10 @@
+//! Metadata records used by linked roles and users' role connections.
+
+mod connection;
+mod metadata;
+mod metadata_type;
+
+pub use self::{
+    connection::ApplicationRoleConnection, metadata::ApplicationRoleConnectionMetadata,
+    metadata_type::ApplicationRoleConnectionMetadataType,
+};
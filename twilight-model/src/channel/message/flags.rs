@@ -36,6 +36,8 @@ bitflags! {
         const SUPPRESS_NOTIFICATIONS = 1 << 12;
         /// This message is a voice message.
         const IS_VOICE_MESSAGE = 1 << 13;
+        /// This message has a snapshot, i.e. is a forwarded message.
+        const HAS_SNAPSHOT = 1 << 14;
     }
 }
 
@@ -108,6 +110,8 @@ mod tests {
         1 << 8
     );
     const_assert_eq!(MessageFlags::SUPPRESS_NOTIFICATIONS.bits(), 1 << 12);
+    const_assert_eq!(MessageFlags::IS_VOICE_MESSAGE.bits(), 1 << 13);
+    const_assert_eq!(MessageFlags::HAS_SNAPSHOT.bits(), 1 << 14);
 
     #[test]
     fn serde() {
@@ -1,10 +1,30 @@
-use serde_repr::{Deserialize_repr, Serialize_repr};
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, Serialize_repr)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 #[non_exhaustive]
-#[repr(u8)]
+#[serde(from = "u8", into = "u8")]
 pub enum PrivacyLevel {
-    GuildOnly = 2,
+    GuildOnly,
+    /// Variant value is unknown to the library.
+    Unknown(u8),
+}
+
+impl From<u8> for PrivacyLevel {
+    fn from(value: u8) -> Self {
+        match value {
+            2 => Self::GuildOnly,
+            unknown => Self::Unknown(unknown),
+        }
+    }
+}
+
+impl From<PrivacyLevel> for u8 {
+    fn from(value: PrivacyLevel) -> Self {
+        match value {
+            PrivacyLevel::GuildOnly => 2,
+            PrivacyLevel::Unknown(unknown) => unknown,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -15,5 +35,6 @@ mod tests {
     #[test]
     fn variants() {
         serde_test::assert_tokens(&PrivacyLevel::GuildOnly, &[Token::U8(2)]);
+        serde_test::assert_tokens(&PrivacyLevel::Unknown(99), &[Token::U8(99)]);
     }
 }
@@ -228,7 +228,7 @@ impl Event {
             Event::ReactionAdd(e) => e.0.guild_id,
             Event::ReactionRemove(e) => e.0.guild_id,
             Event::ReactionRemoveAll(e) => e.guild_id,
-            Event::ReactionRemoveEmoji(e) => Some(e.guild_id),
+            Event::ReactionRemoveEmoji(e) => e.guild_id,
             Event::RoleCreate(e) => Some(e.guild_id),
             Event::RoleDelete(e) => Some(e.guild_id),
             Event::RoleUpdate(e) => Some(e.guild_id),
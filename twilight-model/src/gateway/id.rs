@@ -1,3 +1,4 @@
+use crate::id::{marker::GuildMarker, Id};
 use serde::{Deserialize, Serialize};
 use std::{
     error::Error,
@@ -150,6 +151,41 @@ impl ShardId {
         }
     }
 
+    /// Create the identifier of the shard that receives a guild's events,
+    /// out of a total number of shards.
+    ///
+    /// Guild-targeted gateway commands, such as requesting guild members or
+    /// joining a voice channel, must be sent by this shard. Direct message
+    /// events are always received by the shard with number 0.
+    ///
+    /// Refer to the [advanced use] section for the formula.
+    ///
+    /// # Examples
+    ///
+    /// Calculate which of 16 shards receives a guild's events:
+    ///
+    /// ```
+    /// use twilight_model::{gateway::ShardId, id::Id};
+    ///
+    /// let shard_id = ShardId::for_guild(Id::new(175_928_847_299_117_063), 16);
+    /// assert_eq!(ShardId::new(4, 16), shard_id);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the total number of shards is 0.
+    ///
+    /// [advanced use]: Self#advanced-use
+    pub const fn for_guild(guild_id: Id<GuildMarker>, total: u32) -> Self {
+        assert!(total > 0, "total must be at least 1");
+
+        // The remainder is less than `total`, so it fits in a `u32`.
+        #[allow(clippy::cast_possible_truncation)]
+        let number = ((guild_id.get() >> 22) % total as u64) as u32;
+
+        Self::new(number, total)
+    }
+
     /// Identifying number of the shard, 0-indexed.
     pub const fn number(self) -> u32 {
         self.number
@@ -191,6 +227,7 @@ impl From<ShardId> for [u32; 2] {
 #[cfg(test)]
 mod tests {
     use super::ShardId;
+    use crate::id::Id;
     use serde::{de::DeserializeOwned, Serialize};
     use serde_test::Token;
     use static_assertions::{assert_impl_all, const_assert_eq};
@@ -219,6 +256,17 @@ mod tests {
         assert!(ShardId::new_checked(0, 0).is_none());
     }
 
+    #[test]
+    fn for_guild() {
+        assert_eq!(ShardId::ONE, ShardId::for_guild(Id::new(u64::MAX), 1));
+        assert_eq!(ShardId::new(0, 2), ShardId::for_guild(Id::new(1), 2));
+        assert_eq!(
+            ShardId::new(3, 4),
+            ShardId::for_guild(Id::new(3 << 22 | 1), 4)
+        );
+        assert_eq!(ShardId::new(1, 4), ShardId::for_guild(Id::new(5 << 22), 4));
+    }
+
     #[test]
     const fn getters() {
         let id = ShardId::new(2, 4);
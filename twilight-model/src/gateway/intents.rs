@@ -283,6 +283,39 @@ bitflags! {
     }
 }
 
+impl Intents {
+    /// Intents that must be enabled in the developer portal before they can
+    /// be specified when identifying.
+    ///
+    /// These are [`GUILD_MEMBERS`], [`GUILD_PRESENCES`], and
+    /// [`MESSAGE_CONTENT`]. See [Discord Docs/Privileged Intents].
+    ///
+    /// [`GUILD_MEMBERS`]: Self::GUILD_MEMBERS
+    /// [`GUILD_PRESENCES`]: Self::GUILD_PRESENCES
+    /// [`MESSAGE_CONTENT`]: Self::MESSAGE_CONTENT
+    /// [Discord Docs/Privileged Intents]: https://discord.com/developers/docs/topics/gateway#privileged-intents
+    pub const fn privileged() -> Self {
+        Self::GUILD_MEMBERS
+            .union(Self::GUILD_PRESENCES)
+            .union(Self::MESSAGE_CONTENT)
+    }
+
+    /// All intents that may be specified without being enabled in the
+    /// developer portal.
+    ///
+    /// This is every intent except those returned by [`privileged`].
+    ///
+    /// [`privileged`]: Self::privileged
+    pub const fn non_privileged() -> Self {
+        Self::all().difference(Self::privileged())
+    }
+
+    /// Whether any of the intents are privileged.
+    pub const fn is_privileged(self) -> bool {
+        self.intersects(Self::privileged())
+    }
+}
+
 impl<'de> Deserialize<'de> for Intents {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         Ok(Self::from_bits_truncate(u64::deserialize(deserializer)?))
@@ -363,6 +396,20 @@ mod tests {
     const_assert_eq!(Intents::GUILD_MESSAGE_POLLS.bits(), 1 << 24);
     const_assert_eq!(Intents::DIRECT_MESSAGE_POLLS.bits(), 1 << 25);
 
+    #[test]
+    fn privileged() {
+        assert_eq!(
+            Intents::GUILD_MEMBERS | Intents::GUILD_PRESENCES | Intents::MESSAGE_CONTENT,
+            Intents::privileged()
+        );
+        assert!(!Intents::non_privileged().is_privileged());
+        assert!((Intents::GUILDS | Intents::MESSAGE_CONTENT).is_privileged());
+        assert_eq!(
+            Intents::all(),
+            Intents::privileged() | Intents::non_privileged()
+        );
+    }
+
     #[test]
     fn serde() {
         serde_test::assert_tokens(
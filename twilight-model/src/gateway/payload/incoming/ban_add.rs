@@ -4,8 +4,18 @@ use crate::{
 };
 use serde::{Deserialize, Serialize};
 
+/// A user was banned from a guild.
+///
+/// Requires the [`Intents::GUILD_MODERATION`] intent to receive this event.
+///
+/// Refer to [Discord Docs/Guild Ban Add] for additional information.
+///
+/// [`Intents::GUILD_MODERATION`]: crate::gateway::Intents::GUILD_MODERATION
+/// [Discord Docs/Guild Ban Add]: https://discord.com/developers/docs/topics/gateway-events#guild-ban-add
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct BanAdd {
+    /// ID of the guild.
     pub guild_id: Id<GuildMarker>,
+    /// User who was banned.
     pub user: User,
 }
@@ -4,8 +4,18 @@ use crate::{
 };
 use serde::{Deserialize, Serialize};
 
+/// A user was unbanned from a guild.
+///
+/// Requires the [`Intents::GUILD_MODERATION`] intent to receive this event.
+///
+/// Refer to [Discord Docs/Guild Ban Remove] for additional information.
+///
+/// [`Intents::GUILD_MODERATION`]: crate::gateway::Intents::GUILD_MODERATION
+/// [Discord Docs/Guild Ban Remove]: https://discord.com/developers/docs/topics/gateway-events#guild-ban-remove
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct BanRemove {
+    /// ID of the guild.
     pub guild_id: Id<GuildMarker>,
+    /// User who was unbanned.
     pub user: User,
 }
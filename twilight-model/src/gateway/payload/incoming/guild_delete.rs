@@ -1,6 +1,12 @@
 use crate::id::{marker::GuildMarker, Id};
 use serde::{Deserialize, Serialize};
 
+/// Guild became unavailable due to an outage, or the current user was removed
+/// from it.
+///
+/// Use [`is_outage`] to distinguish the two.
+///
+/// [`is_outage`]: Self::is_outage
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct GuildDelete {
     pub id: Id<GuildMarker>,
@@ -8,6 +14,19 @@ pub struct GuildDelete {
     pub unavailable: Option<bool>,
 }
 
+impl GuildDelete {
+    /// Whether the guild is unavailable due to an outage, rather than the
+    /// current user having been removed from it.
+    ///
+    /// Guilds that are unavailable due to an outage are sent again via
+    /// [`GuildCreate`] once they are available.
+    ///
+    /// [`GuildCreate`]: super::GuildCreate
+    pub const fn is_outage(&self) -> bool {
+        matches!(self.unavailable, Some(true))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::GuildDelete;
@@ -96,6 +115,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn is_outage() {
+        let mut event = GuildDelete {
+            id: Id::new(123),
+            unavailable: Some(true),
+        };
+        assert!(event.is_outage());
+
+        event.unavailable = Some(false);
+        assert!(!event.is_outage());
+
+        event.unavailable = None;
+        assert!(!event.is_outage());
+    }
+
     #[test]
     fn guild_delete_unavailable_null_default() {
         let expected = GuildDelete {
@@ -4,8 +4,18 @@ use crate::{
 };
 use serde::{Deserialize, Serialize};
 
+/// A guild's emojis were updated.
+///
+/// Contains the full list of the guild's emojis, replacing the previous list.
+///
+/// Requires the [`Intents::GUILD_EMOJIS_AND_STICKERS`] intent to receive this
+/// event.
+///
+/// [`Intents::GUILD_EMOJIS_AND_STICKERS`]: crate::gateway::Intents::GUILD_EMOJIS_AND_STICKERS
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct GuildEmojisUpdate {
+    /// All of the guild's emojis.
     pub emojis: Vec<Emoji>,
+    /// ID of the guild.
     pub guild_id: Id<GuildMarker>,
 }
@@ -1,7 +1,20 @@
 use crate::id::{marker::GuildMarker, Id};
 use serde::{Deserialize, Serialize};
 
+/// A guild's integrations were updated.
+///
+/// The event doesn't include the integrations; they must be fetched over
+/// HTTP.
+///
+/// Requires the [`Intents::GUILD_INTEGRATIONS`] intent to receive this event.
+///
+/// Refer to [Discord Docs/Guild Integrations Update] for additional
+/// information.
+///
+/// [`Intents::GUILD_INTEGRATIONS`]: crate::gateway::Intents::GUILD_INTEGRATIONS
+/// [Discord Docs/Guild Integrations Update]: https://discord.com/developers/docs/topics/gateway-events#guild-integrations-update
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct GuildIntegrationsUpdate {
+    /// ID of the guild whose integrations were updated.
     pub guild_id: Id<GuildMarker>,
 }
@@ -4,8 +4,19 @@ use crate::{
 };
 use serde::{Deserialize, Serialize};
 
+/// A guild's stickers were updated.
+///
+/// Contains the full list of the guild's stickers, replacing the previous
+/// list.
+///
+/// Requires the [`Intents::GUILD_EMOJIS_AND_STICKERS`] intent to receive this
+/// event.
+///
+/// [`Intents::GUILD_EMOJIS_AND_STICKERS`]: crate::gateway::Intents::GUILD_EMOJIS_AND_STICKERS
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct GuildStickersUpdate {
+    /// ID of the guild.
     pub guild_id: Id<GuildMarker>,
+    /// All of the guild's stickers.
     pub stickers: Vec<Sticker>,
 }
@@ -2,6 +2,14 @@ use crate::guild::GuildIntegration;
 use serde::{Deserialize, Serialize};
 use std::ops::{Deref, DerefMut};
 
+/// A [`GuildIntegration`] was created.
+///
+/// Requires the [`Intents::GUILD_INTEGRATIONS`] intent to receive this event.
+///
+/// Refer to [Discord Docs/Integration Create] for additional information.
+///
+/// [`Intents::GUILD_INTEGRATIONS`]: crate::gateway::Intents::GUILD_INTEGRATIONS
+/// [Discord Docs/Integration Create]: https://discord.com/developers/docs/topics/gateway-events#integration-create
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct IntegrationCreate(pub GuildIntegration);
 
@@ -4,6 +4,15 @@ use crate::id::{
 };
 use serde::{Deserialize, Serialize};
 
+/// A [`GuildIntegration`] was deleted.
+///
+/// Requires the [`Intents::GUILD_INTEGRATIONS`] intent to receive this event.
+///
+/// Refer to [Discord Docs/Integration Delete] for additional information.
+///
+/// [`GuildIntegration`]: crate::guild::GuildIntegration
+/// [`Intents::GUILD_INTEGRATIONS`]: crate::gateway::Intents::GUILD_INTEGRATIONS
+/// [Discord Docs/Integration Delete]: https://discord.com/developers/docs/topics/gateway-events#integration-delete
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct IntegrationDelete {
     /// ID of the Bot/OAuth2 application for this integration.
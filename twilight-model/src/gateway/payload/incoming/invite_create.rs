@@ -34,7 +34,10 @@ pub struct InviteCreate {
     /// Maximum number of uses before the invite expires.
     pub max_uses: u64,
     /// Target of the invite.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    ///
+    /// Deserialized from either the `target_type` field sent by the gateway or
+    /// the legacy `target_user_type` field.
+    #[serde(alias = "target_type", skip_serializing_if = "Option::is_none")]
     pub target_user_type: Option<TargetType>,
     /// User whose stream to display for this voice channel stream invite.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -85,7 +88,7 @@ impl PartialUser {
 #[cfg(test)]
 mod tests {
     use super::{InviteCreate, PartialUser};
-    use crate::{id::Id, test::image_hash, util::Timestamp};
+    use crate::{guild::invite::TargetType, id::Id, test::image_hash, util::Timestamp};
     use serde::{Deserialize, Serialize};
     use serde_test::Token;
     use static_assertions::{assert_fields, assert_impl_all};
@@ -176,6 +179,57 @@ mod tests {
         );
     }
 
+    #[test]
+    fn invite_create_target_type() {
+        let created_at = Timestamp::from_secs(1_609_459_200).expect("non zero");
+
+        let value = InviteCreate {
+            channel_id: Id::new(1),
+            code: "a".repeat(7),
+            created_at,
+            guild_id: Id::new(2),
+            inviter: None,
+            max_age: 0,
+            max_uses: 0,
+            target_user_type: Some(TargetType::Stream),
+            target_user: None,
+            temporary: true,
+            uses: 0,
+        };
+
+        serde_test::assert_de_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "InviteCreate",
+                    len: 9,
+                },
+                Token::Str("channel_id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::Str("code"),
+                Token::Str("aaaaaaa"),
+                Token::Str("created_at"),
+                Token::Str("2021-01-01T00:00:00.000000+00:00"),
+                Token::Str("guild_id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("2"),
+                Token::Str("max_age"),
+                Token::U64(0),
+                Token::Str("max_uses"),
+                Token::U64(0),
+                Token::Str("target_type"),
+                Token::Some,
+                Token::U8(1),
+                Token::Str("temporary"),
+                Token::Bool(true),
+                Token::Str("uses"),
+                Token::U8(0),
+                Token::StructEnd,
+            ],
+        );
+    }
+
     #[test]
     fn partial_user() {
         let value = PartialUser {
@@ -4,9 +4,15 @@ use crate::id::{
 };
 use serde::{Deserialize, Serialize};
 
+/// An [`Invite`] has been deleted.
+///
+/// [`Invite`]: crate::guild::invite::Invite
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct InviteDelete {
+    /// ID of the channel the invite was for.
     pub channel_id: Id<ChannelMarker>,
+    /// Unique code of the invite.
     pub code: String,
+    /// ID of the guild the invite was for.
     pub guild_id: Id<GuildMarker>,
 }
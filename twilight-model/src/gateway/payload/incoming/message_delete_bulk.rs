@@ -4,10 +4,18 @@ use crate::id::{
 };
 use serde::{Deserialize, Serialize};
 
+/// Multiple messages were deleted at once.
+///
+/// Requires the [`Intents::GUILD_MESSAGES`] intent to receive this event.
+///
+/// [`Intents::GUILD_MESSAGES`]: crate::gateway::Intents::GUILD_MESSAGES
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct MessageDeleteBulk {
+    /// ID of the channel the messages were in.
     pub channel_id: Id<ChannelMarker>,
+    /// ID of the guild the channel is in.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub guild_id: Option<Id<GuildMarker>>,
+    /// IDs of the deleted messages.
     pub ids: Vec<Id<MessageMarker>>,
 }
@@ -2,6 +2,19 @@ use crate::channel::Message;
 use serde::{Deserialize, Serialize};
 use std::ops::{Deref, DerefMut};
 
+/// A message was updated.
+///
+/// Discord sends the full updated message, so the payload is a [`Message`]
+/// rather than only the fields that changed.
+///
+/// Requires the [`Intents::GUILD_MESSAGES`] or [`Intents::DIRECT_MESSAGES`]
+/// intent to receive this event.
+///
+/// Refer to [Discord Docs/Message Update] for additional information.
+///
+/// [`Intents::DIRECT_MESSAGES`]: crate::gateway::Intents::DIRECT_MESSAGES
+/// [`Intents::GUILD_MESSAGES`]: crate::gateway::Intents::GUILD_MESSAGES
+/// [Discord Docs/Message Update]: https://discord.com/developers/docs/topics/gateway-events#message-update
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 #[serde(transparent)]
 pub struct MessageUpdate(pub Message);
@@ -2,6 +2,16 @@ use crate::gateway::GatewayReaction;
 use serde::{Deserialize, Serialize};
 use std::ops::{Deref, DerefMut};
 
+/// A user reacted to a message.
+///
+/// Reactions in guilds include the [`member`] who reacted.
+///
+/// Requires the [`Intents::GUILD_MESSAGE_REACTIONS`] or
+/// [`Intents::DIRECT_MESSAGE_REACTIONS`] intent to receive this event.
+///
+/// [`Intents::DIRECT_MESSAGE_REACTIONS`]: crate::gateway::Intents::DIRECT_MESSAGE_REACTIONS
+/// [`Intents::GUILD_MESSAGE_REACTIONS`]: crate::gateway::Intents::GUILD_MESSAGE_REACTIONS
+/// [`member`]: GatewayReaction::member
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct ReactionAdd(pub GatewayReaction);
 
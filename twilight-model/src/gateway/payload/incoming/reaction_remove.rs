@@ -2,6 +2,13 @@ use crate::gateway::GatewayReaction;
 use serde::{Deserialize, Serialize};
 use std::ops::{Deref, DerefMut};
 
+/// A user removed their reaction from a message.
+///
+/// Requires the [`Intents::GUILD_MESSAGE_REACTIONS`] or
+/// [`Intents::DIRECT_MESSAGE_REACTIONS`] intent to receive this event.
+///
+/// [`Intents::DIRECT_MESSAGE_REACTIONS`]: crate::gateway::Intents::DIRECT_MESSAGE_REACTIONS
+/// [`Intents::GUILD_MESSAGE_REACTIONS`]: crate::gateway::Intents::GUILD_MESSAGE_REACTIONS
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct ReactionRemove(pub GatewayReaction);
 
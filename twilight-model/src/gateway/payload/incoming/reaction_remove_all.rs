@@ -4,10 +4,20 @@ use crate::id::{
 };
 use serde::{Deserialize, Serialize};
 
+/// All reactions were removed from a message.
+///
+/// Requires the [`Intents::GUILD_MESSAGE_REACTIONS`] or
+/// [`Intents::DIRECT_MESSAGE_REACTIONS`] intent to receive this event.
+///
+/// [`Intents::DIRECT_MESSAGE_REACTIONS`]: crate::gateway::Intents::DIRECT_MESSAGE_REACTIONS
+/// [`Intents::GUILD_MESSAGE_REACTIONS`]: crate::gateway::Intents::GUILD_MESSAGE_REACTIONS
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct ReactionRemoveAll {
+    /// ID of the channel the message is in.
     pub channel_id: Id<ChannelMarker>,
+    /// ID of the message.
     pub message_id: Id<MessageMarker>,
+    /// ID of the guild the message is in, if it's in a guild.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub guild_id: Option<Id<GuildMarker>>,
 }
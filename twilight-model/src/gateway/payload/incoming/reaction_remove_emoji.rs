@@ -7,10 +7,66 @@ use crate::{
 };
 use serde::{Deserialize, Serialize};
 
+/// All reactions of an emoji were removed from a message.
+///
+/// Requires the [`Intents::GUILD_MESSAGE_REACTIONS`] or
+/// [`Intents::DIRECT_MESSAGE_REACTIONS`] intent to receive this event.
+///
+/// [`Intents::DIRECT_MESSAGE_REACTIONS`]: crate::gateway::Intents::DIRECT_MESSAGE_REACTIONS
+/// [`Intents::GUILD_MESSAGE_REACTIONS`]: crate::gateway::Intents::GUILD_MESSAGE_REACTIONS
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct ReactionRemoveEmoji {
+    /// ID of the channel the message is in.
     pub channel_id: Id<ChannelMarker>,
+    /// Emoji whose reactions were removed.
     pub emoji: EmojiReactionType,
-    pub guild_id: Id<GuildMarker>,
+    /// ID of the guild the message is in, if it's in a guild.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<Id<GuildMarker>>,
+    /// ID of the message.
     pub message_id: Id<MessageMarker>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ReactionRemoveEmoji;
+    use crate::{channel::message::EmojiReactionType, id::Id};
+    use serde_test::Token;
+
+    #[test]
+    fn private_channel() {
+        let value = ReactionRemoveEmoji {
+            channel_id: Id::new(1),
+            emoji: EmojiReactionType::Unicode {
+                name: "\u{1f643}".to_owned(),
+            },
+            guild_id: None,
+            message_id: Id::new(2),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "ReactionRemoveEmoji",
+                    len: 3,
+                },
+                Token::Str("channel_id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::Str("emoji"),
+                Token::Struct {
+                    name: "EmojiReactionType",
+                    len: 1,
+                },
+                Token::Str("name"),
+                Token::Str("\u{1f643}"),
+                Token::StructEnd,
+                Token::Str("message_id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("2"),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -2,6 +2,11 @@ use crate::channel::Channel;
 use serde::{Deserialize, Serialize};
 use std::ops::{Deref, DerefMut};
 
+/// A thread was created or the current user was added to a private thread.
+///
+/// Requires the [`Intents::GUILDS`] intent to receive this event.
+///
+/// [`Intents::GUILDS`]: crate::gateway::Intents::GUILDS
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct ThreadCreate(pub Channel);
 
@@ -7,11 +7,20 @@ use crate::{
 };
 use serde::{Deserialize, Serialize};
 
+/// A thread was deleted.
+///
+/// Requires the [`Intents::GUILDS`] intent to receive this event.
+///
+/// [`Intents::GUILDS`]: crate::gateway::Intents::GUILDS
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct ThreadDelete {
+    /// ID of the guild the thread was in.
     pub guild_id: Id<GuildMarker>,
+    /// ID of the thread.
     pub id: Id<ChannelMarker>,
+    /// Type of the thread.
     #[serde(rename = "type")]
     pub kind: ChannelType,
+    /// ID of the channel the thread was in.
     pub parent_id: Id<ChannelMarker>,
 }
@@ -7,11 +7,23 @@ use crate::{
 };
 use serde::{Deserialize, Serialize};
 
+/// Active threads were synced after the current user gained access to a
+/// channel.
+///
+/// Requires the [`Intents::GUILDS`] intent to receive this event.
+///
+/// [`Intents::GUILDS`]: crate::gateway::Intents::GUILDS
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct ThreadListSync {
+    /// IDs of the parent channels whose threads are being synced.
+    ///
+    /// Empty if the threads of every channel in the guild are being synced.
     #[serde(default)]
     pub channel_ids: Vec<Id<ChannelMarker>>,
+    /// ID of the guild.
     pub guild_id: Id<GuildMarker>,
+    /// Thread members of the current user for the synced threads.
     pub members: Vec<ThreadMember>,
+    /// Active threads in the channels.
     pub threads: Vec<Channel>,
 }
@@ -5,9 +5,16 @@ use crate::{
 use serde::{Deserialize, Serialize};
 use std::ops::{Deref, DerefMut};
 
+/// The current user's thread member was updated.
+///
+/// Requires the [`Intents::GUILDS`] intent to receive this event.
+///
+/// [`Intents::GUILDS`]: crate::gateway::Intents::GUILDS
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct ThreadMemberUpdate {
+    /// ID of the guild the thread is in.
     pub guild_id: Id<GuildMarker>,
+    /// Updated thread member.
     #[serde(flatten)]
     pub member: ThreadMember,
 }
@@ -11,6 +11,12 @@ use serde::{
 };
 use std::fmt::{Formatter, Result as FmtResult};
 
+/// Members were added to or removed from a thread.
+///
+/// Requires the [`Intents::GUILD_MEMBERS`] intent to receive this event for
+/// members other than the current user.
+///
+/// [`Intents::GUILD_MEMBERS`]: crate::gateway::Intents::GUILD_MEMBERS
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize)]
 pub struct ThreadMembersUpdate {
     /// List of thread members.
@@ -20,12 +26,15 @@ pub struct ThreadMembersUpdate {
     /// [`member`]: ThreadMember::member
     #[serde(default)]
     pub added_members: Vec<ThreadMember>,
+    /// ID of the guild the thread is in.
     pub guild_id: Id<GuildMarker>,
+    /// ID of the thread.
     pub id: Id<ChannelMarker>,
     /// Number of members in the thread.
     ///
     /// This is an approximation and may not be accurate.
     pub member_count: i32,
+    /// IDs of the users removed from the thread.
     #[serde(default)]
     pub removed_member_ids: Vec<Id<UserMarker>>,
 }
@@ -150,6 +159,7 @@ mod tests {
             buttons: Vec::new(),
             created_at: Some(1_571_048_061_237),
             details: None,
+            details_url: None,
             flags: None,
             id: Some("aaaaaaaaaaaaaaaa".to_owned()),
             instance: None,
@@ -163,6 +173,8 @@ mod tests {
             party: None,
             secrets: None,
             state: None,
+            state_url: None,
+            status_display_type: None,
             timestamps: None,
             url: None,
         };
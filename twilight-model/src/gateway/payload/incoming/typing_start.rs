@@ -7,14 +7,29 @@ use crate::{
 };
 use serde::{Deserialize, Serialize};
 
+/// User started typing in a channel.
+///
+/// Requires the [`Intents::GUILD_MESSAGE_TYPING`] or
+/// [`Intents::DIRECT_MESSAGE_TYPING`] intent to receive this event.
+///
+/// Refer to [Discord Docs/Typing Start] for additional information.
+///
+/// [`Intents::DIRECT_MESSAGE_TYPING`]: crate::gateway::Intents::DIRECT_MESSAGE_TYPING
+/// [`Intents::GUILD_MESSAGE_TYPING`]: crate::gateway::Intents::GUILD_MESSAGE_TYPING
+/// [Discord Docs/Typing Start]: https://discord.com/developers/docs/topics/gateway-events#typing-start
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct TypingStart {
+    /// ID of the channel the user is typing in.
     pub channel_id: Id<ChannelMarker>,
+    /// ID of the guild the channel is in, if it's a guild channel.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub guild_id: Option<Id<GuildMarker>>,
+    /// Member who started typing, if the channel is in a guild.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub member: Option<Member>,
+    /// Unix time in seconds of when the user started typing.
     pub timestamp: u64,
+    /// ID of the user who started typing.
     pub user_id: Id<UserMarker>,
 }
 
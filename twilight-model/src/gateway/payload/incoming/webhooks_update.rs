@@ -4,9 +4,21 @@ use crate::id::{
 };
 use serde::{Deserialize, Serialize};
 
+/// A channel's webhooks were created, updated, or deleted.
+///
+/// The event doesn't include the webhooks; they must be fetched over HTTP.
+///
+/// Requires the [`Intents::GUILD_WEBHOOKS`] intent to receive this event.
+///
+/// Refer to [Discord Docs/Webhooks Update] for additional information.
+///
+/// [`Intents::GUILD_WEBHOOKS`]: crate::gateway::Intents::GUILD_WEBHOOKS
+/// [Discord Docs/Webhooks Update]: https://discord.com/developers/docs/topics/gateway-events#webhooks-update
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct WebhooksUpdate {
+    /// ID of the channel whose webhooks changed.
     pub channel_id: Id<ChannelMarker>,
+    /// ID of the guild the channel is in.
     pub guild_id: Id<GuildMarker>,
 }
 
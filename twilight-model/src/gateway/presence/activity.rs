@@ -1,7 +1,7 @@
 use crate::{
     gateway::presence::{
         ActivityAssets, ActivityButton, ActivityEmoji, ActivityFlags, ActivityParty,
-        ActivitySecrets, ActivityTimestamps, ActivityType,
+        ActivitySecrets, ActivityTimestamps, ActivityType, StatusDisplayType,
     },
     id::{marker::ApplicationMarker, Id},
 };
@@ -20,6 +20,9 @@ pub struct Activity {
     pub created_at: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub details: Option<String>,
+    /// URL that is linked when clicking on the details text.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details_url: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub emoji: Option<ActivityEmoji>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -37,6 +40,12 @@ pub struct Activity {
     pub secrets: Option<ActivitySecrets>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub state: Option<String>,
+    /// URL that is linked when clicking on the state text.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state_url: Option<String>,
+    /// Which field is displayed in the user's status text in the member list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_display_type: Option<StatusDisplayType>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub timestamps: Option<ActivityTimestamps>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -46,4 +55,36 @@ pub struct Activity {
 #[cfg(test)]
 mod tests {
     // Custom activities is tested by the custom presence test.
+
+    use super::Activity;
+    use crate::gateway::presence::{ActivityType, StatusDisplayType};
+
+    #[test]
+    fn status_display_type() -> Result<(), serde_json::Error> {
+        let input = r#"{
+            "created_at": 1571048061237,
+            "details": "Song",
+            "details_url": "https://example.com/song",
+            "name": "Music",
+            "state": "Artist",
+            "state_url": "https://example.com/artist",
+            "status_display_type": 1,
+            "type": 2
+        }"#;
+
+        let activity = serde_json::from_str::<Activity>(input)?;
+
+        assert_eq!(ActivityType::Listening, activity.kind);
+        assert_eq!(
+            Some("https://example.com/song"),
+            activity.details_url.as_deref()
+        );
+        assert_eq!(
+            Some("https://example.com/artist"),
+            activity.state_url.as_deref()
+        );
+        assert_eq!(Some(StatusDisplayType::State), activity.status_display_type);
+
+        Ok(())
+    }
 }
@@ -15,6 +15,7 @@ impl From<MinimalActivity> for Activity {
             buttons: Vec::new(),
             created_at: None,
             details: None,
+            details_url: None,
             emoji: None,
             flags: None,
             id: None,
@@ -24,6 +25,8 @@ impl From<MinimalActivity> for Activity {
             party: None,
             secrets: None,
             state: None,
+            state_url: None,
+            status_display_type: None,
             timestamps: None,
             url: minimal_activity.url,
         }
@@ -11,13 +11,14 @@ mod activity_type;
 mod client_status;
 mod minimal_activity;
 mod status;
+mod status_display_type;
 
 pub use self::{
     activity::Activity, activity_assets::ActivityAssets, activity_button::ActivityButton,
     activity_emoji::ActivityEmoji, activity_flags::ActivityFlags, activity_party::ActivityParty,
     activity_secrets::ActivitySecrets, activity_timestamps::ActivityTimestamps,
     activity_type::ActivityType, client_status::ClientStatus, minimal_activity::MinimalActivity,
-    status::Status,
+    status::Status, status_display_type::StatusDisplayType,
 };
 
 use crate::{
@@ -187,6 +188,7 @@ mod tests {
             buttons: Vec::new(),
             created_at: Some(1_571_048_061_237),
             details: None,
+            details_url: None,
             flags: None,
             id: Some("aaaaaaaaaaaaaaaa".to_owned()),
             instance: None,
@@ -200,6 +202,8 @@ mod tests {
             party: None,
             secrets: None,
             state: None,
+            state_url: None,
+            status_display_type: None,
             timestamps: None,
             url: None,
         };
@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+/// Which field of an [`Activity`] is displayed in the user's status text in
+/// the member list.
+///
+/// [`Activity`]: super::Activity
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[non_exhaustive]
+#[serde(from = "u8", into = "u8")]
+pub enum StatusDisplayType {
+    /// Activity's name is displayed.
+    Name,
+    /// Activity's state is displayed.
+    State,
+    /// Activity's details are displayed.
+    Details,
+    /// Variant value is unknown to the library.
+    Unknown(u8),
+}
+
+impl From<u8> for StatusDisplayType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => StatusDisplayType::Name,
+            1 => StatusDisplayType::State,
+            2 => StatusDisplayType::Details,
+            unknown => StatusDisplayType::Unknown(unknown),
+        }
+    }
+}
+
+impl From<StatusDisplayType> for u8 {
+    fn from(value: StatusDisplayType) -> Self {
+        match value {
+            StatusDisplayType::Name => 0,
+            StatusDisplayType::State => 1,
+            StatusDisplayType::Details => 2,
+            StatusDisplayType::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StatusDisplayType;
+    use serde_test::Token;
+
+    #[test]
+    fn variants() {
+        serde_test::assert_tokens(&StatusDisplayType::Name, &[Token::U8(0)]);
+        serde_test::assert_tokens(&StatusDisplayType::State, &[Token::U8(1)]);
+        serde_test::assert_tokens(&StatusDisplayType::Details, &[Token::U8(2)]);
+        serde_test::assert_tokens(&StatusDisplayType::Unknown(99), &[Token::U8(99)]);
+    }
+}
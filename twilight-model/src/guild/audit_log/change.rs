@@ -508,6 +508,15 @@ pub enum AuditLogChange {
         #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
         old: Option<String>,
     },
+    /// Whether a guild's boost progress bar is enabled.
+    PremiumProgressBarEnabled {
+        /// New state of the boost progress bar being enabled.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<bool>,
+        /// Old state of the boost progress bar being enabled.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<bool>,
+    },
     /// Privacy level of a stage instance.
     PrivacyLevel {
         /// New privacy level.
@@ -581,6 +590,15 @@ pub enum AuditLogChange {
         #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
         old: Option<Id<ChannelMarker>>,
     },
+    /// ID of a guild's safety alerts channel.
+    SafetyAlertsChannelId {
+        /// New safety alerts channel ID.
+        #[serde(rename = "new_value", skip_serializing_if = "Option::is_none")]
+        new: Option<Id<ChannelMarker>>,
+        /// Old safety alerts channel ID.
+        #[serde(rename = "old_value", skip_serializing_if = "Option::is_none")]
+        old: Option<Id<ChannelMarker>>,
+    },
     /// Hash of a guild's splash.
     SplashHash {
         /// Old hash of a guild's splash.
@@ -796,6 +814,7 @@ impl AuditLogChange {
             Self::Permissions { .. } => AuditLogChangeKey::Permissions,
             Self::Position { .. } => AuditLogChangeKey::Position,
             Self::PreferredLocale { .. } => AuditLogChangeKey::PreferredLocale,
+            Self::PremiumProgressBarEnabled { .. } => AuditLogChangeKey::PremiumProgressBarEnabled,
             Self::PrivacyLevel { .. } => AuditLogChangeKey::PrivacyLevel,
             Self::PruneDeleteDays { .. } => AuditLogChangeKey::PruneDeleteDays,
             Self::PublicUpdatesChannelId { .. } => AuditLogChangeKey::PublicUpdatesChannelId,
@@ -804,6 +823,7 @@ impl AuditLogChange {
             Self::RoleAdded { .. } => AuditLogChangeKey::RoleAdded,
             Self::RoleRemoved { .. } => AuditLogChangeKey::RoleRemoved,
             Self::RulesChannelId { .. } => AuditLogChangeKey::RulesChannelId,
+            Self::SafetyAlertsChannelId { .. } => AuditLogChangeKey::SafetyAlertsChannelId,
             Self::SplashHash { .. } => AuditLogChangeKey::SplashHash,
             Self::Status { .. } => AuditLogChangeKey::Status,
             Self::SystemChannelId { .. } => AuditLogChangeKey::SystemChannelId,
@@ -873,12 +893,14 @@ mod tests {
     assert_fields!(AuditLogChange::PrivacyLevel: new, old);
     assert_fields!(AuditLogChange::Position: new, old);
     assert_fields!(AuditLogChange::PreferredLocale: new, old);
+    assert_fields!(AuditLogChange::PremiumProgressBarEnabled: new, old);
     assert_fields!(AuditLogChange::PruneDeleteDays: new);
     assert_fields!(AuditLogChange::PublicUpdatesChannelId: new, old);
     assert_fields!(AuditLogChange::RateLimitPerUser: new, old);
     assert_fields!(AuditLogChange::RoleAdded: new);
     assert_fields!(AuditLogChange::RoleRemoved: new);
     assert_fields!(AuditLogChange::RulesChannelId: new, old);
+    assert_fields!(AuditLogChange::SafetyAlertsChannelId: new, old);
     assert_fields!(AuditLogChange::SplashHash: new, old);
     assert_fields!(AuditLogChange::SystemChannelId: new, old);
     assert_fields!(AuditLogChange::Temporary: new);
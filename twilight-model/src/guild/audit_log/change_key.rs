@@ -115,6 +115,8 @@ pub enum AuditLogChangeKey {
     Position,
     /// Preferred locale of a guild.
     PreferredLocale,
+    /// Whether a guild's boost progress bar is enabled.
+    PremiumProgressBarEnabled,
     /// Privacy level of a stage instance.
     PrivacyLevel,
     /// Number of days' worth of inactivity for a guild prune.
@@ -133,6 +135,8 @@ pub enum AuditLogChangeKey {
     RoleRemoved,
     /// ID of a guild's rules channel.
     RulesChannelId,
+    /// ID of a guild's safety alerts channel.
+    SafetyAlertsChannelId,
     /// Hash of a guild's splash.
     SplashHash,
     /// Status of guild scheduled event was changed.
@@ -234,6 +238,7 @@ impl AuditLogChangeKey {
             Self::Permissions => "permissions",
             Self::Position => "position",
             Self::PreferredLocale => "preferred_locale",
+            Self::PremiumProgressBarEnabled => "premium_progress_bar_enabled",
             Self::PrivacyLevel => "privacy_level",
             Self::PruneDeleteDays => "prune_delete_days",
             Self::PublicUpdatesChannelId => "public_updates_channel_id",
@@ -242,6 +247,7 @@ impl AuditLogChangeKey {
             Self::RoleAdded => "$add",
             Self::RoleRemoved => "$remove",
             Self::RulesChannelId => "rules_channel_id",
+            Self::SafetyAlertsChannelId => "safety_alerts_channel_id",
             Self::SplashHash => "splash_hash",
             Self::Status => "status",
             Self::SystemChannelId => "system_channel_id",
@@ -291,6 +297,7 @@ mod tests {
         Sync
     );
 
+    #[allow(clippy::too_many_lines)]
     #[test]
     fn name() {
         assert_eq!("afk_channel_id", AuditLogChangeKey::AfkChannelId.name());
@@ -353,6 +360,10 @@ mod tests {
             "preferred_locale",
             AuditLogChangeKey::PreferredLocale.name()
         );
+        assert_eq!(
+            "premium_progress_bar_enabled",
+            AuditLogChangeKey::PremiumProgressBarEnabled.name()
+        );
         assert_eq!("privacy_level", AuditLogChangeKey::PrivacyLevel.name());
         assert_eq!(
             "prune_delete_days",
@@ -369,6 +380,10 @@ mod tests {
         assert_eq!("$add", AuditLogChangeKey::RoleAdded.name());
         assert_eq!("$remove", AuditLogChangeKey::RoleRemoved.name());
         assert_eq!("rules_channel_id", AuditLogChangeKey::RulesChannelId.name());
+        assert_eq!(
+            "safety_alerts_channel_id",
+            AuditLogChangeKey::SafetyAlertsChannelId.name()
+        );
         assert_eq!("splash_hash", AuditLogChangeKey::SplashHash.name());
         assert_eq!(
             "system_channel_id",
@@ -667,6 +682,13 @@ mod tests {
                 variant: "preferred_locale",
             }],
         );
+        serde_test::assert_tokens(
+            &AuditLogChangeKey::PremiumProgressBarEnabled,
+            &[Token::UnitVariant {
+                name: "AuditLogChangeKey",
+                variant: "premium_progress_bar_enabled",
+            }],
+        );
         serde_test::assert_tokens(
             &AuditLogChangeKey::PrivacyLevel,
             &[Token::UnitVariant {
@@ -716,6 +738,13 @@ mod tests {
                 variant: "rules_channel_id",
             }],
         );
+        serde_test::assert_tokens(
+            &AuditLogChangeKey::SafetyAlertsChannelId,
+            &[Token::UnitVariant {
+                name: "AuditLogChangeKey",
+                variant: "safety_alerts_channel_id",
+            }],
+        );
         serde_test::assert_tokens(
             &AuditLogChangeKey::SplashHash,
             &[Token::UnitVariant {
@@ -16,6 +16,8 @@ pub enum GuildFeature {
     AnimatedBanner,
     /// Has access to set an animated guild icon.
     AnimatedIcon,
+    /// Is using the old permissions configuration behavior.
+    ApplicationCommandPermissionsV2,
     /// Has set up auto moderation rules.
     AutoModeration,
     /// Has access to set a guild banner image.
@@ -39,6 +41,8 @@ pub enum GuildFeature {
     InviteSplash,
     /// Has enabled membership screening.
     MemberVerificationGateEnabled,
+    /// Has increased custom soundboard sound slots.
+    MoreSoundboard,
     /// Has increased custom sticker slots.
     MoreStickers,
     /// Has access to create news channels.
@@ -57,6 +61,8 @@ pub enum GuildFeature {
     RoleSubscriptionsAvailableForPurchase,
     /// Guild has enabled role subscriptions.
     RoleSubscriptionsEnabled,
+    /// Has created soundboard sounds.
+    Soundboard,
     /// Has enabled ticketed events.
     TicketedEventsEnabled,
     /// Has access to set a vanity URL.
@@ -76,6 +82,9 @@ impl From<GuildFeature> for Cow<'static, str> {
         match value {
             GuildFeature::AnimatedBanner => "ANIMATED_BANNER".into(),
             GuildFeature::AnimatedIcon => "ANIMATED_ICON".into(),
+            GuildFeature::ApplicationCommandPermissionsV2 => {
+                "APPLICATION_COMMAND_PERMISSIONS_V2".into()
+            }
             GuildFeature::AutoModeration => "AUTO_MODERATION".into(),
             GuildFeature::Banner => "BANNER".into(),
             GuildFeature::Community => "COMMUNITY".into(),
@@ -89,6 +98,7 @@ impl From<GuildFeature> for Cow<'static, str> {
             GuildFeature::MemberVerificationGateEnabled => {
                 "MEMBER_VERIFICATION_GATE_ENABLED".into()
             }
+            GuildFeature::MoreSoundboard => "MORE_SOUNDBOARD".into(),
             GuildFeature::MoreStickers => "MORE_STICKERS".into(),
             GuildFeature::News => "NEWS".into(),
             GuildFeature::Partnered => "PARTNERED".into(),
@@ -100,6 +110,7 @@ impl From<GuildFeature> for Cow<'static, str> {
                 "ROLE_SUBSCRIPTIONS_AVAILABLE_FOR_PURCHASE".into()
             }
             GuildFeature::RoleSubscriptionsEnabled => "ROLE_SUBSCRIPTIONS_ENABLED".into(),
+            GuildFeature::Soundboard => "SOUNDBOARD".into(),
             GuildFeature::TicketedEventsEnabled => "TICKETED_EVENTS_ENABLED".into(),
             GuildFeature::VanityUrl => "VANITY_URL".into(),
             GuildFeature::Verified => "VERIFIED".into(),
@@ -115,6 +126,7 @@ impl From<String> for GuildFeature {
         match value.as_str() {
             "ANIMATED_BANNER" => Self::AnimatedBanner,
             "ANIMATED_ICON" => Self::AnimatedIcon,
+            "APPLICATION_COMMAND_PERMISSIONS_V2" => Self::ApplicationCommandPermissionsV2,
             "AUTO_MODERATION" => Self::AutoModeration,
             "BANNER" => Self::Banner,
             "COMMUNITY" => Self::Community,
@@ -126,6 +138,7 @@ impl From<String> for GuildFeature {
             "INVITES_DISABLED" => Self::InvitesDisabled,
             "INVITE_SPLASH" => Self::InviteSplash,
             "MEMBER_VERIFICATION_GATE_ENABLED" => Self::MemberVerificationGateEnabled,
+            "MORE_SOUNDBOARD" => Self::MoreSoundboard,
             "MORE_STICKERS" => Self::MoreStickers,
             "NEWS" => Self::News,
             "PARTNERED" => Self::Partnered,
@@ -137,6 +150,7 @@ impl From<String> for GuildFeature {
                 GuildFeature::RoleSubscriptionsAvailableForPurchase
             }
             "ROLE_SUBSCRIPTIONS_ENABLED" => GuildFeature::RoleSubscriptionsEnabled,
+            "SOUNDBOARD" => Self::Soundboard,
             "TICKETED_EVENTS_ENABLED" => Self::TicketedEventsEnabled,
             "VANITY_URL" => Self::VanityUrl,
             "VERIFIED" => Self::Verified,
@@ -159,6 +173,10 @@ mod tests {
             &[Token::Str("ANIMATED_BANNER")],
         );
         serde_test::assert_tokens(&GuildFeature::AnimatedIcon, &[Token::Str("ANIMATED_ICON")]);
+        serde_test::assert_tokens(
+            &GuildFeature::ApplicationCommandPermissionsV2,
+            &[Token::Str("APPLICATION_COMMAND_PERMISSIONS_V2")],
+        );
         serde_test::assert_tokens(
             &GuildFeature::AutoModeration,
             &[Token::Str("AUTO_MODERATION")],
@@ -188,6 +206,10 @@ mod tests {
             &GuildFeature::MemberVerificationGateEnabled,
             &[Token::Str("MEMBER_VERIFICATION_GATE_ENABLED")],
         );
+        serde_test::assert_tokens(
+            &GuildFeature::MoreSoundboard,
+            &[Token::Str("MORE_SOUNDBOARD")],
+        );
         serde_test::assert_tokens(&GuildFeature::MoreStickers, &[Token::Str("MORE_STICKERS")]);
         serde_test::assert_tokens(&GuildFeature::News, &[Token::Str("NEWS")]);
         serde_test::assert_tokens(&GuildFeature::Partnered, &[Token::Str("PARTNERED")]);
@@ -208,6 +230,7 @@ mod tests {
             &GuildFeature::RoleSubscriptionsEnabled,
             &[Token::Str("ROLE_SUBSCRIPTIONS_ENABLED")],
         );
+        serde_test::assert_tokens(&GuildFeature::Soundboard, &[Token::Str("SOUNDBOARD")]);
         serde_test::assert_tokens(
             &GuildFeature::TicketedEventsEnabled,
             &[Token::Str("TICKETED_EVENTS_ENABLED")],
@@ -1,10 +1,21 @@
 use serde::{Deserialize, Serialize};
 
+/// Multi-factor authentication requirement of a guild for moderation actions.
+///
+/// When elevated, members must have MFA enabled on their account to perform
+/// actions requiring moderation permissions, such as banning or kicking
+/// members.
+///
+/// Refer to [Discord Docs/MFA Level] for additional information.
+///
+/// [Discord Docs/MFA Level]: https://discord.com/developers/docs/resources/guild#guild-object-mfa-level
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 #[non_exhaustive]
 #[serde(from = "u8", into = "u8")]
 pub enum MfaLevel {
+    /// Moderation actions don't require MFA.
     None,
+    /// Moderation actions require MFA.
     Elevated,
     /// Variant value is unknown to the library.
     Unknown(u8),
@@ -1,5 +1,5 @@
 use super::{
-    AfkTimeout, DefaultMessageNotificationLevel, Emoji, ExplicitContentFilter, GuildFeature,
+    AfkTimeout, DefaultMessageNotificationLevel, Emoji, ExplicitContentFilter, Guild, GuildFeature,
     MfaLevel, NSFWLevel, Permissions, PremiumTier, Role, SystemChannelFlags, VerificationLevel,
 };
 use crate::{
@@ -61,6 +61,48 @@ pub struct PartialGuild {
     pub widget_enabled: Option<bool>,
 }
 
+impl From<Guild> for PartialGuild {
+    fn from(guild: Guild) -> Self {
+        Self {
+            afk_channel_id: guild.afk_channel_id,
+            afk_timeout: guild.afk_timeout,
+            application_id: guild.application_id,
+            banner: guild.banner,
+            default_message_notifications: guild.default_message_notifications,
+            description: guild.description,
+            discovery_splash: guild.discovery_splash,
+            emojis: guild.emojis,
+            explicit_content_filter: guild.explicit_content_filter,
+            features: guild.features,
+            icon: guild.icon,
+            id: guild.id,
+            max_members: guild.max_members,
+            max_presences: guild.max_presences,
+            member_count: guild.member_count,
+            mfa_level: guild.mfa_level,
+            name: guild.name,
+            nsfw_level: guild.nsfw_level,
+            owner_id: guild.owner_id,
+            owner: guild.owner,
+            permissions: guild.permissions,
+            preferred_locale: guild.preferred_locale,
+            premium_progress_bar_enabled: guild.premium_progress_bar_enabled,
+            premium_subscription_count: guild.premium_subscription_count,
+            premium_tier: guild.premium_tier,
+            public_updates_channel_id: guild.public_updates_channel_id,
+            roles: guild.roles,
+            rules_channel_id: guild.rules_channel_id,
+            splash: guild.splash,
+            system_channel_flags: guild.system_channel_flags,
+            system_channel_id: guild.system_channel_id,
+            verification_level: guild.verification_level,
+            vanity_url_code: guild.vanity_url_code,
+            widget_channel_id: guild.widget_channel_id,
+            widget_enabled: guild.widget_enabled,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
@@ -6,7 +6,7 @@ use crate::{
 };
 use serde::{Deserialize, Serialize};
 
-use super::MemberFlags;
+use super::{Member, MemberFlags};
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct PartialMember {
@@ -32,12 +32,31 @@ pub struct PartialMember {
     pub user: Option<User>,
 }
 
+impl From<Member> for PartialMember {
+    fn from(member: Member) -> Self {
+        Self {
+            avatar: member.avatar,
+            communication_disabled_until: member.communication_disabled_until,
+            deaf: member.deaf,
+            flags: member.flags,
+            joined_at: member.joined_at,
+            mute: member.mute,
+            nick: member.nick,
+            permissions: None,
+            premium_since: member.premium_since,
+            roles: member.roles,
+            user: Some(member.user),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::PartialMember;
     use crate::{
-        guild::MemberFlags,
+        guild::{Member, MemberFlags},
         id::Id,
+        user::User,
         util::datetime::{Timestamp, TimestampParseError},
     };
     use serde_test::Token;
@@ -96,4 +115,50 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn from_member() {
+        let user = User {
+            accent_color: None,
+            avatar: None,
+            avatar_decoration: None,
+            avatar_decoration_data: None,
+            banner: None,
+            bot: false,
+            discriminator: 1,
+            email: None,
+            flags: None,
+            global_name: None,
+            id: Id::new(3),
+            locale: None,
+            mfa_enabled: None,
+            name: "twilight".to_owned(),
+            premium_type: None,
+            public_flags: None,
+            system: None,
+            verified: None,
+        };
+        let member = Member {
+            avatar: None,
+            communication_disabled_until: None,
+            deaf: true,
+            flags: MemberFlags::DID_REJOIN,
+            joined_at: None,
+            mute: false,
+            nick: Some("a nickname".to_owned()),
+            pending: false,
+            premium_since: None,
+            roles: vec![Id::new(1)],
+            user: user.clone(),
+        };
+
+        let partial = PartialMember::from(member);
+
+        assert!(partial.deaf);
+        assert_eq!(MemberFlags::DID_REJOIN, partial.flags);
+        assert_eq!(Some("a nickname"), partial.nick.as_deref());
+        assert!(partial.permissions.is_none());
+        assert_eq!(vec![Id::new(1)], partial.roles);
+        assert_eq!(Some(user), partial.user);
+    }
 }
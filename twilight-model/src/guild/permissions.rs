@@ -77,6 +77,8 @@ bitflags! {
         const USE_EXTERNAL_SOUNDS = 1 << 45;
         /// Allows sending voice messages
         const SEND_VOICE_MESSAGES = 1 << 46;
+        /// Allows setting the status of voice channels.
+        const SET_VOICE_CHANNEL_STATUS = 1 << 48;
         /// Allows sending polls.
         const SEND_POLLS = 1 << 49;
         /// Allows user-installed apps to send public responses. When
@@ -211,6 +213,7 @@ mod tests {
     const_assert_eq!(Permissions::USE_SOUNDBOARD.bits(), 1 << 42);
     const_assert_eq!(Permissions::USE_EXTERNAL_SOUNDS.bits(), 1 << 45);
     const_assert_eq!(Permissions::SEND_VOICE_MESSAGES.bits(), 1 << 46);
+    const_assert_eq!(Permissions::SET_VOICE_CHANNEL_STATUS.bits(), 1 << 48);
     const_assert_eq!(Permissions::SEND_POLLS.bits(), 1 << 49);
     const_assert_eq!(Permissions::USE_EXTERNAL_APPS.bits(), 1 << 50);
 
@@ -1,8 +1,15 @@
 //! Types for interacting with scheduled events.
 
+mod recurrence_rule;
 mod user;
 
-pub use self::user::GuildScheduledEventUser;
+pub use self::{
+    recurrence_rule::{
+        RecurrenceRule, RecurrenceRuleFrequency, RecurrenceRuleMonth, RecurrenceRuleNWeekday,
+        RecurrenceRuleWeekday,
+    },
+    user::GuildScheduledEventUser,
+};
 
 use crate::{
     id::{
@@ -66,6 +73,9 @@ pub struct GuildScheduledEvent {
     pub name: String,
     /// Privacy level of the event.
     pub privacy_level: PrivacyLevel,
+    /// Rule describing how often the event repeats, if it does.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recurrence_rule: Option<RecurrenceRule>,
     /// Scheduled end time of the event.
     ///
     /// Required on events of type [`EntityType::External`]. It also may be
@@ -223,6 +233,7 @@ mod tests {
             image: Some(COVER),
             name: "garfield dance party".into(),
             privacy_level: PrivacyLevel::GuildOnly,
+            recurrence_rule: None,
             scheduled_end_time: None,
             scheduled_start_time,
             status: Status::Completed,
@@ -0,0 +1,305 @@
+use crate::util::Timestamp;
+use serde::{Deserialize, Serialize};
+
+/// Rule describing how often a scheduled event repeats.
+///
+/// Rules are a subset of the [iCalendar RRULE] specification. See
+/// [Discord Docs/Recurrence Rule Object] for the combinations of fields that
+/// Discord accepts.
+///
+/// [iCalendar RRULE]: https://datatracker.ietf.org/doc/html/rfc5545
+/// [Discord Docs/Recurrence Rule Object]: https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-recurrence-rule-object
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct RecurrenceRule {
+    /// Months to repeat the event on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub by_month: Option<Vec<RecurrenceRuleMonth>>,
+    /// Days of the month to repeat the event on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub by_month_day: Option<Vec<u8>>,
+    /// Specific days within a specific week to repeat the event on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub by_n_weekday: Option<Vec<RecurrenceRuleNWeekday>>,
+    /// Days of the week to repeat the event on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub by_weekday: Option<Vec<RecurrenceRuleWeekday>>,
+    /// Days of the year to repeat the event on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub by_year_day: Option<Vec<u16>>,
+    /// Total number of times the event is allowed to recur before stopping.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub count: Option<u32>,
+    /// End of the recurrence interval.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end: Option<Timestamp>,
+    /// How often the event occurs.
+    pub frequency: RecurrenceRuleFrequency,
+    /// Spacing between events, defined by [`frequency`].
+    ///
+    /// For example, a [`RecurrenceRuleFrequency::Weekly`] frequency with an
+    /// interval of `2` is "every other week".
+    ///
+    /// [`frequency`]: Self::frequency
+    pub interval: u16,
+    /// Start of the recurrence interval.
+    pub start: Timestamp,
+}
+
+/// How often a [`RecurrenceRule`] repeats.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[non_exhaustive]
+#[serde(from = "u8", into = "u8")]
+pub enum RecurrenceRuleFrequency {
+    /// Event repeats yearly.
+    Yearly,
+    /// Event repeats monthly.
+    Monthly,
+    /// Event repeats weekly.
+    Weekly,
+    /// Event repeats daily.
+    Daily,
+    /// Variant value is unknown to the library.
+    Unknown(u8),
+}
+
+impl From<u8> for RecurrenceRuleFrequency {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::Yearly,
+            1 => Self::Monthly,
+            2 => Self::Weekly,
+            3 => Self::Daily,
+            unknown => Self::Unknown(unknown),
+        }
+    }
+}
+
+impl From<RecurrenceRuleFrequency> for u8 {
+    fn from(value: RecurrenceRuleFrequency) -> Self {
+        match value {
+            RecurrenceRuleFrequency::Yearly => 0,
+            RecurrenceRuleFrequency::Monthly => 1,
+            RecurrenceRuleFrequency::Weekly => 2,
+            RecurrenceRuleFrequency::Daily => 3,
+            RecurrenceRuleFrequency::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+/// Month of the year a [`RecurrenceRule`] repeats on.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[non_exhaustive]
+#[serde(from = "u8", into = "u8")]
+pub enum RecurrenceRuleMonth {
+    /// January.
+    January,
+    /// February.
+    February,
+    /// March.
+    March,
+    /// April.
+    April,
+    /// May.
+    May,
+    /// June.
+    June,
+    /// July.
+    July,
+    /// August.
+    August,
+    /// September.
+    September,
+    /// October.
+    October,
+    /// November.
+    November,
+    /// December.
+    December,
+    /// Variant value is unknown to the library.
+    Unknown(u8),
+}
+
+impl From<u8> for RecurrenceRuleMonth {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => Self::January,
+            2 => Self::February,
+            3 => Self::March,
+            4 => Self::April,
+            5 => Self::May,
+            6 => Self::June,
+            7 => Self::July,
+            8 => Self::August,
+            9 => Self::September,
+            10 => Self::October,
+            11 => Self::November,
+            12 => Self::December,
+            unknown => Self::Unknown(unknown),
+        }
+    }
+}
+
+impl From<RecurrenceRuleMonth> for u8 {
+    fn from(value: RecurrenceRuleMonth) -> Self {
+        match value {
+            RecurrenceRuleMonth::January => 1,
+            RecurrenceRuleMonth::February => 2,
+            RecurrenceRuleMonth::March => 3,
+            RecurrenceRuleMonth::April => 4,
+            RecurrenceRuleMonth::May => 5,
+            RecurrenceRuleMonth::June => 6,
+            RecurrenceRuleMonth::July => 7,
+            RecurrenceRuleMonth::August => 8,
+            RecurrenceRuleMonth::September => 9,
+            RecurrenceRuleMonth::October => 10,
+            RecurrenceRuleMonth::November => 11,
+            RecurrenceRuleMonth::December => 12,
+            RecurrenceRuleMonth::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+/// Specific day within a specific week a [`RecurrenceRule`] repeats on.
+///
+/// For example, the second Tuesday of the month is represented by an `n` of
+/// `2` and a `day` of [`RecurrenceRuleWeekday::Tuesday`].
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct RecurrenceRuleNWeekday {
+    /// Day within the week.
+    pub day: RecurrenceRuleWeekday,
+    /// Week to repeat on, between 1 and 5.
+    pub n: u8,
+}
+
+/// Day of the week a [`RecurrenceRule`] repeats on.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[non_exhaustive]
+#[serde(from = "u8", into = "u8")]
+pub enum RecurrenceRuleWeekday {
+    /// Monday.
+    Monday,
+    /// Tuesday.
+    Tuesday,
+    /// Wednesday.
+    Wednesday,
+    /// Thursday.
+    Thursday,
+    /// Friday.
+    Friday,
+    /// Saturday.
+    Saturday,
+    /// Sunday.
+    Sunday,
+    /// Variant value is unknown to the library.
+    Unknown(u8),
+}
+
+impl From<u8> for RecurrenceRuleWeekday {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::Monday,
+            1 => Self::Tuesday,
+            2 => Self::Wednesday,
+            3 => Self::Thursday,
+            4 => Self::Friday,
+            5 => Self::Saturday,
+            6 => Self::Sunday,
+            unknown => Self::Unknown(unknown),
+        }
+    }
+}
+
+impl From<RecurrenceRuleWeekday> for u8 {
+    fn from(value: RecurrenceRuleWeekday) -> Self {
+        match value {
+            RecurrenceRuleWeekday::Monday => 0,
+            RecurrenceRuleWeekday::Tuesday => 1,
+            RecurrenceRuleWeekday::Wednesday => 2,
+            RecurrenceRuleWeekday::Thursday => 3,
+            RecurrenceRuleWeekday::Friday => 4,
+            RecurrenceRuleWeekday::Saturday => 5,
+            RecurrenceRuleWeekday::Sunday => 6,
+            RecurrenceRuleWeekday::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        RecurrenceRule, RecurrenceRuleFrequency, RecurrenceRuleMonth, RecurrenceRuleNWeekday,
+        RecurrenceRuleWeekday,
+    };
+    use crate::util::Timestamp;
+    use serde_test::Token;
+    use std::error::Error;
+
+    #[test]
+    fn recurrence_rule() -> Result<(), Box<dyn Error>> {
+        let value = RecurrenceRule {
+            by_month: None,
+            by_month_day: None,
+            by_n_weekday: Some(Vec::from([RecurrenceRuleNWeekday {
+                day: RecurrenceRuleWeekday::Tuesday,
+                n: 2,
+            }])),
+            by_weekday: None,
+            by_year_day: None,
+            count: None,
+            end: None,
+            frequency: RecurrenceRuleFrequency::Monthly,
+            interval: 1,
+            start: Timestamp::parse("2024-01-09T18:00:00.000000+00:00")?,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "RecurrenceRule",
+                    len: 4,
+                },
+                Token::Str("by_n_weekday"),
+                Token::Some,
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "RecurrenceRuleNWeekday",
+                    len: 2,
+                },
+                Token::Str("day"),
+                Token::U8(1),
+                Token::Str("n"),
+                Token::U8(2),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::Str("frequency"),
+                Token::U8(1),
+                Token::Str("interval"),
+                Token::U16(1),
+                Token::Str("start"),
+                Token::Str("2024-01-09T18:00:00.000000+00:00"),
+                Token::StructEnd,
+            ],
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn variants() {
+        assert_eq!(
+            RecurrenceRuleFrequency::Daily,
+            RecurrenceRuleFrequency::from(3)
+        );
+        assert_eq!(RecurrenceRuleMonth::December, RecurrenceRuleMonth::from(12));
+        assert_eq!(
+            RecurrenceRuleMonth::Unknown(0),
+            RecurrenceRuleMonth::from(0)
+        );
+        assert_eq!(
+            RecurrenceRuleWeekday::Sunday,
+            RecurrenceRuleWeekday::from(6)
+        );
+        assert_eq!(7, u8::from(RecurrenceRuleWeekday::Unknown(7)));
+    }
+}
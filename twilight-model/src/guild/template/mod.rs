@@ -239,6 +239,7 @@ mod tests {
                         rate_limit_per_user: None,
                         recipients: None,
                         rtc_region: None,
+                        status: None,
                         thread_metadata: None,
                         topic: None,
                         user_limit: None,
@@ -289,6 +290,7 @@ mod tests {
                         rate_limit_per_user: Some(0),
                         recipients: None,
                         rtc_region: None,
+                        status: None,
                         thread_metadata: None,
                         topic: None,
                         user_limit: None,
@@ -326,6 +328,7 @@ mod tests {
                         rate_limit_per_user: None,
                         recipients: None,
                         rtc_region: None,
+                        status: None,
                         thread_metadata: None,
                         topic: None,
                         user_limit: None,
@@ -363,6 +366,7 @@ mod tests {
                         rate_limit_per_user: None,
                         recipients: None,
                         rtc_region: None,
+                        status: None,
                         thread_metadata: None,
                         topic: None,
                         user_limit: Some(0),
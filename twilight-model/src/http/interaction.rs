@@ -3,8 +3,15 @@
 
 use super::attachment::Attachment;
 use crate::{
-    application::command::CommandOptionChoice,
-    channel::message::{AllowedMentions, Component, Embed, MessageFlags},
+    application::{command::CommandOptionChoice, interaction::InteractionType},
+    channel::{
+        message::{AllowedMentions, Component, Embed, MessageFlags},
+        Message,
+    },
+    id::{
+        marker::{InteractionMarker, MessageMarker},
+        Id,
+    },
 };
 use serde::{Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
@@ -109,16 +116,99 @@ pub enum InteractionResponseType {
     /// instead.
     #[deprecated(note = "Deprecated by Discord in favor of Premium Buttons")]
     PremiumRequired = 10,
+    /// Launch the activity associated with the application.
+    ///
+    /// Only available for applications with activities enabled.
+    LaunchActivity = 12,
+}
+
+/// Response to creating an interaction response, returned when requested.
+///
+/// See [Discord Docs/Interaction Callback Response Object].
+///
+/// [Discord Docs/Interaction Callback Response Object]: https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-callback-interaction-callback-response-object
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct InteractionCallbackResponse {
+    /// Interaction that was responded to.
+    pub interaction: InteractionCallback,
+    /// Resource created by the response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource: Option<InteractionCallbackResource>,
+}
+
+/// Interaction associated with an [`InteractionCallbackResponse`].
+///
+/// See [Discord Docs/Interaction Callback Object].
+///
+/// [Discord Docs/Interaction Callback Object]: https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-callback-interaction-callback-object
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct InteractionCallback {
+    /// Instance ID of the activity if one was launched or joined.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_instance_id: Option<String>,
+    /// ID of the interaction.
+    pub id: Id<InteractionMarker>,
+    /// Type of the interaction.
+    #[serde(rename = "type")]
+    pub kind: InteractionType,
+    /// ID of the message that was created by the interaction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_message_id: Option<Id<MessageMarker>>,
+    /// Whether the message is ephemeral.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_message_ephemeral: Option<bool>,
+    /// Whether the message is in a loading state.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_message_loading: Option<bool>,
+}
+
+/// Resource created by an interaction response.
+///
+/// See [Discord Docs/Interaction Callback Resource Object].
+///
+/// [Discord Docs/Interaction Callback Resource Object]: https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-callback-interaction-callback-resource-object
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct InteractionCallbackResource {
+    /// Activity launched by the response.
+    ///
+    /// Only present for [`InteractionResponseType::LaunchActivity`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_instance: Option<InteractionCallbackActivityInstance>,
+    /// Type of the response.
+    #[serde(rename = "type")]
+    pub kind: InteractionResponseType,
+    /// Message created by the response.
+    ///
+    /// Only present for [`InteractionResponseType::ChannelMessageWithSource`]
+    /// and [`InteractionResponseType::UpdateMessage`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<Message>,
+}
+
+/// Activity instance launched by an interaction response.
+///
+/// See [Discord Docs/Interaction Callback Activity Instance Resource].
+///
+/// [Discord Docs/Interaction Callback Activity Instance Resource]: https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-callback-interaction-callback-activity-instance-resource
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct InteractionCallbackActivityInstance {
+    /// Instance ID of the activity.
+    pub id: String,
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{
+        application::interaction::InteractionType,
         channel::message::MessageFlags,
         http::{
             attachment::Attachment,
-            interaction::{InteractionResponse, InteractionResponseData, InteractionResponseType},
+            interaction::{
+                InteractionCallback, InteractionCallbackResponse, InteractionResponse,
+                InteractionResponseData, InteractionResponseType,
+            },
         },
+        id::Id,
     };
     use serde::{Deserialize, Serialize};
     use serde_test::Token;
@@ -134,6 +224,23 @@ mod tests {
         flags,
         tts
     );
+    assert_fields!(
+        InteractionCallback: activity_instance_id,
+        id,
+        kind,
+        response_message_id,
+        response_message_ephemeral,
+        response_message_loading
+    );
+    assert_impl_all!(
+        InteractionCallbackResponse: Clone,
+        Debug,
+        Deserialize<'static>,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
     assert_impl_all!(
         InteractionResponseData: Clone,
         Debug,
@@ -237,4 +344,51 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn interaction_callback_response() {
+        let value = InteractionCallbackResponse {
+            interaction: InteractionCallback {
+                activity_instance_id: None,
+                id: Id::new(1),
+                kind: InteractionType::ApplicationCommand,
+                response_message_id: Some(Id::new(2)),
+                response_message_ephemeral: Some(false),
+                response_message_loading: Some(true),
+            },
+            resource: None,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "InteractionCallbackResponse",
+                    len: 1,
+                },
+                Token::Str("interaction"),
+                Token::Struct {
+                    name: "InteractionCallback",
+                    len: 5,
+                },
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("1"),
+                Token::Str("type"),
+                Token::U8(InteractionType::ApplicationCommand as u8),
+                Token::Str("response_message_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "Id" },
+                Token::Str("2"),
+                Token::Str("response_message_ephemeral"),
+                Token::Some,
+                Token::Bool(false),
+                Token::Str("response_message_loading"),
+                Token::Some,
+                Token::Bool(true),
+                Token::StructEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
 }
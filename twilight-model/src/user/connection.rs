@@ -1,22 +1,39 @@
 use crate::{guild::GuildIntegration, user::ConnectionVisibility};
 use serde::{Deserialize, Serialize};
 
+/// Account of a third party service connected to a user.
+///
+/// Retrieved via the `connections` `OAuth2` scope.
+///
+/// See [Discord Docs/Connection Object].
+///
+/// [Discord Docs/Connection Object]: https://discord.com/developers/docs/resources/user#connection-object
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct Connection {
+    /// Whether friend sync is enabled for this connection.
     pub friend_sync: bool,
+    /// ID of the account on the third party service.
     pub id: String,
+    /// Server integrations of the connection.
     #[serde(default)]
     pub integrations: Vec<GuildIntegration>,
+    /// Third party service of the connection, such as `github` or `twitch`.
     #[serde(rename = "type")]
     pub kind: String,
+    /// Username of the account on the third party service.
     pub name: String,
+    /// Whether the connection has been revoked.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub revoked: Option<bool>,
+    /// Whether activities related to this connection are shown in the
+    /// user's presence.
     pub show_activity: bool,
     /// Whether this connection has a corresponding third party OAuth2 token.
     pub two_way_link: bool,
+    /// Whether the connection is verified.
     pub verified: bool,
+    /// Who can see the connection.
     pub visibility: ConnectionVisibility,
 }
 
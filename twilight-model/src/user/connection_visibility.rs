@@ -1,11 +1,17 @@
 use serde::{Deserialize, Serialize};
 
+/// Who can see a [`Connection`].
+///
+/// [`Connection`]: super::Connection
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 #[non_exhaustive]
 #[serde(from = "u8", into = "u8")]
 pub enum ConnectionVisibility {
+    /// Only the user can see the connection.
     None,
+    /// Everyone can see the connection.
     Everyone,
+    /// Variant value is unknown to the library.
     Unknown(u8),
 }
 
@@ -58,6 +58,10 @@ const MICROSECONDS_PER_SECOND: i64 = 1_000_000;
 /// Number of nanoseconds in a microsecond.
 const NANOSECONDS_PER_MICROSECOND: i64 = 1_000;
 
+/// Number of nanoseconds in a second.
+#[cfg(feature = "chrono")]
+const NANOSECONDS_PER_SECOND: i64 = 1_000_000_000;
+
 /// Representation of a Unix timestamp.
 ///
 /// # Display
@@ -90,7 +94,12 @@ impl Timestamp {
     pub fn from_micros(unix_microseconds: i64) -> Result<Self, TimestampParseError> {
         let nanoseconds = i128::from(unix_microseconds) * i128::from(NANOSECONDS_PER_MICROSECOND);
 
-        OffsetDateTime::from_unix_timestamp_nanos(nanoseconds)
+        Self::from_nanos(nanoseconds)
+    }
+
+    /// Create a timestamp from a Unix timestamp with nanoseconds precision.
+    fn from_nanos(unix_nanoseconds: i128) -> Result<Self, TimestampParseError> {
+        OffsetDateTime::from_unix_timestamp_nanos(unix_nanoseconds)
             .map(|offset| Self(PrimitiveDateTime::new(offset.date(), offset.time())))
             .map_err(TimestampParseError::from_component_range)
     }
@@ -289,6 +298,60 @@ impl TryFrom<&'_ str> for Timestamp {
     }
 }
 
+#[cfg(feature = "chrono")]
+impl From<Timestamp> for chrono::DateTime<chrono::Utc> {
+    fn from(timestamp: Timestamp) -> Self {
+        let utc = timestamp.0.assume_utc();
+
+        Self::from_timestamp(utc.unix_timestamp(), utc.nanosecond())
+            .expect("timestamps are within chrono's range")
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<chrono::DateTime<chrono::Utc>> for Timestamp {
+    type Error = TimestampParseError;
+
+    /// Convert a chrono datetime into a timestamp.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TimestampParseErrorType::Range`] error type if the datetime
+    /// is outside of the range of a timestamp.
+    ///
+    /// [`TimestampParseErrorType::Range`]: self::error::TimestampParseErrorType::Range
+    fn try_from(datetime: chrono::DateTime<chrono::Utc>) -> Result<Self, Self::Error> {
+        let nanoseconds = i128::from(datetime.timestamp()) * i128::from(NANOSECONDS_PER_SECOND)
+            + i128::from(datetime.timestamp_subsec_nanos());
+
+        Self::from_nanos(nanoseconds)
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<Timestamp> for OffsetDateTime {
+    fn from(timestamp: Timestamp) -> Self {
+        timestamp.0.assume_utc()
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<OffsetDateTime> for Timestamp {
+    type Error = TimestampParseError;
+
+    /// Convert a datetime into a timestamp, converting it to UTC.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TimestampParseErrorType::Range`] error type if the datetime
+    /// is outside of the range of a timestamp once converted to UTC.
+    ///
+    /// [`TimestampParseErrorType::Range`]: self::error::TimestampParseErrorType::Range
+    fn try_from(datetime: OffsetDateTime) -> Result<Self, Self::Error> {
+        Self::from_nanos(datetime.unix_timestamp_nanos())
+    }
+}
+
 /// Parse an input ISO 8601 timestamp into a Unix timestamp with microseconds.
 ///
 /// Input in the format of "2021-01-01T01:01:01.010000+00:00" is acceptable.
@@ -374,6 +437,36 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono() -> Result<(), TimestampParseError> {
+        use chrono::{DateTime, Utc};
+
+        let timestamp = Timestamp::from_str("2021-08-10T11:16:37.123456+00:00")?;
+        let datetime = DateTime::<Utc>::from(timestamp);
+        assert_eq!(1_628_594_197_123_456, datetime.timestamp_micros());
+        assert_eq!(timestamp, Timestamp::try_from(datetime)?);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time() -> Result<(), TimestampParseError> {
+        use time::UtcOffset;
+
+        let timestamp = Timestamp::from_str("2021-08-10T11:16:37.123456+00:00")?;
+        let datetime = OffsetDateTime::from(timestamp);
+        assert_eq!(UtcOffset::UTC, datetime.offset());
+        assert_eq!(1_628_594_197, datetime.unix_timestamp());
+        assert_eq!(timestamp, Timestamp::try_from(datetime)?);
+
+        let offset = UtcOffset::from_hms(2, 0, 0).unwrap();
+        assert_eq!(timestamp, Timestamp::try_from(datetime.to_offset(offset))?);
+
+        Ok(())
+    }
+
     /// Test the boundaries of valid ISO 8601 datetime boundaries.
     #[test]
     fn parse_iso8601_boundaries() -> Result<(), TimestampParseError> {
@@ -1162,6 +1162,7 @@ mod tests {
         Interaction {
             app_permissions: Some(Permissions::SEND_MESSAGES),
             application_id: Id::new(1),
+            attachment_size_limit: None,
             authorizing_integration_owners: ApplicationIntegrationMap {
                 guild: None,
                 user: None,
@@ -1182,6 +1183,7 @@ mod tests {
                 rate_limit_per_user: None,
                 recipients: None,
                 rtc_region: None,
+                status: None,
                 topic: None,
                 user_limit: None,
                 application_id: None,
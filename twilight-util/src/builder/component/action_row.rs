@@ -0,0 +1,117 @@
+//! Create action rows.
+
+use twilight_model::channel::message::component::{ActionRow, Component};
+use twilight_validate::component::{action_row as validate_action_row, ComponentValidationError};
+
+/// Create an [`ActionRow`] with a builder.
+///
+/// Refer to [`ACTION_ROW_COMPONENT_COUNT`] for the maximum number of
+/// components that can be in an action row.
+///
+/// [`ACTION_ROW_COMPONENT_COUNT`]: twilight_validate::component::ACTION_ROW_COMPONENT_COUNT
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[must_use = "must be built into an action row"]
+pub struct ActionRowBuilder(ActionRow);
+
+impl ActionRowBuilder {
+    /// Create a new action row builder.
+    pub const fn new() -> Self {
+        Self(ActionRow {
+            components: Vec::new(),
+        })
+    }
+
+    /// Build into an action row.
+    #[allow(clippy::missing_const_for_fn)]
+    #[must_use = "should be used as part of a message or modal"]
+    pub fn build(self) -> ActionRow {
+        self.0
+    }
+
+    /// Ensure the action row is valid.
+    ///
+    /// # Errors
+    ///
+    /// Refer to the errors section of
+    /// [`twilight_validate::component::action_row`] for possible errors.
+    pub fn validate(self) -> Result<Self, ComponentValidationError> {
+        validate_action_row(&self.0)?;
+
+        Ok(self)
+    }
+
+    /// Add a component to the action row.
+    ///
+    /// Action rows can contain [`Button`]s, a single [`SelectMenu`] or a
+    /// single [`TextInput`].
+    ///
+    /// [`Button`]: twilight_model::channel::message::component::Button
+    /// [`SelectMenu`]: twilight_model::channel::message::component::SelectMenu
+    /// [`TextInput`]: twilight_model::channel::message::component::TextInput
+    pub fn component(mut self, component: impl Into<Component>) -> Self {
+        self.0.components.push(component.into());
+
+        self
+    }
+}
+
+impl Default for ActionRowBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<ActionRowBuilder> for ActionRow {
+    /// Convert an action row builder into an action row.
+    ///
+    /// This is equivalent to calling [`ActionRowBuilder::build`].
+    fn from(builder: ActionRowBuilder) -> Self {
+        builder.build()
+    }
+}
+
+impl From<ActionRowBuilder> for Component {
+    /// Convert an action row builder into a component.
+    fn from(builder: ActionRowBuilder) -> Self {
+        Self::ActionRow(builder.build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::channel::message::component::{Button, ButtonStyle};
+
+    assert_impl_all!(ActionRowBuilder: Clone, Debug, Default, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(ActionRow: From<ActionRowBuilder>);
+    assert_impl_all!(Component: From<ActionRowBuilder>);
+
+    #[test]
+    fn builder() {
+        let button = Button {
+            custom_id: Some("a".to_owned()),
+            disabled: false,
+            emoji: None,
+            label: Some("b".to_owned()),
+            style: ButtonStyle::Primary,
+            url: None,
+            sku_id: None,
+        };
+        let expected = ActionRow {
+            components: Vec::from([Component::Button(button.clone())]),
+        };
+        let actual = ActionRowBuilder::new().component(button).build();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn validate() {
+        assert!(ActionRowBuilder::new()
+            .component(ActionRowBuilder::new())
+            .validate()
+            .is_err());
+    }
+}
@@ -0,0 +1,175 @@
+//! Create buttons.
+
+use twilight_model::{
+    channel::message::{
+        component::{Button, ButtonStyle, Component},
+        EmojiReactionType,
+    },
+    id::{marker::SkuMarker, Id},
+};
+use twilight_validate::component::{button as validate_button, ComponentValidationError};
+
+/// Create a [`Button`] with a builder.
+///
+/// This can be passed into [`ActionRowBuilder::component`].
+///
+/// [`ActionRowBuilder::component`]: super::ActionRowBuilder::component
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[must_use = "must be built into a button"]
+pub struct ButtonBuilder(Button);
+
+impl ButtonBuilder {
+    /// Create a new button builder.
+    ///
+    /// Depending on the style, a [custom ID], [URL] or [SKU ID] must also be
+    /// set.
+    ///
+    /// [custom ID]: Self::custom_id
+    /// [SKU ID]: Self::sku_id
+    /// [URL]: Self::url
+    pub const fn new(style: ButtonStyle) -> Self {
+        Self(Button {
+            custom_id: None,
+            disabled: false,
+            emoji: None,
+            label: None,
+            style,
+            url: None,
+            sku_id: None,
+        })
+    }
+
+    /// Build into a button.
+    #[allow(clippy::missing_const_for_fn)]
+    #[must_use = "should be used as part of an action row"]
+    pub fn build(self) -> Button {
+        self.0
+    }
+
+    /// Ensure the button is valid.
+    ///
+    /// # Errors
+    ///
+    /// Refer to the errors section of
+    /// [`twilight_validate::component::button`] for possible errors.
+    pub fn validate(self) -> Result<Self, ComponentValidationError> {
+        validate_button(&self.0)?;
+
+        Ok(self)
+    }
+
+    /// Set the custom ID of the button.
+    ///
+    /// Refer to [`COMPONENT_CUSTOM_ID_LENGTH`] for the maximum number of
+    /// characters that can be in a custom ID.
+    ///
+    /// [`COMPONENT_CUSTOM_ID_LENGTH`]: twilight_validate::component::COMPONENT_CUSTOM_ID_LENGTH
+    pub fn custom_id(mut self, custom_id: impl Into<String>) -> Self {
+        self.0.custom_id = Some(custom_id.into());
+
+        self
+    }
+
+    /// Set whether the button is disabled.
+    ///
+    /// Defaults to `false`.
+    pub const fn disabled(mut self, disabled: bool) -> Self {
+        self.0.disabled = disabled;
+
+        self
+    }
+
+    /// Set the emoji of the button.
+    pub fn emoji(mut self, emoji: EmojiReactionType) -> Self {
+        self.0.emoji = Some(emoji);
+
+        self
+    }
+
+    /// Set the label of the button.
+    ///
+    /// Refer to [`COMPONENT_BUTTON_LABEL_LENGTH`] for the maximum number of
+    /// characters that can be in a label.
+    ///
+    /// [`COMPONENT_BUTTON_LABEL_LENGTH`]: twilight_validate::component::COMPONENT_BUTTON_LABEL_LENGTH
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.0.label = Some(label.into());
+
+        self
+    }
+
+    /// Set the SKU ID of a [`ButtonStyle::Premium`] button.
+    pub const fn sku_id(mut self, sku_id: Id<SkuMarker>) -> Self {
+        self.0.sku_id = Some(sku_id);
+
+        self
+    }
+
+    /// Set the URL of a [`ButtonStyle::Link`] button.
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.0.url = Some(url.into());
+
+        self
+    }
+}
+
+impl From<ButtonBuilder> for Button {
+    /// Convert a button builder into a button.
+    ///
+    /// This is equivalent to calling [`ButtonBuilder::build`].
+    fn from(builder: ButtonBuilder) -> Self {
+        builder.build()
+    }
+}
+
+impl From<ButtonBuilder> for Component {
+    /// Convert a button builder into a component.
+    fn from(builder: ButtonBuilder) -> Self {
+        Self::Button(builder.build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(ButtonBuilder: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(Button: From<ButtonBuilder>);
+    assert_impl_all!(Component: From<ButtonBuilder>);
+
+    #[test]
+    fn builder() {
+        let expected = Button {
+            custom_id: None,
+            disabled: true,
+            emoji: Some(EmojiReactionType::Unicode {
+                name: "🦄".to_owned(),
+            }),
+            label: Some("twilight".to_owned()),
+            style: ButtonStyle::Link,
+            url: Some("https://twilight.rs".to_owned()),
+            sku_id: None,
+        };
+        let actual = ButtonBuilder::new(ButtonStyle::Link)
+            .disabled(true)
+            .emoji(EmojiReactionType::Unicode {
+                name: "🦄".to_owned(),
+            })
+            .label("twilight")
+            .url("https://twilight.rs")
+            .build();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn validate() {
+        assert!(ButtonBuilder::new(ButtonStyle::Primary).validate().is_err());
+        assert!(ButtonBuilder::new(ButtonStyle::Primary)
+            .custom_id("a")
+            .validate()
+            .is_ok());
+    }
+}
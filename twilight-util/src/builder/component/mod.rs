@@ -0,0 +1,38 @@
+//! Create message [`Component`]s with builders.
+//!
+//! # Examples
+//!
+//! Build an action row containing a button and a link button:
+//!
+//! ```
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use twilight_model::channel::message::component::ButtonStyle;
+//! use twilight_util::builder::component::{ActionRowBuilder, ButtonBuilder};
+//!
+//! let component = ActionRowBuilder::new()
+//!     .component(
+//!         ButtonBuilder::new(ButtonStyle::Primary)
+//!             .custom_id("twilight")
+//!             .label("Best pony"),
+//!     )
+//!     .component(
+//!         ButtonBuilder::new(ButtonStyle::Link)
+//!             .label("Source")
+//!             .url("https://github.com/twilight-rs/twilight"),
+//!     )
+//!     .validate()?
+//!     .build();
+//! # Ok(()) }
+//! ```
+//!
+//! [`Component`]: twilight_model::channel::message::Component
+
+mod action_row;
+mod button;
+mod select_menu;
+
+pub use self::{
+    action_row::ActionRowBuilder,
+    button::ButtonBuilder,
+    select_menu::{SelectMenuBuilder, SelectMenuOptionBuilder},
+};
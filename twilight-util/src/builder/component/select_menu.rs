@@ -0,0 +1,323 @@
+//! Create select menus and their options.
+
+use twilight_model::channel::{
+    message::{
+        component::{Component, SelectDefaultValue, SelectMenu, SelectMenuOption, SelectMenuType},
+        EmojiReactionType,
+    },
+    ChannelType,
+};
+use twilight_validate::component::{select_menu as validate_select_menu, ComponentValidationError};
+
+/// Create a [`SelectMenu`] with a builder.
+///
+/// This can be passed into [`ActionRowBuilder::component`].
+///
+/// # Examples
+///
+/// Build a text select menu with two options:
+///
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use twilight_model::channel::message::component::SelectMenuType;
+/// use twilight_util::builder::component::{SelectMenuBuilder, SelectMenuOptionBuilder};
+///
+/// let select_menu = SelectMenuBuilder::new("pony", SelectMenuType::Text)
+///     .option(SelectMenuOptionBuilder::new("Twilight Sparkle", "twilight"))
+///     .option(SelectMenuOptionBuilder::new("Rarity", "rarity").default(true))
+///     .placeholder("Choose the best pony")
+///     .validate()?
+///     .build();
+/// # Ok(()) }
+/// ```
+///
+/// [`ActionRowBuilder::component`]: super::ActionRowBuilder::component
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[must_use = "must be built into a select menu"]
+pub struct SelectMenuBuilder(SelectMenu);
+
+impl SelectMenuBuilder {
+    /// Create a new select menu builder.
+    ///
+    /// Refer to [`COMPONENT_CUSTOM_ID_LENGTH`] for the maximum number of
+    /// characters that can be in a custom ID.
+    ///
+    /// [`COMPONENT_CUSTOM_ID_LENGTH`]: twilight_validate::component::COMPONENT_CUSTOM_ID_LENGTH
+    pub fn new(custom_id: impl Into<String>, kind: SelectMenuType) -> Self {
+        Self(SelectMenu {
+            channel_types: None,
+            custom_id: custom_id.into(),
+            default_values: None,
+            disabled: false,
+            kind,
+            max_values: None,
+            min_values: None,
+            options: None,
+            placeholder: None,
+        })
+    }
+
+    /// Build into a select menu.
+    #[allow(clippy::missing_const_for_fn)]
+    #[must_use = "should be used as part of an action row"]
+    pub fn build(self) -> SelectMenu {
+        self.0
+    }
+
+    /// Ensure the select menu is valid.
+    ///
+    /// # Errors
+    ///
+    /// Refer to the errors section of
+    /// [`twilight_validate::component::select_menu`] for possible errors.
+    pub fn validate(self) -> Result<Self, ComponentValidationError> {
+        validate_select_menu(&self.0)?;
+
+        Ok(self)
+    }
+
+    /// Set the channel types of a [`SelectMenuType::Channel`] select menu.
+    pub fn channel_types(mut self, channel_types: Vec<ChannelType>) -> Self {
+        self.0.channel_types = Some(channel_types);
+
+        self
+    }
+
+    /// Add a default value to an auto-populated select menu.
+    pub fn default_value(mut self, default_value: SelectDefaultValue) -> Self {
+        self.0
+            .default_values
+            .get_or_insert_with(Vec::new)
+            .push(default_value);
+
+        self
+    }
+
+    /// Set whether the select menu is disabled.
+    ///
+    /// Defaults to `false`.
+    pub const fn disabled(mut self, disabled: bool) -> Self {
+        self.0.disabled = disabled;
+
+        self
+    }
+
+    /// Set the maximum number of options that may be chosen.
+    ///
+    /// Refer to [`SELECT_MAXIMUM_VALUES_LIMIT`] for the maximum value.
+    ///
+    /// [`SELECT_MAXIMUM_VALUES_LIMIT`]: twilight_validate::component::SELECT_MAXIMUM_VALUES_LIMIT
+    pub const fn max_values(mut self, max_values: u8) -> Self {
+        self.0.max_values = Some(max_values);
+
+        self
+    }
+
+    /// Set the minimum number of options that must be chosen.
+    ///
+    /// Refer to [`SELECT_MINIMUM_VALUES_LIMIT`] for the maximum value.
+    ///
+    /// [`SELECT_MINIMUM_VALUES_LIMIT`]: twilight_validate::component::SELECT_MINIMUM_VALUES_LIMIT
+    pub const fn min_values(mut self, min_values: u8) -> Self {
+        self.0.min_values = Some(min_values);
+
+        self
+    }
+
+    /// Add an option to a [`SelectMenuType::Text`] select menu.
+    ///
+    /// Refer to [`SELECT_OPTION_COUNT`] for the maximum number of options.
+    ///
+    /// [`SELECT_OPTION_COUNT`]: twilight_validate::component::SELECT_OPTION_COUNT
+    pub fn option(mut self, option: impl Into<SelectMenuOption>) -> Self {
+        self.0
+            .options
+            .get_or_insert_with(Vec::new)
+            .push(option.into());
+
+        self
+    }
+
+    /// Set the placeholder text shown when no option is selected.
+    ///
+    /// Refer to [`SELECT_PLACEHOLDER_LENGTH`] for the maximum number of
+    /// characters that can be in a placeholder.
+    ///
+    /// [`SELECT_PLACEHOLDER_LENGTH`]: twilight_validate::component::SELECT_PLACEHOLDER_LENGTH
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.0.placeholder = Some(placeholder.into());
+
+        self
+    }
+}
+
+impl From<SelectMenuBuilder> for SelectMenu {
+    /// Convert a select menu builder into a select menu.
+    ///
+    /// This is equivalent to calling [`SelectMenuBuilder::build`].
+    fn from(builder: SelectMenuBuilder) -> Self {
+        builder.build()
+    }
+}
+
+impl From<SelectMenuBuilder> for Component {
+    /// Convert a select menu builder into a component.
+    fn from(builder: SelectMenuBuilder) -> Self {
+        Self::SelectMenu(builder.build())
+    }
+}
+
+/// Create a [`SelectMenuOption`] with a builder.
+///
+/// This can be passed into [`SelectMenuBuilder::option`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[must_use = "must be built into a select menu option"]
+pub struct SelectMenuOptionBuilder(SelectMenuOption);
+
+impl SelectMenuOptionBuilder {
+    /// Create a new select menu option builder.
+    ///
+    /// Refer to [`SELECT_OPTION_LABEL_LENGTH`] and
+    /// [`SELECT_OPTION_VALUE_LENGTH`] for the maximum number of characters
+    /// that can be in a label and value.
+    ///
+    /// [`SELECT_OPTION_LABEL_LENGTH`]: twilight_validate::component::SELECT_OPTION_LABEL_LENGTH
+    /// [`SELECT_OPTION_VALUE_LENGTH`]: twilight_validate::component::SELECT_OPTION_VALUE_LENGTH
+    pub fn new(label: impl Into<String>, value: impl Into<String>) -> Self {
+        Self(SelectMenuOption {
+            default: false,
+            description: None,
+            emoji: None,
+            label: label.into(),
+            value: value.into(),
+        })
+    }
+
+    /// Build into a select menu option.
+    #[allow(clippy::missing_const_for_fn)]
+    #[must_use = "should be used as part of a select menu builder"]
+    pub fn build(self) -> SelectMenuOption {
+        self.0
+    }
+
+    /// Set whether the option is selected by default.
+    ///
+    /// Defaults to `false`.
+    pub const fn default(mut self, default: bool) -> Self {
+        self.0.default = default;
+
+        self
+    }
+
+    /// Set the description of the option.
+    ///
+    /// Refer to [`SELECT_OPTION_DESCRIPTION_LENGTH`] for the maximum number of
+    /// characters that can be in a description.
+    ///
+    /// [`SELECT_OPTION_DESCRIPTION_LENGTH`]: twilight_validate::component::SELECT_OPTION_DESCRIPTION_LENGTH
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.0.description = Some(description.into());
+
+        self
+    }
+
+    /// Set the emoji of the option.
+    pub fn emoji(mut self, emoji: EmojiReactionType) -> Self {
+        self.0.emoji = Some(emoji);
+
+        self
+    }
+}
+
+impl From<SelectMenuOptionBuilder> for SelectMenuOption {
+    /// Convert a select menu option builder into a select menu option.
+    ///
+    /// This is equivalent to calling [`SelectMenuOptionBuilder::build`].
+    fn from(builder: SelectMenuOptionBuilder) -> Self {
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::id::Id;
+
+    assert_impl_all!(SelectMenuBuilder: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(SelectMenu: From<SelectMenuBuilder>);
+    assert_impl_all!(Component: From<SelectMenuBuilder>);
+    assert_impl_all!(SelectMenuOptionBuilder: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(SelectMenuOption: From<SelectMenuOptionBuilder>);
+
+    #[test]
+    fn builder() {
+        let expected = SelectMenu {
+            channel_types: None,
+            custom_id: "pony".to_owned(),
+            default_values: None,
+            disabled: false,
+            kind: SelectMenuType::Text,
+            max_values: Some(2),
+            min_values: Some(1),
+            options: Some(Vec::from([
+                SelectMenuOption {
+                    default: false,
+                    description: Some("Best pony".to_owned()),
+                    emoji: None,
+                    label: "Twilight Sparkle".to_owned(),
+                    value: "twilight".to_owned(),
+                },
+                SelectMenuOption {
+                    default: true,
+                    description: None,
+                    emoji: None,
+                    label: "Rarity".to_owned(),
+                    value: "rarity".to_owned(),
+                },
+            ])),
+            placeholder: Some("Choose".to_owned()),
+        };
+        let actual = SelectMenuBuilder::new("pony", SelectMenuType::Text)
+            .max_values(2)
+            .min_values(1)
+            .option(
+                SelectMenuOptionBuilder::new("Twilight Sparkle", "twilight")
+                    .description("Best pony"),
+            )
+            .option(SelectMenuOptionBuilder::new("Rarity", "rarity").default(true))
+            .placeholder("Choose")
+            .build();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn default_values() {
+        let actual = SelectMenuBuilder::new("users", SelectMenuType::User)
+            .default_value(SelectDefaultValue::User(Id::new(1)))
+            .default_value(SelectDefaultValue::User(Id::new(2)))
+            .build();
+
+        assert_eq!(
+            actual.default_values,
+            Some(Vec::from([
+                SelectDefaultValue::User(Id::new(1)),
+                SelectDefaultValue::User(Id::new(2)),
+            ]))
+        );
+    }
+
+    #[test]
+    fn validate() {
+        assert!(SelectMenuBuilder::new("pony", SelectMenuType::Text)
+            .option(SelectMenuOptionBuilder::new("Twilight Sparkle", "twilight"))
+            .validate()
+            .is_ok());
+        assert!(SelectMenuBuilder::new("pony", SelectMenuType::Text)
+            .placeholder("a".repeat(151))
+            .validate()
+            .is_err());
+    }
+}
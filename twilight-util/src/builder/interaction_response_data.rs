@@ -3,6 +3,9 @@ use twilight_model::{
     channel::message::{AllowedMentions, Component, Embed, MessageFlags},
     http::{attachment::Attachment, interaction::InteractionResponseData},
 };
+use twilight_validate::interaction::{
+    response_data as validate_response_data, InteractionValidationError,
+};
 
 /// Create an [`InteractionResponseData`] with a builder.
 ///
@@ -62,6 +65,18 @@ impl InteractionResponseDataBuilder {
         self.0
     }
 
+    /// Ensure the response data is valid.
+    ///
+    /// # Errors
+    ///
+    /// Refer to the errors section of
+    /// [`twilight_validate::interaction::response_data`] for possible errors.
+    pub fn validate(self) -> Result<Self, InteractionValidationError> {
+        validate_response_data(&self.0)?;
+
+        Ok(self)
+    }
+
     /// Set the [`AllowedMentions`] of the callback.
     ///
     /// Defaults to [`None`].
@@ -246,4 +261,20 @@ mod tests {
 
         assert_eq!(value, expected);
     }
+
+    #[test]
+    fn validate() {
+        assert!(InteractionResponseDataBuilder::new()
+            .content("a".repeat(2000))
+            .validate()
+            .is_ok());
+        assert!(InteractionResponseDataBuilder::new()
+            .content("a".repeat(2001))
+            .validate()
+            .is_err());
+        assert!(InteractionResponseDataBuilder::new()
+            .title("a".repeat(46))
+            .validate()
+            .is_err());
+    }
 }
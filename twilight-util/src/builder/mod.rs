@@ -1,6 +1,7 @@
 //! Builders for large structs.
 
 pub mod command;
+pub mod component;
 pub mod embed;
 
 mod interaction_response_data;
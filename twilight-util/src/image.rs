@@ -0,0 +1,225 @@
+//! Convert raw image bytes into the Data URIs Discord accepts when uploading
+//! avatars, icons, and emojis.
+//!
+//! Refer to [Discord Docs/Image Data] for additional information.
+//!
+//! # Examples
+//!
+//! Set the current user's avatar from a PNG file:
+//!
+//! ```no_run
+//! use twilight_util::image::ImageData;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let bytes = std::fs::read("avatar.png")?;
+//! let avatar = ImageData::new(&bytes)?;
+//!
+//! // Pass the Data URI to a request, such as `UpdateCurrentUser::avatar`.
+//! println!("{}", avatar.as_str());
+//! # Ok(()) }
+//! ```
+//!
+//! [Discord Docs/Image Data]: https://discord.com/developers/docs/reference#image-data
+
+pub use crate::image_format::ImageFormat;
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// Maximum size of an emoji's image in bytes.
+pub const EMOJI_SIZE_MAX: usize = 256 * 1024;
+
+/// Error when creating [`ImageData`].
+#[derive(Debug)]
+pub struct ImageDataError {
+    kind: ImageDataErrorType,
+}
+
+impl ImageDataError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ImageDataErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (ImageDataErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, None)
+    }
+}
+
+impl Display for ImageDataError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            ImageDataErrorType::FormatUnsupported => {
+                f.write_str("image is not a GIF, JPEG, PNG, or WebP image")
+            }
+            ImageDataErrorType::TooLarge { size, size_max } => {
+                f.write_str("image is ")?;
+                Display::fmt(size, f)?;
+                f.write_str(" bytes, but may be at most ")?;
+                Display::fmt(size_max, f)?;
+
+                f.write_str(" bytes")
+            }
+        }
+    }
+}
+
+impl Error for ImageDataError {}
+
+/// Type of [`ImageDataError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ImageDataErrorType {
+    /// Image is not in one of the supported [`ImageFormat`]s.
+    FormatUnsupported,
+    /// Image is larger than the maximum size.
+    TooLarge {
+        /// Size of the image in bytes.
+        size: usize,
+        /// Maximum size of the image in bytes.
+        size_max: usize,
+    },
+}
+
+/// Image encoded as a Data URI in the form of
+/// `data:image/{type};base64,{data}`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ImageData {
+    format: ImageFormat,
+    uri: String,
+}
+
+impl ImageData {
+    /// Encode an image, detecting its format from its contents.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`FormatUnsupported`] if the image is not in
+    /// a supported [`ImageFormat`].
+    ///
+    /// [`FormatUnsupported`]: ImageDataErrorType::FormatUnsupported
+    pub fn new(bytes: &[u8]) -> Result<Self, ImageDataError> {
+        let format = ImageFormat::from_bytes(bytes).ok_or(ImageDataError {
+            kind: ImageDataErrorType::FormatUnsupported,
+        })?;
+
+        let mime_type = format.mime_type();
+        let mut uri = String::with_capacity(
+            "data:;base64,".len()
+                + mime_type.len()
+                + base64::encoded_len(bytes.len(), true).unwrap_or_default(),
+        );
+        uri.push_str("data:");
+        uri.push_str(mime_type);
+        uri.push_str(";base64,");
+        STANDARD.encode_string(bytes, &mut uri);
+
+        Ok(Self { format, uri })
+    }
+
+    /// Encode an image, ensuring that it is no larger than a maximum size in
+    /// bytes, such as [`EMOJI_SIZE_MAX`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`TooLarge`] if the image is larger than the
+    /// maximum size.
+    ///
+    /// Returns an error of type [`FormatUnsupported`] if the image is not in
+    /// a supported [`ImageFormat`].
+    ///
+    /// [`FormatUnsupported`]: ImageDataErrorType::FormatUnsupported
+    /// [`TooLarge`]: ImageDataErrorType::TooLarge
+    pub fn with_size_max(bytes: &[u8], size_max: usize) -> Result<Self, ImageDataError> {
+        if bytes.len() > size_max {
+            return Err(ImageDataError {
+                kind: ImageDataErrorType::TooLarge {
+                    size: bytes.len(),
+                    size_max,
+                },
+            });
+        }
+
+        Self::new(bytes)
+    }
+
+    /// Data URI of the image.
+    pub fn as_str(&self) -> &str {
+        &self.uri
+    }
+
+    /// Format of the image.
+    pub const fn format(&self) -> ImageFormat {
+        self.format
+    }
+
+    /// Consume the image data, returning the Data URI.
+    pub fn into_string(self) -> String {
+        self.uri
+    }
+}
+
+impl AsRef<str> for ImageData {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Display for ImageData {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(&self.uri)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ImageData, ImageDataError, ImageDataErrorType, ImageFormat};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug, hash::Hash};
+
+    assert_impl_all!(ImageData: AsRef<str>, Clone, Debug, Eq, Hash, PartialEq, Send, Sync);
+    assert_impl_all!(ImageDataError: Debug, Error, Send, Sync);
+    assert_impl_all!(ImageDataErrorType: Debug, Send, Sync);
+
+    const PNG: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+    #[test]
+    fn new() {
+        let image = ImageData::new(PNG).unwrap();
+        assert_eq!(ImageFormat::Png, image.format());
+        assert_eq!("data:image/png;base64,iVBORw0KGgo=", image.as_str());
+        assert_eq!(image.as_str(), image.to_string());
+
+        assert!(matches!(
+            ImageData::new(b"not an image").unwrap_err().kind(),
+            ImageDataErrorType::FormatUnsupported
+        ));
+    }
+
+    #[test]
+    fn with_size_max() {
+        assert!(ImageData::with_size_max(PNG, PNG.len()).is_ok());
+        assert!(matches!(
+            ImageData::with_size_max(PNG, PNG.len() - 1)
+                .unwrap_err()
+                .kind(),
+            ImageDataErrorType::TooLarge {
+                size: 8,
+                size_max: 7,
+            }
+        ));
+    }
+}
@@ -0,0 +1,81 @@
+//! Format of images, shared by image data and CDN links.
+
+/// Format of an image.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ImageFormat {
+    /// GIF image, which may be animated.
+    ///
+    /// Discord's CDN only supports this format for animated images.
+    Gif,
+    /// JPEG image.
+    Jpeg,
+    /// PNG image.
+    Png,
+    /// `WebP` image.
+    WebP,
+}
+
+impl ImageFormat {
+    /// File extension of the format.
+    pub const fn extension(self) -> &'static str {
+        match self {
+            Self::Gif => "gif",
+            Self::Jpeg => "jpg",
+            Self::Png => "png",
+            Self::WebP => "webp",
+        }
+    }
+
+    /// Determine the format of an image from its leading bytes.
+    ///
+    /// Returns [`None`] if the image is not in a supported format.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        match bytes {
+            [b'G', b'I', b'F', b'8', b'7' | b'9', b'a', ..] => Some(Self::Gif),
+            [0xFF, 0xD8, 0xFF, ..] => Some(Self::Jpeg),
+            [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1A, b'\n', ..] => Some(Self::Png),
+            [b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'E', b'B', b'P', ..] => Some(Self::WebP),
+            _ => None,
+        }
+    }
+
+    /// MIME type of the format.
+    pub const fn mime_type(self) -> &'static str {
+        match self {
+            Self::Gif => "image/gif",
+            Self::Jpeg => "image/jpeg",
+            Self::Png => "image/png",
+            Self::WebP => "image/webp",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ImageFormat;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_impl_all!(ImageFormat: Clone, Copy, Debug, Eq, Hash, PartialEq, Send, Sync);
+
+    #[test]
+    fn from_bytes() {
+        assert_eq!(Some(ImageFormat::Gif), ImageFormat::from_bytes(b"GIF89a"));
+        assert_eq!(Some(ImageFormat::Gif), ImageFormat::from_bytes(b"GIF87a"));
+        assert_eq!(
+            Some(ImageFormat::Jpeg),
+            ImageFormat::from_bytes(&[0xFF, 0xD8, 0xFF, 0xE0])
+        );
+        assert_eq!(
+            Some(ImageFormat::Png),
+            ImageFormat::from_bytes(b"\x89PNG\r\n\x1a\n")
+        );
+        assert_eq!(
+            Some(ImageFormat::WebP),
+            ImageFormat::from_bytes(b"RIFF\0\0\0\0WEBPVP8 ")
+        );
+        assert!(ImageFormat::from_bytes(b"RIFF\0\0\0\0WAVE").is_none());
+        assert!(ImageFormat::from_bytes(b"").is_none());
+    }
+}
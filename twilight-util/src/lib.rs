@@ -16,11 +16,20 @@
 #[cfg(feature = "builder")]
 pub mod builder;
 
+#[cfg(feature = "image")]
+pub mod image;
+
+#[cfg(any(feature = "image", feature = "link"))]
+mod image_format;
+
 #[cfg(feature = "link")]
 pub mod link;
 
 #[cfg(feature = "permission-calculator")]
 pub mod permission_calculator;
 
+#[cfg(feature = "signature")]
+pub mod signature;
+
 #[cfg(feature = "snowflake")]
 pub mod snowflake;
@@ -0,0 +1,391 @@
+//! Utilities for formatting links to images and files hosted on Discord's CDN.
+//!
+//! # Examples
+//!
+//! Format the URL to a user's avatar as a 256 pixel `WebP` image:
+//!
+//! ```
+//! use twilight_model::{id::Id, util::ImageHash};
+//! use twilight_util::link::cdn::{ImageFormat, ImageUrl};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let hash = ImageHash::parse(b"b2a6536641da91a0b59bd66557c56c36")?;
+//! let url = ImageUrl::user_avatar(Id::new(123), hash)
+//!     .format(ImageFormat::WebP)?
+//!     .size(256)?;
+//!
+//! assert_eq!(
+//!     "https://cdn.discordapp.com/avatars/123/b2a6536641da91a0b59bd66557c56c36.webp?size=256",
+//!     url.to_string(),
+//! );
+//! # Ok(()) }
+//! ```
+
+pub use crate::image_format::ImageFormat;
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    id::{
+        marker::{AttachmentMarker, ChannelMarker, EmojiMarker, GuildMarker, UserMarker},
+        Id,
+    },
+    util::ImageHash,
+};
+
+/// Base URL of Discord's CDN.
+pub const BASE: &str = "https://cdn.discordapp.com";
+
+/// Maximum size of an image.
+pub const SIZE_MAX: u16 = 4096;
+
+/// Minimum size of an image.
+pub const SIZE_MIN: u16 = 16;
+
+/// Formats supported by most image endpoints.
+const FORMATS_ALL: &[ImageFormat] = &[
+    ImageFormat::Gif,
+    ImageFormat::Jpeg,
+    ImageFormat::Png,
+    ImageFormat::WebP,
+];
+
+/// Formats supported by image endpoints that can't be animated.
+const FORMATS_STATIC: &[ImageFormat] = &[ImageFormat::Jpeg, ImageFormat::Png, ImageFormat::WebP];
+
+/// Error when configuring an [`ImageUrl`].
+#[derive(Debug)]
+pub struct CdnUrlError {
+    kind: CdnUrlErrorType,
+}
+
+impl CdnUrlError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &CdnUrlErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the owned error type.
+    #[must_use = "consuming the error into its type has no effect if left unused"]
+    pub const fn into_kind(self) -> CdnUrlErrorType {
+        self.kind
+    }
+}
+
+impl Display for CdnUrlError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            CdnUrlErrorType::FormatUnsupported { format } => {
+                f.write_str("image format ")?;
+                f.write_str(format.extension())?;
+
+                f.write_str(" is not supported for this image")
+            }
+            CdnUrlErrorType::SizeInvalid { size } => {
+                f.write_str("provided image size is ")?;
+                Display::fmt(size, f)?;
+                f.write_str(", but it must be a power of 2 between ")?;
+                Display::fmt(&SIZE_MIN, f)?;
+                f.write_str(" and ")?;
+
+                Display::fmt(&SIZE_MAX, f)
+            }
+        }
+    }
+}
+
+impl Error for CdnUrlError {}
+
+/// Type of [`CdnUrlError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum CdnUrlErrorType {
+    /// Image can't be requested in the provided format.
+    ///
+    /// The [`Gif`] format is only supported for animated images.
+    ///
+    /// [`Gif`]: ImageFormat::Gif
+    FormatUnsupported {
+        /// Provided format.
+        format: ImageFormat,
+    },
+    /// Size isn't a power of 2 between [`SIZE_MIN`] and [`SIZE_MAX`].
+    SizeInvalid {
+        /// Provided size.
+        size: u16,
+    },
+}
+
+/// URL to an image hosted on Discord's CDN.
+///
+/// Animated images default to the [`Gif`] format while other images default
+/// to the [`Png`] format. Use [`format`] and [`size`] to configure the image,
+/// and format the URL via its [`Display`] implementation.
+///
+/// [`Gif`]: ImageFormat::Gif
+/// [`Png`]: ImageFormat::Png
+/// [`format`]: Self::format
+/// [`size`]: Self::size
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[must_use = "image URLs have no effect if left unused"]
+pub struct ImageUrl {
+    animated: bool,
+    format: ImageFormat,
+    formats: &'static [ImageFormat],
+    path: String,
+    size: Option<u16>,
+}
+
+impl ImageUrl {
+    /// Create a new image URL from its path and whether it is animated.
+    const fn new(path: String, animated: bool, formats: &'static [ImageFormat]) -> Self {
+        Self {
+            animated,
+            format: if animated {
+                ImageFormat::Gif
+            } else {
+                ImageFormat::Png
+            },
+            formats,
+            path,
+            size: None,
+        }
+    }
+
+    /// URL to a user's default avatar.
+    ///
+    /// For users on the new username system the index is
+    /// `(user_id >> 22) % 6`, and for users with a legacy discriminator it is
+    /// `discriminator % 5`. Default avatars only support the [`Png`] format.
+    ///
+    /// [`Png`]: ImageFormat::Png
+    pub fn default_avatar(index: u8) -> Self {
+        Self::new(format!("embed/avatars/{index}"), false, &[ImageFormat::Png])
+    }
+
+    /// URL to an emoji.
+    ///
+    /// The [`Gif`] format is only supported if the emoji is animated.
+    ///
+    /// [`Gif`]: ImageFormat::Gif
+    pub fn emoji(emoji_id: Id<EmojiMarker>, animated: bool) -> Self {
+        Self::new(format!("emojis/{emoji_id}"), animated, FORMATS_ALL)
+    }
+
+    /// URL to a guild's banner.
+    pub fn guild_banner(guild_id: Id<GuildMarker>, hash: ImageHash) -> Self {
+        Self::new(
+            format!("banners/{guild_id}/{hash}"),
+            hash.is_animated(),
+            FORMATS_ALL,
+        )
+    }
+
+    /// URL to a guild's icon.
+    pub fn guild_icon(guild_id: Id<GuildMarker>, hash: ImageHash) -> Self {
+        Self::new(
+            format!("icons/{guild_id}/{hash}"),
+            hash.is_animated(),
+            FORMATS_ALL,
+        )
+    }
+
+    /// URL to a guild's invite splash.
+    pub fn guild_splash(guild_id: Id<GuildMarker>, hash: ImageHash) -> Self {
+        Self::new(format!("splashes/{guild_id}/{hash}"), false, FORMATS_STATIC)
+    }
+
+    /// URL to a user's avatar.
+    pub fn user_avatar(user_id: Id<UserMarker>, hash: ImageHash) -> Self {
+        Self::new(
+            format!("avatars/{user_id}/{hash}"),
+            hash.is_animated(),
+            FORMATS_ALL,
+        )
+    }
+
+    /// URL to a user's banner.
+    pub fn user_banner(user_id: Id<UserMarker>, hash: ImageHash) -> Self {
+        Self::new(
+            format!("banners/{user_id}/{hash}"),
+            hash.is_animated(),
+            FORMATS_ALL,
+        )
+    }
+
+    /// Set the format of the image.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`FormatUnsupported`] if the image can't be
+    /// requested in the format.
+    ///
+    /// [`FormatUnsupported`]: CdnUrlErrorType::FormatUnsupported
+    pub fn format(mut self, format: ImageFormat) -> Result<Self, CdnUrlError> {
+        if !self.formats.contains(&format) || (format == ImageFormat::Gif && !self.animated) {
+            return Err(CdnUrlError {
+                kind: CdnUrlErrorType::FormatUnsupported { format },
+            });
+        }
+
+        self.format = format;
+
+        Ok(self)
+    }
+
+    /// Set the size of the image.
+    ///
+    /// The size must be a power of 2 between [`SIZE_MIN`] and [`SIZE_MAX`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of type [`SizeInvalid`] if the size is invalid.
+    ///
+    /// [`SizeInvalid`]: CdnUrlErrorType::SizeInvalid
+    pub fn size(mut self, size: u16) -> Result<Self, CdnUrlError> {
+        if !(SIZE_MIN..=SIZE_MAX).contains(&size) || !size.is_power_of_two() {
+            return Err(CdnUrlError {
+                kind: CdnUrlErrorType::SizeInvalid { size },
+            });
+        }
+
+        self.size = Some(size);
+
+        Ok(self)
+    }
+}
+
+impl Display for ImageUrl {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(BASE)?;
+        f.write_str("/")?;
+        f.write_str(&self.path)?;
+        f.write_str(".")?;
+        f.write_str(self.format.extension())?;
+
+        if let Some(size) = self.size {
+            f.write_str("?size=")?;
+            Display::fmt(&size, f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Format the URL to a message attachment.
+///
+/// The filename is used as-is and must already be URL encoded.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_model::id::Id;
+/// use twilight_util::link::cdn;
+///
+/// assert_eq!(
+///     "https://cdn.discordapp.com/attachments/1/2/image.png",
+///     cdn::attachment(Id::new(1), Id::new(2), "image.png"),
+/// );
+/// ```
+pub fn attachment(
+    channel_id: Id<ChannelMarker>,
+    attachment_id: Id<AttachmentMarker>,
+    filename: &str,
+) -> String {
+    format!("{BASE}/attachments/{channel_id}/{attachment_id}/{filename}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CdnUrlError, CdnUrlErrorType, ImageFormat, ImageUrl};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug, hash::Hash};
+    use twilight_model::{id::Id, util::ImageHash};
+
+    assert_impl_all!(CdnUrlErrorType: Debug, Send, Sync);
+    assert_impl_all!(CdnUrlError: Debug, Error, Send, Sync);
+    assert_impl_all!(ImageUrl: Clone, Debug, Eq, Hash, PartialEq, Send, Sync);
+
+    const HASH: &[u8] = b"b2a6536641da91a0b59bd66557c56c36";
+    const HASH_ANIMATED: &[u8] = b"a_b2a6536641da91a0b59bd66557c56c36";
+
+    #[test]
+    fn default_format() -> Result<(), Box<dyn Error>> {
+        assert_eq!(
+            "https://cdn.discordapp.com/icons/1/b2a6536641da91a0b59bd66557c56c36.png",
+            ImageUrl::guild_icon(Id::new(1), ImageHash::parse(HASH)?).to_string(),
+        );
+        assert_eq!(
+            "https://cdn.discordapp.com/avatars/2/a_b2a6536641da91a0b59bd66557c56c36.gif",
+            ImageUrl::user_avatar(Id::new(2), ImageHash::parse(HASH_ANIMATED)?).to_string(),
+        );
+        assert_eq!(
+            "https://cdn.discordapp.com/embed/avatars/3.png",
+            ImageUrl::default_avatar(3).to_string(),
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn format() -> Result<(), Box<dyn Error>> {
+        let hash = ImageHash::parse(HASH)?;
+
+        assert_eq!(
+            "https://cdn.discordapp.com/banners/1/b2a6536641da91a0b59bd66557c56c36.jpg",
+            ImageUrl::guild_banner(Id::new(1), hash)
+                .format(ImageFormat::Jpeg)?
+                .to_string(),
+        );
+        assert_eq!(
+            "https://cdn.discordapp.com/emojis/2.gif",
+            ImageUrl::emoji(Id::new(2), true)
+                .format(ImageFormat::Gif)?
+                .to_string(),
+        );
+
+        assert!(matches!(
+            ImageUrl::user_banner(Id::new(3), hash)
+                .format(ImageFormat::Gif)
+                .unwrap_err()
+                .kind(),
+            CdnUrlErrorType::FormatUnsupported {
+                format: ImageFormat::Gif
+            },
+        ));
+        assert!(
+            ImageUrl::guild_splash(Id::new(4), ImageHash::parse(HASH_ANIMATED)?)
+                .format(ImageFormat::Gif)
+                .is_err()
+        );
+        assert!(ImageUrl::default_avatar(0)
+            .format(ImageFormat::WebP)
+            .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn size() -> Result<(), Box<dyn Error>> {
+        assert_eq!(
+            "https://cdn.discordapp.com/emojis/1.png?size=16",
+            ImageUrl::emoji(Id::new(1), false).size(16)?.to_string(),
+        );
+        assert!(ImageUrl::emoji(Id::new(1), false).size(4096).is_ok());
+
+        assert!(matches!(
+            ImageUrl::emoji(Id::new(1), false)
+                .size(300)
+                .unwrap_err()
+                .kind(),
+            CdnUrlErrorType::SizeInvalid { size: 300 },
+        ));
+        assert!(ImageUrl::emoji(Id::new(1), false).size(8).is_err());
+        assert!(ImageUrl::emoji(Id::new(1), false).size(8192).is_err());
+
+        Ok(())
+    }
+}
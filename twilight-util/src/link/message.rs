@@ -0,0 +1,336 @@
+//! Utilities for formatting and parsing links to messages.
+//!
+//! # Examples
+//!
+//! Format a link to a message in a guild and parse it back:
+//!
+//! ```
+//! use twilight_model::id::Id;
+//! use twilight_util::link::message::MessageLink;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let link = MessageLink::new(Some(Id::new(1)), Id::new(2), Id::new(3));
+//! let url = link.to_string();
+//! assert_eq!("https://discord.com/channels/1/2/3", url);
+//!
+//! assert_eq!(link, MessageLink::parse(&url)?);
+//! # Ok(()) }
+//! ```
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    num::NonZeroU64,
+    str::FromStr,
+};
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker, MessageMarker},
+    Id,
+};
+
+/// Base URL of message links.
+pub const BASE: &str = "https://discord.com/channels";
+
+/// Guild segment of links to messages in private channels.
+const PRIVATE_SEGMENT: &str = "@me";
+
+/// Error when [parsing] a message link.
+///
+/// [parsing]: MessageLink::parse
+#[derive(Debug)]
+pub struct MessageLinkParseError {
+    kind: MessageLinkParseErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl MessageLinkParseError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &MessageLinkParseErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        MessageLinkParseErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for MessageLinkParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.kind {
+            MessageLinkParseErrorType::IdInvalid => {
+                f.write_str("url path segment isn't a valid ID")
+            }
+            MessageLinkParseErrorType::SegmentMissing => {
+                f.write_str("url is missing a required path segment")
+            }
+        }
+    }
+}
+
+impl Error for MessageLinkParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`MessageLinkParseError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum MessageLinkParseErrorType {
+    /// ID segment in the URL path is not an integer.
+    IdInvalid,
+    /// Required segment of the URL path is missing.
+    SegmentMissing,
+}
+
+/// Link to a message in a guild or private channel.
+///
+/// Formats into a URL via its [`Display`] implementation.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct MessageLink {
+    /// ID of the channel the message is in.
+    pub channel_id: Id<ChannelMarker>,
+    /// ID of the guild the message is in, or [`None`] if it is in a private
+    /// channel.
+    pub guild_id: Option<Id<GuildMarker>>,
+    /// ID of the message.
+    pub message_id: Id<MessageMarker>,
+}
+
+impl MessageLink {
+    /// Create a link to a message.
+    ///
+    /// Pass [`None`] as the guild ID for messages in private channels.
+    pub const fn new(
+        guild_id: Option<Id<GuildMarker>>,
+        channel_id: Id<ChannelMarker>,
+        message_id: Id<MessageMarker>,
+    ) -> Self {
+        Self {
+            channel_id,
+            guild_id,
+            message_id,
+        }
+    }
+
+    /// Parse a message link, if it exists in the string.
+    ///
+    /// Links from the canary and PTB clients are supported, and trailing path
+    /// segments, queries, and fragments are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Parse a link to a message in a private channel:
+    ///
+    /// ```
+    /// use twilight_model::id::Id;
+    /// use twilight_util::link::message::MessageLink;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let link = MessageLink::parse("https://canary.discord.com/channels/@me/2/3")?;
+    /// assert!(link.guild_id.is_none());
+    /// assert_eq!(Id::new(2), link.channel_id);
+    /// assert_eq!(Id::new(3), link.message_id);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`MessageLinkParseErrorType::IdInvalid`] error type if an ID
+    /// segment of the URL is not a valid integer.
+    ///
+    /// Returns a [`MessageLinkParseErrorType::SegmentMissing`] error type if
+    /// the "channels" segment of the URL or one of the ID segments is missing.
+    pub fn parse(url: &str) -> Result<Self, MessageLinkParseError> {
+        let mut segments = {
+            let mut start = url.split("discord.com/channels/");
+            let path = start.nth(1).ok_or(MessageLinkParseError {
+                kind: MessageLinkParseErrorType::SegmentMissing,
+                source: None,
+            })?;
+            let end = path.find(['?', '#']).unwrap_or(path.len());
+
+            path[..end].split('/')
+        };
+
+        let mut segment = || {
+            segments
+                .next()
+                .filter(|segment| !segment.is_empty())
+                .ok_or(MessageLinkParseError {
+                    kind: MessageLinkParseErrorType::SegmentMissing,
+                    source: None,
+                })
+        };
+
+        let guild_segment = segment()?;
+        let channel_segment = segment()?;
+        let message_segment = segment()?;
+
+        let guild_id = if guild_segment == PRIVATE_SEGMENT {
+            None
+        } else {
+            Some(parse_id(guild_segment)?)
+        };
+
+        Ok(Self {
+            channel_id: parse_id(channel_segment)?,
+            guild_id,
+            message_id: parse_id(message_segment)?,
+        })
+    }
+}
+
+impl Display for MessageLink {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(BASE)?;
+        f.write_str("/")?;
+
+        if let Some(guild_id) = self.guild_id {
+            Display::fmt(&guild_id, f)?;
+        } else {
+            f.write_str(PRIVATE_SEGMENT)?;
+        }
+
+        f.write_str("/")?;
+        Display::fmt(&self.channel_id, f)?;
+        f.write_str("/")?;
+
+        Display::fmt(&self.message_id, f)
+    }
+}
+
+impl FromStr for MessageLink {
+    type Err = MessageLinkParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// Parse a path segment into an ID.
+fn parse_id<T>(segment: &str) -> Result<Id<T>, MessageLinkParseError> {
+    segment
+        .parse::<NonZeroU64>()
+        .map(Id::from)
+        .map_err(|source| MessageLinkParseError {
+            kind: MessageLinkParseErrorType::IdInvalid,
+            source: Some(Box::new(source)),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MessageLink, MessageLinkParseError, MessageLinkParseErrorType};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug, hash::Hash, str::FromStr};
+    use twilight_model::id::Id;
+
+    assert_impl_all!(MessageLinkParseErrorType: Debug, Send, Sync);
+    assert_impl_all!(MessageLinkParseError: Debug, Error, Send, Sync);
+    assert_impl_all!(
+        MessageLink: Clone,
+        Copy,
+        Debug,
+        Eq,
+        FromStr,
+        Hash,
+        PartialEq,
+        Send,
+        Sync
+    );
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            "https://discord.com/channels/1/2/3",
+            MessageLink::new(Some(Id::new(1)), Id::new(2), Id::new(3)).to_string(),
+        );
+        assert_eq!(
+            "https://discord.com/channels/@me/2/3",
+            MessageLink::new(None, Id::new(2), Id::new(3)).to_string(),
+        );
+    }
+
+    #[test]
+    fn parse() {
+        let guild = MessageLink::new(Some(Id::new(1)), Id::new(2), Id::new(3));
+        let private = MessageLink::new(None, Id::new(2), Id::new(3));
+
+        assert_eq!(
+            guild,
+            MessageLink::parse("https://discord.com/channels/1/2/3").unwrap(),
+        );
+        assert_eq!(
+            guild,
+            MessageLink::parse("https://ptb.discord.com/channels/1/2/3/").unwrap(),
+        );
+        assert_eq!(
+            guild,
+            MessageLink::parse("see https://discord.com/channels/1/2/3?a=b").unwrap(),
+        );
+        assert_eq!(
+            private,
+            MessageLink::parse("https://discord.com/channels/@me/2/3#c").unwrap(),
+        );
+        assert_eq!(
+            private,
+            "https://canary.discord.com/channels/@me/2/3"
+                .parse()
+                .unwrap(),
+        );
+    }
+
+    #[test]
+    fn parse_invalid() {
+        // Base URL is improper.
+        assert!(matches!(
+            MessageLink::parse("https://discord.com/api/1/2/3")
+                .unwrap_err()
+                .kind(),
+            &MessageLinkParseErrorType::SegmentMissing,
+        ));
+        // Message ID is missing.
+        assert!(matches!(
+            MessageLink::parse("https://discord.com/channels/1/2")
+                .unwrap_err()
+                .kind(),
+            &MessageLinkParseErrorType::SegmentMissing,
+        ));
+        assert!(matches!(
+            MessageLink::parse("https://discord.com/channels/1/2/")
+                .unwrap_err()
+                .kind(),
+            &MessageLinkParseErrorType::SegmentMissing,
+        ));
+        // ID segments aren't integers.
+        assert!(matches!(
+            MessageLink::parse("https://discord.com/channels/guild/2/3")
+                .unwrap_err()
+                .kind(),
+            &MessageLinkParseErrorType::IdInvalid,
+        ));
+        assert!(matches!(
+            MessageLink::parse("https://discord.com/channels/1/2/0")
+                .unwrap_err()
+                .kind(),
+            &MessageLinkParseErrorType::IdInvalid,
+        ));
+    }
+}
@@ -1,3 +1,5 @@
 //! Utilities for parsing and formatting links to various resources.
 
+pub mod cdn;
+pub mod message;
 pub mod webhook;
@@ -95,8 +95,8 @@ mod bitops;
 mod preset;
 
 use self::preset::{
-    PERMISSIONS_MESSAGING, PERMISSIONS_ROOT_ONLY, PERMISSIONS_STAGE_OMIT, PERMISSIONS_TEXT_OMIT,
-    PERMISSIONS_VOICE_OMIT,
+    PERMISSIONS_COMMUNICATION_DISABLED_OMIT, PERMISSIONS_MESSAGING, PERMISSIONS_ROOT_ONLY,
+    PERMISSIONS_STAGE_OMIT, PERMISSIONS_TEXT_OMIT, PERMISSIONS_VOICE_OMIT,
 };
 use twilight_model::{
     channel::{
@@ -120,6 +120,8 @@ use twilight_model::{
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[must_use = "calculators aren't useful if you don't calculate permissions"]
 pub struct PermissionCalculator<'a> {
+    /// Whether the member's communication is disabled.
+    communication_disabled: bool,
     /// Permissions of the `@everyone` role for the guild.
     everyone_role: Permissions,
     /// ID of the guild.
@@ -148,6 +150,7 @@ impl<'a> PermissionCalculator<'a> {
         member_roles: &'a [(Id<RoleMarker>, Permissions)],
     ) -> Self {
         Self {
+            communication_disabled: false,
             everyone_role,
             guild_id,
             owner_id: None,
@@ -173,6 +176,27 @@ impl<'a> PermissionCalculator<'a> {
         self
     }
 
+    /// Configure whether the member's communication is disabled.
+    ///
+    /// This should be set if the member's
+    /// [`communication_disabled_until`] is in the future, i.e. the member is
+    /// timed out.
+    ///
+    /// Members that are timed out only have the [View Channel] and
+    /// [Read Message History] permissions, unless they are the owner of the
+    /// guild or have the [Administrator] permission.
+    ///
+    /// [`communication_disabled_until`]: twilight_model::guild::Member::communication_disabled_until
+    /// [Administrator]: twilight_model::guild::Permissions::ADMINISTRATOR
+    /// [Read Message History]: twilight_model::guild::Permissions::READ_MESSAGE_HISTORY
+    /// [View Channel]: twilight_model::guild::Permissions::VIEW_CHANNEL
+    #[must_use = "calculators should be used to calculate permissions"]
+    pub const fn communication_disabled(mut self, communication_disabled: bool) -> Self {
+        self.communication_disabled = communication_disabled;
+
+        self
+    }
+
     /// Calculate the guild-level permissions of a member.
     #[must_use = "calculating permissions is only useful if they're used"]
     pub const fn root(&self) -> Permissions {
@@ -212,6 +236,11 @@ impl<'a> PermissionCalculator<'a> {
             idx += 1;
         }
 
+        // Timed out members may only view channels and read their history.
+        if self.communication_disabled {
+            permissions = bitops::remove(permissions, PERMISSIONS_COMMUNICATION_DISABLED_OMIT);
+        }
+
         permissions
     }
 
@@ -377,6 +406,10 @@ impl<'a> PermissionCalculator<'a> {
             self.user_id,
         );
 
+        if self.communication_disabled {
+            permissions = bitops::remove(permissions, PERMISSIONS_COMMUNICATION_DISABLED_OMIT);
+        }
+
         // If the permission set is empty then we don't need to do any removals.
         if permissions.is_empty() {
             return permissions;
@@ -663,6 +696,39 @@ mod tests {
         assert_eq!(calculated, Permissions::MANAGE_MESSAGES);
     }
 
+    /// Test that a timed out member only has the "view channel" and "read
+    /// message history" permissions, even if overwrites allow more.
+    #[test]
+    fn communication_disabled() {
+        let guild_id = Id::new(1);
+        let user_id = Id::new(2);
+        let everyone_role = Permissions::READ_MESSAGE_HISTORY
+            | Permissions::SEND_MESSAGES
+            | Permissions::VIEW_CHANNEL;
+        let roles = &[(Id::new(3), Permissions::KICK_MEMBERS)];
+        let overwrites = &[PermissionOverwrite {
+            allow: Permissions::ADD_REACTIONS,
+            deny: Permissions::empty(),
+            id: user_id.cast(),
+            kind: PermissionOverwriteType::Member,
+        }];
+
+        let calculator = PermissionCalculator::new(guild_id, user_id, everyone_role, roles)
+            .communication_disabled(true);
+        let expected = Permissions::READ_MESSAGE_HISTORY | Permissions::VIEW_CHANNEL;
+        assert_eq!(expected, calculator.root());
+        assert_eq!(
+            expected,
+            calculator.in_channel(ChannelType::GuildText, overwrites)
+        );
+
+        // The owner isn't restricted by timeouts.
+        let calculator = PermissionCalculator::new(guild_id, user_id, everyone_role, roles)
+            .communication_disabled(true)
+            .owner_id(user_id);
+        assert_eq!(Permissions::all(), calculator.root());
+    }
+
     /// Test that a member that has a role with the "administrator" permission
     /// has all denying overwrites ignored.
     #[test]
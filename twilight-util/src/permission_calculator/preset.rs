@@ -12,6 +12,12 @@ pub const PERMISSIONS_MESSAGING: Permissions = Permissions::from_bits_truncate(
         | Permissions::SEND_TTS_MESSAGES.bits(),
 );
 
+/// Permissions omitted from members whose communication is disabled, i.e. all
+/// permissions except for viewing channels and reading message history.
+pub const PERMISSIONS_COMMUNICATION_DISABLED_OMIT: Permissions = Permissions::from_bits_truncate(
+    !(Permissions::READ_MESSAGE_HISTORY.bits() | Permissions::VIEW_CHANNEL.bits()),
+);
+
 /// Permissions associated with a guild only at the root level (i.e. not channel
 /// related).
 pub const PERMISSIONS_ROOT_ONLY: Permissions = Permissions::from_bits_truncate(
@@ -0,0 +1,286 @@
+//! Verify the signatures of interactions received over HTTP.
+//!
+//! Discord signs every request sent to an application's interactions endpoint
+//! URL with the application's Ed25519 key. Requests provide the signature in
+//! the `X-Signature-Ed25519` header and the timestamp it was made with in the
+//! `X-Signature-Timestamp` header, and the signed message is the timestamp
+//! followed by the request body. Requests with invalid signatures must be
+//! rejected with a 401 status code.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use twilight_util::signature::Verifier;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let (signature, timestamp, body) = ("", "", b"");
+//! let verifier = Verifier::new("APPLICATION_PUBLIC_KEY")?;
+//!
+//! // Values of the `X-Signature-Ed25519` and `X-Signature-Timestamp` headers
+//! // and the raw request body, as provided by a web framework.
+//! let interaction = verifier.verify_interaction(signature, timestamp, body)?;
+//! # Ok(()) }
+//! ```
+
+use ed25519_dalek::{Signature, VerifyingKey, PUBLIC_KEY_LENGTH, SIGNATURE_LENGTH};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::application::interaction::Interaction;
+
+/// Name of the header containing the signature of a request.
+pub const SIGNATURE_HEADER: &str = "x-signature-ed25519";
+
+/// Name of the header containing the timestamp of a request's signature.
+pub const TIMESTAMP_HEADER: &str = "x-signature-timestamp";
+
+/// Error when verifying the signature of a request.
+#[derive(Debug)]
+pub struct VerifyError {
+    kind: VerifyErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl VerifyError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &VerifyErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (VerifyErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+
+    fn new(kind: VerifyErrorType, source: Option<Box<dyn Error + Send + Sync>>) -> Self {
+        Self { kind, source }
+    }
+}
+
+impl Display for VerifyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.kind {
+            VerifyErrorType::Deserializing => f.write_str("failed to deserialize request body"),
+            VerifyErrorType::PublicKeyInvalid => f.write_str("public key is invalid"),
+            VerifyErrorType::SignatureInvalid => f.write_str("signature header is malformed"),
+            VerifyErrorType::SignatureMismatch => {
+                f.write_str("signature doesn't match the request")
+            }
+        }
+    }
+}
+
+impl Error for VerifyError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`VerifyError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum VerifyErrorType {
+    /// Signature is valid, but the body is not a valid interaction.
+    Deserializing,
+    /// Public key is not a hex encoded Ed25519 public key.
+    PublicKeyInvalid,
+    /// Signature is not a hex encoded Ed25519 signature.
+    SignatureInvalid,
+    /// Signature was not made by the application's key for the timestamp and
+    /// body.
+    ///
+    /// The request must be rejected.
+    SignatureMismatch,
+}
+
+/// Verifier of request signatures for an application's public key.
+#[derive(Clone, Debug)]
+pub struct Verifier {
+    key: VerifyingKey,
+}
+
+impl Verifier {
+    /// Create a verifier from the hex encoded public key shown in the
+    /// application's settings.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`VerifyErrorType::PublicKeyInvalid`] error type if the key is
+    /// not a valid hex encoded Ed25519 public key.
+    pub fn new(public_key: &str) -> Result<Self, VerifyError> {
+        let mut bytes = [0; PUBLIC_KEY_LENGTH];
+        hex::decode_to_slice(public_key, &mut bytes).map_err(|source| {
+            VerifyError::new(VerifyErrorType::PublicKeyInvalid, Some(Box::new(source)))
+        })?;
+
+        Self::from_bytes(&bytes)
+    }
+
+    /// Create a verifier from the bytes of a public key.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`VerifyErrorType::PublicKeyInvalid`] error type if the bytes
+    /// are not a valid Ed25519 public key.
+    pub fn from_bytes(public_key: &[u8; PUBLIC_KEY_LENGTH]) -> Result<Self, VerifyError> {
+        let key = VerifyingKey::from_bytes(public_key).map_err(|source| {
+            VerifyError::new(VerifyErrorType::PublicKeyInvalid, Some(Box::new(source)))
+        })?;
+
+        Ok(Self { key })
+    }
+
+    /// Verify the signature of a request.
+    ///
+    /// `signature` and `timestamp` are the values of the [`SIGNATURE_HEADER`]
+    /// and [`TIMESTAMP_HEADER`] headers and `body` is the raw request body.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`VerifyErrorType::SignatureInvalid`] error type if the
+    /// signature is not a valid hex encoded Ed25519 signature.
+    ///
+    /// Returns a [`VerifyErrorType::SignatureMismatch`] error type if the
+    /// signature doesn't match the timestamp and body. Signatures are verified
+    /// strictly, rejecting malleable signatures and weak public keys.
+    pub fn verify(&self, signature: &str, timestamp: &str, body: &[u8]) -> Result<(), VerifyError> {
+        let mut bytes = [0; SIGNATURE_LENGTH];
+        hex::decode_to_slice(signature, &mut bytes).map_err(|source| {
+            VerifyError::new(VerifyErrorType::SignatureInvalid, Some(Box::new(source)))
+        })?;
+        let signature = Signature::from_bytes(&bytes);
+
+        let message = [timestamp.as_bytes(), body].concat();
+
+        self.key
+            .verify_strict(&message, &signature)
+            .map_err(|source| {
+                VerifyError::new(VerifyErrorType::SignatureMismatch, Some(Box::new(source)))
+            })
+    }
+
+    /// Verify the signature of a request and deserialize its body into an
+    /// [`Interaction`].
+    ///
+    /// Refer to [`verify`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`VerifyErrorType::Deserializing`] error type if the body is
+    /// not a valid interaction.
+    ///
+    /// Returns the errors of [`verify`].
+    ///
+    /// [`verify`]: Self::verify
+    pub fn verify_interaction(
+        &self,
+        signature: &str,
+        timestamp: &str,
+        body: &[u8],
+    ) -> Result<Interaction, VerifyError> {
+        self.verify(signature, timestamp, body)?;
+
+        serde_json::from_slice(body).map_err(|source| {
+            VerifyError::new(VerifyErrorType::Deserializing, Some(Box::new(source)))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Verifier, VerifyError, VerifyErrorType};
+    use ed25519_dalek::{Signer, SigningKey};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug};
+
+    assert_impl_all!(Verifier: Clone, Debug, Send, Sync);
+    assert_impl_all!(VerifyError: Debug, Error, Send, Sync);
+    assert_impl_all!(VerifyErrorType: Debug, Send, Sync);
+
+    const BODY: &[u8] =
+        br#"{"application_id":"1","authorizing_integration_owners":{},"entitlements":[],"id":"2","token":"a","type":1,"version":1}"#;
+    const TIMESTAMP: &str = "1700000000";
+
+    fn sign(key: &SigningKey, timestamp: &str, body: &[u8]) -> String {
+        let signature = key.sign(&[timestamp.as_bytes(), body].concat());
+
+        hex::encode(signature.to_bytes())
+    }
+
+    fn signing_key() -> SigningKey {
+        SigningKey::from_bytes(&[7; 32])
+    }
+
+    fn verifier() -> Verifier {
+        Verifier::new(&hex::encode(signing_key().verifying_key().as_bytes())).unwrap()
+    }
+
+    #[test]
+    fn public_key_invalid() {
+        assert!(matches!(
+            Verifier::new("not hex").unwrap_err().kind(),
+            VerifyErrorType::PublicKeyInvalid
+        ));
+        assert!(matches!(
+            Verifier::new("abcd").unwrap_err().kind(),
+            VerifyErrorType::PublicKeyInvalid
+        ));
+    }
+
+    #[test]
+    fn verify() {
+        let signature = sign(&signing_key(), TIMESTAMP, BODY);
+        let verifier = verifier();
+
+        assert!(verifier.verify(&signature, TIMESTAMP, BODY).is_ok());
+        assert!(matches!(
+            verifier
+                .verify(&signature, "1700000001", BODY)
+                .unwrap_err()
+                .kind(),
+            VerifyErrorType::SignatureMismatch
+        ));
+        assert!(matches!(
+            verifier
+                .verify(&signature, TIMESTAMP, b"{}")
+                .unwrap_err()
+                .kind(),
+            VerifyErrorType::SignatureMismatch
+        ));
+        assert!(matches!(
+            verifier.verify("zz", TIMESTAMP, BODY).unwrap_err().kind(),
+            VerifyErrorType::SignatureInvalid
+        ));
+    }
+
+    #[test]
+    fn verify_interaction() {
+        let verifier = verifier();
+
+        let signature = sign(&signing_key(), TIMESTAMP, BODY);
+        let interaction = verifier
+            .verify_interaction(&signature, TIMESTAMP, BODY)
+            .unwrap();
+        assert_eq!(2, interaction.id.get());
+
+        let signature = sign(&signing_key(), TIMESTAMP, b"{}");
+        assert!(matches!(
+            verifier
+                .verify_interaction(&signature, TIMESTAMP, b"{}")
+                .unwrap_err()
+                .kind(),
+            VerifyErrorType::Deserializing
+        ));
+    }
+}
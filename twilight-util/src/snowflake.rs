@@ -11,6 +11,9 @@ use twilight_model::id::{
     Id,
 };
 
+/// Discord's custom epoch, the unix time in milliseconds for the first second of 2015.
+const DISCORD_EPOCH: u64 = 1_420_070_400_000;
+
 /// Snowflake is a trait for defining extractable information from a Snowflake. A Snowflake is a
 /// u64 generated by Discord to uniquely identify a resource.
 pub trait Snowflake {
@@ -59,9 +62,6 @@ pub trait Snowflake {
     /// ```
     #[allow(clippy::cast_possible_wrap)]
     fn timestamp(&self) -> i64 {
-        // Discord's custom epoch, the unix time in milliseconds for the first second of 2015.
-        const DISCORD_EPOCH: u64 = 1_420_070_400_000;
-
         ((self.id() >> 22) + DISCORD_EPOCH) as i64
     }
 
@@ -91,6 +91,38 @@ pub trait Snowflake {
     }
 }
 
+/// Create an ID from a Unix epoch in milliseconds.
+///
+/// The worker ID, process ID, and increment of the returned ID are zero. This
+/// is useful as a pagination cursor, such as fetching messages sent after a
+/// point in time.
+///
+/// Returns [`None`] if the timestamp is not after Discord's epoch, the first
+/// second of 2015, or is too large to be represented.
+///
+/// # Examples
+///
+/// Create a message ID to fetch messages sent after a point in time:
+///
+/// ```
+/// use twilight_model::id::{marker::MessageMarker, Id};
+/// use twilight_util::snowflake::{self, Snowflake};
+///
+/// let id = snowflake::from_timestamp::<MessageMarker>(1_445_219_918_546).unwrap();
+///
+/// assert_eq!(1_445_219_918_546, id.timestamp());
+/// assert_eq!(105_484_726_235_561_984, id.get());
+/// ```
+pub fn from_timestamp<T>(timestamp: i64) -> Option<Id<T>> {
+    let timestamp = u64::try_from(timestamp).ok()?.checked_sub(DISCORD_EPOCH)?;
+
+    if timestamp >> 42 != 0 {
+        return None;
+    }
+
+    Id::new_checked(timestamp << 22)
+}
+
 impl Snowflake for Id<ApplicationMarker> {
     fn id(&self) -> u64 {
         self.get()
@@ -274,6 +306,20 @@ mod tests {
         assert_eq!(expected, id.timestamp());
     }
 
+    #[test]
+    fn from_timestamp() {
+        let id = super::from_timestamp::<GenericMarker>(1_445_219_918_546).unwrap();
+
+        assert_eq!(1_445_219_918_546, id.timestamp());
+        assert_eq!(0, id.worker_id());
+        assert_eq!(0, id.process_id());
+        assert_eq!(0, id.increment());
+
+        assert!(super::from_timestamp::<GenericMarker>(-1).is_none());
+        assert!(super::from_timestamp::<GenericMarker>(1_420_070_400_000).is_none());
+        assert!(super::from_timestamp::<GenericMarker>(i64::MAX).is_none());
+    }
+
     #[test]
     fn worker_id() {
         let expected: u8 = 8;
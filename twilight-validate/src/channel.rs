@@ -39,6 +39,9 @@ pub const CHANNEL_TOPIC_LENGTH_MAX: usize = 1024;
 /// Maximum user limit of an audio channel.
 pub const CHANNEL_USER_LIMIT_MAX: u16 = 99;
 
+/// Maximum length of a voice channel's status.
+pub const CHANNEL_VOICE_STATUS_LENGTH_MAX: usize = 500;
+
 /// Returned when the channel can not be updated as configured.
 #[derive(Debug)]
 pub struct ChannelValidationError {
@@ -113,6 +116,9 @@ impl Display for ChannelValidationError {
 
                 Display::fmt(&CHANNEL_USER_LIMIT_MAX, f)
             }
+            ChannelValidationErrorType::VoiceStatusInvalid => {
+                f.write_str("the voice status is invalid")
+            }
         }
     }
 }
@@ -148,6 +154,8 @@ pub enum ChannelValidationErrorType {
     },
     /// User limit is greater than 99.
     UserLimitInvalid,
+    /// The length of the voice status is more than 500 characters.
+    VoiceStatusInvalid,
 }
 
 /// Ensure a channel's bitrate is collect.
@@ -338,6 +346,25 @@ pub const fn user_limit(value: u16) -> Result<(), ChannelValidationError> {
     }
 }
 
+/// Ensure a voice channel's status's length is correct.
+///
+/// # Errors
+///
+/// Returns an error of type [`VoiceStatusInvalid`] if the status is invalid.
+///
+/// [`VoiceStatusInvalid`]: ChannelValidationErrorType::VoiceStatusInvalid
+pub fn voice_status(value: impl AsRef<str>) -> Result<(), ChannelValidationError> {
+    let count = value.as_ref().chars().count();
+
+    if count <= CHANNEL_VOICE_STATUS_LENGTH_MAX {
+        Ok(())
+    } else {
+        Err(ChannelValidationError {
+            kind: ChannelValidationErrorType::VoiceStatusInvalid,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -420,4 +447,15 @@ mod tests {
             ChannelValidationErrorType::UserLimitInvalid
         ));
     }
+
+    #[test]
+    fn voice_status_length() {
+        assert!(voice_status("").is_ok());
+        assert!(voice_status("a".repeat(500)).is_ok());
+
+        assert!(matches!(
+            voice_status("a".repeat(501)).unwrap_err().kind(),
+            ChannelValidationErrorType::VoiceStatusInvalid
+        ));
+    }
 }
@@ -0,0 +1,346 @@
+//! Constants, error types, and functions for validating
+//! [`InteractionResponseData`] fields.
+//!
+//! [`InteractionResponseData`]: twilight_model::http::interaction::InteractionResponseData
+
+use crate::{
+    command::{choice as validate_choice, CommandValidationErrorType, CHOICES_LIMIT},
+    component::COMPONENT_CUSTOM_ID_LENGTH,
+    message::{
+        attachment as validate_attachment, components as validate_components,
+        content as validate_content, embeds as validate_embeds, MessageValidationError,
+        MessageValidationErrorType,
+    },
+};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    application::command::CommandOptionChoice, http::interaction::InteractionResponseData,
+};
+
+/// Maximum length of a modal's title in codepoints.
+pub const MODAL_TITLE_LENGTH_MAX: usize = 45;
+
+/// Interaction response data is not valid.
+#[derive(Debug)]
+pub struct InteractionValidationError {
+    /// Type of error that occurred.
+    kind: InteractionValidationErrorType,
+    /// Source of the error, if any.
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl InteractionValidationError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &InteractionValidationErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        InteractionValidationErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for InteractionValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            InteractionValidationErrorType::ChoiceCount { count } => {
+                Display::fmt(count, f)?;
+                f.write_str(" choices were provided, but only ")?;
+                Display::fmt(&CHOICES_LIMIT, f)?;
+
+                f.write_str(" are allowed")
+            }
+            InteractionValidationErrorType::ChoiceInvalid { idx, .. } => {
+                f.write_str("choice at index ")?;
+                Display::fmt(idx, f)?;
+
+                f.write_str(" is invalid")
+            }
+            InteractionValidationErrorType::CustomIdInvalid { chars } => {
+                f.write_str("custom id is ")?;
+                Display::fmt(chars, f)?;
+                f.write_str(" characters long, but the max is ")?;
+
+                Display::fmt(&COMPONENT_CUSTOM_ID_LENGTH, f)
+            }
+            InteractionValidationErrorType::MessageInvalid { .. } => {
+                f.write_str("message of the response is invalid")
+            }
+            InteractionValidationErrorType::TitleInvalid { chars } => {
+                f.write_str("modal title is ")?;
+                Display::fmt(chars, f)?;
+                f.write_str(" characters long, but the max is ")?;
+
+                Display::fmt(&MODAL_TITLE_LENGTH_MAX, f)
+            }
+        }
+    }
+}
+
+impl Error for InteractionValidationError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`InteractionValidationError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum InteractionValidationErrorType {
+    /// Too many autocomplete choices were provided.
+    ChoiceCount {
+        /// Number of choices that were provided.
+        count: usize,
+    },
+    /// An invalid autocomplete choice was provided.
+    ChoiceInvalid {
+        /// Index of the choice.
+        idx: usize,
+        /// Additional details about the validation failure type.
+        kind: CommandValidationErrorType,
+    },
+    /// Custom ID of the modal is too long.
+    CustomIdInvalid {
+        /// Provided number of codepoints.
+        chars: usize,
+    },
+    /// Message fields of the response are invalid.
+    MessageInvalid {
+        /// Additional details about the validation failure type.
+        kind: MessageValidationErrorType,
+    },
+    /// Title of the modal is too long.
+    TitleInvalid {
+        /// Provided number of codepoints.
+        chars: usize,
+    },
+}
+
+/// Ensure a list of autocomplete choices is correct.
+///
+/// # Errors
+///
+/// Returns an error of type [`ChoiceCount`] if there are more than
+/// [`CHOICES_LIMIT`] choices.
+///
+/// Returns an error of type [`ChoiceInvalid`] if a choice is invalid. Refer
+/// to the errors section of [`command::choice`] for the possible reasons.
+///
+/// [`ChoiceCount`]: InteractionValidationErrorType::ChoiceCount
+/// [`ChoiceInvalid`]: InteractionValidationErrorType::ChoiceInvalid
+/// [`command::choice`]: crate::command::choice
+pub fn choices(choices: &[CommandOptionChoice]) -> Result<(), InteractionValidationError> {
+    let count = choices.len();
+
+    if count > CHOICES_LIMIT {
+        return Err(InteractionValidationError {
+            kind: InteractionValidationErrorType::ChoiceCount { count },
+            source: None,
+        });
+    }
+
+    for (idx, choice) in choices.iter().enumerate() {
+        validate_choice(choice).map_err(|source| {
+            let (kind, source) = source.into_parts();
+
+            InteractionValidationError {
+                kind: InteractionValidationErrorType::ChoiceInvalid { idx, kind },
+                source,
+            }
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Ensure a modal's custom ID is correct.
+///
+/// # Errors
+///
+/// Returns an error of type [`CustomIdInvalid`] if the custom ID is longer
+/// than [`COMPONENT_CUSTOM_ID_LENGTH`].
+///
+/// [`CustomIdInvalid`]: InteractionValidationErrorType::CustomIdInvalid
+pub fn custom_id(value: impl AsRef<str>) -> Result<(), InteractionValidationError> {
+    let chars = value.as_ref().chars().count();
+
+    if chars <= COMPONENT_CUSTOM_ID_LENGTH {
+        Ok(())
+    } else {
+        Err(InteractionValidationError {
+            kind: InteractionValidationErrorType::CustomIdInvalid { chars },
+            source: None,
+        })
+    }
+}
+
+/// Ensure a modal's title is correct.
+///
+/// # Errors
+///
+/// Returns an error of type [`TitleInvalid`] if the title is longer than
+/// [`MODAL_TITLE_LENGTH_MAX`].
+///
+/// [`TitleInvalid`]: InteractionValidationErrorType::TitleInvalid
+pub fn modal_title(value: impl AsRef<str>) -> Result<(), InteractionValidationError> {
+    let chars = value.as_ref().chars().count();
+
+    if chars <= MODAL_TITLE_LENGTH_MAX {
+        Ok(())
+    } else {
+        Err(InteractionValidationError {
+            kind: InteractionValidationErrorType::TitleInvalid { chars },
+            source: None,
+        })
+    }
+}
+
+/// Ensure the data of an interaction response is correct.
+///
+/// # Errors
+///
+/// Returns an error of type [`MessageInvalid`] if the attachments, components,
+/// content, or embeds are invalid. Refer to the errors sections of the
+/// functions in the [`message`] module for the possible reasons.
+///
+/// Refer to the errors sections of [`choices`], [`custom_id`], and
+/// [`modal_title`] for the remaining errors.
+///
+/// [`MessageInvalid`]: InteractionValidationErrorType::MessageInvalid
+/// [`message`]: crate::message
+pub fn response_data(data: &InteractionResponseData) -> Result<(), InteractionValidationError> {
+    if let Some(attachments) = &data.attachments {
+        attachments
+            .iter()
+            .try_for_each(validate_attachment)
+            .map_err(message_invalid)?;
+    }
+
+    if let Some(choices) = &data.choices {
+        self::choices(choices)?;
+    }
+
+    if let Some(components) = &data.components {
+        validate_components(components).map_err(message_invalid)?;
+    }
+
+    if let Some(content) = &data.content {
+        validate_content(content).map_err(message_invalid)?;
+    }
+
+    if let Some(custom_id) = &data.custom_id {
+        self::custom_id(custom_id)?;
+    }
+
+    if let Some(embeds) = &data.embeds {
+        validate_embeds(embeds).map_err(message_invalid)?;
+    }
+
+    if let Some(title) = &data.title {
+        modal_title(title)?;
+    }
+
+    Ok(())
+}
+
+/// Wrap a message validation error in an interaction validation error.
+fn message_invalid(source: MessageValidationError) -> InteractionValidationError {
+    let (kind, source) = source.into_parts();
+
+    InteractionValidationError {
+        kind: InteractionValidationErrorType::MessageInvalid { kind },
+        source,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::application::command::CommandOptionChoiceValue;
+
+    assert_impl_all!(InteractionValidationErrorType: Debug, Send, Sync);
+    assert_impl_all!(InteractionValidationError: Debug, Error, Send, Sync);
+
+    fn choice(name: &str) -> CommandOptionChoice {
+        CommandOptionChoice {
+            name: name.to_owned(),
+            name_localizations: None,
+            value: CommandOptionChoiceValue::Integer(1),
+        }
+    }
+
+    #[test]
+    fn choice_count() {
+        assert!(choices(&vec![choice("a"); 25]).is_ok());
+
+        assert!(matches!(
+            choices(&vec![choice("a"); 26]).unwrap_err().kind(),
+            InteractionValidationErrorType::ChoiceCount { count: 26 }
+        ));
+        assert!(matches!(
+            choices(&[choice("a"), choice("")]).unwrap_err().kind(),
+            InteractionValidationErrorType::ChoiceInvalid {
+                idx: 1,
+                kind: CommandValidationErrorType::OptionChoiceNameLengthInvalid,
+            }
+        ));
+    }
+
+    #[test]
+    fn custom_id_length() {
+        assert!(custom_id("a".repeat(100)).is_ok());
+
+        assert!(matches!(
+            custom_id("a".repeat(101)).unwrap_err().kind(),
+            InteractionValidationErrorType::CustomIdInvalid { chars: 101 }
+        ));
+    }
+
+    #[test]
+    fn modal_title_length() {
+        assert!(modal_title("a".repeat(45)).is_ok());
+
+        assert!(matches!(
+            modal_title("a".repeat(46)).unwrap_err().kind(),
+            InteractionValidationErrorType::TitleInvalid { chars: 46 }
+        ));
+    }
+
+    #[test]
+    fn response_data_message() {
+        let mut data = InteractionResponseData {
+            content: Some("a".repeat(2000)),
+            ..Default::default()
+        };
+        assert!(response_data(&data).is_ok());
+
+        data.content = Some("a".repeat(2001));
+        assert!(matches!(
+            response_data(&data).unwrap_err().kind(),
+            InteractionValidationErrorType::MessageInvalid {
+                kind: MessageValidationErrorType::ContentInvalid,
+            }
+        ));
+    }
+}
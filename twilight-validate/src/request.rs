@@ -59,6 +59,12 @@ pub const CREATE_GUILD_BAN_DELETE_MESSAGE_SECONDS_MAX: u32 = 604_800;
 /// Maximum amount of time a member can be timed out for.
 pub const COMMUNICATION_DISABLED_MAX_DURATION: i64 = 28 * 24 * 60 * 60;
 
+/// Maximum amount of voters to get for a poll answer.
+pub const GET_ANSWER_VOTERS_LIMIT_MAX: u8 = 100;
+
+/// Minimum amount of voters to get for a poll answer.
+pub const GET_ANSWER_VOTERS_LIMIT_MIN: u8 = 1;
+
 /// Maximum amount of messages to get.
 pub const GET_CHANNEL_MESSAGES_LIMIT_MAX: u16 = 100;
 
@@ -332,6 +338,15 @@ impl Display for ValidationError {
             ValidationErrorType::CommunicationDisabledUntil { .. } => {
                 f.write_str("provided timestamp is too far in the future")
             }
+            ValidationErrorType::GetAnswerVoters { limit } => {
+                f.write_str("provided get answer voters limit is ")?;
+                Display::fmt(limit, f)?;
+                f.write_str(", but it must be at least ")?;
+                Display::fmt(&GET_ANSWER_VOTERS_LIMIT_MIN, f)?;
+                f.write_str(" and at most ")?;
+
+                Display::fmt(&GET_ANSWER_VOTERS_LIMIT_MAX, f)
+            }
             ValidationErrorType::GetChannelMessages { limit } => {
                 f.write_str("provided get guild members limit is ")?;
                 Display::fmt(limit, f)?;
@@ -616,6 +631,11 @@ pub enum ValidationErrorType {
         /// Invalid timestamp.
         timestamp: Timestamp,
     },
+    /// Provided get answer voters limit was invalid.
+    GetAnswerVoters {
+        /// Invalid limit.
+        limit: u8,
+    },
     /// Provided get channel messages limit was invalid.
     GetChannelMessages {
         /// Invalid limit.
@@ -1174,6 +1194,28 @@ pub fn communication_disabled_until(timestamp: Timestamp) -> Result<(), Validati
     }
 }
 
+/// Ensure that the limit for the Get Answer Voters request is correct.
+///
+/// The limit must be at least [`GET_ANSWER_VOTERS_LIMIT_MIN`] and at most
+/// [`GET_ANSWER_VOTERS_LIMIT_MAX`]. This is based on
+/// [this documentation entry].
+///
+/// # Errors
+///
+/// Returns an error of type [`GetAnswerVoters`] if the limit is invalid.
+///
+/// [`GetAnswerVoters`]: ValidationErrorType::GetAnswerVoters
+/// [this documentation entry]: https://discord.com/developers/docs/resources/poll#get-answer-voters
+pub const fn get_answer_voters_limit(limit: u8) -> Result<(), ValidationError> {
+    if limit >= GET_ANSWER_VOTERS_LIMIT_MIN && limit <= GET_ANSWER_VOTERS_LIMIT_MAX {
+        Ok(())
+    } else {
+        Err(ValidationError {
+            kind: ValidationErrorType::GetAnswerVoters { limit },
+        })
+    }
+}
+
 /// Ensure that the limit for the Get Channel Messages request is correct.
 ///
 /// The limit must be at least [`GET_CHANNEL_MESSAGES_LIMIT_MIN`] and at most
@@ -1854,6 +1896,15 @@ mod tests {
         assert!(communication_disabled_until(err_timestamp).is_err());
     }
 
+    #[test]
+    fn get_answer_voters_limit_count() {
+        assert!(get_answer_voters_limit(1).is_ok());
+        assert!(get_answer_voters_limit(100).is_ok());
+
+        assert!(get_answer_voters_limit(0).is_err());
+        assert!(get_answer_voters_limit(101).is_err());
+    }
+
     #[test]
     fn get_channel_messages_limit_count() {
         assert!(get_channel_messages_limit(1).is_ok());